@@ -60,6 +60,9 @@ pub enum Value {
     Timestamp(DateTime<Utc>),
 
     /// Object.
+    ///
+    /// Fields are always iterated, displayed and serialized in ascending key
+    /// order, regardless of the order in which they were inserted.
     Object(BTreeMap<String, Value>),
 
     /// Array.
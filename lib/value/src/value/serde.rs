@@ -216,6 +216,7 @@ impl TryInto<serde_json::Value> for Value {
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
     use std::fs;
     use std::io::Read;
     use std::path::Path;
@@ -288,4 +289,46 @@ mod test {
                 _ => panic!("This test should never read Err'ing type folders."),
             });
     }
+
+    #[test]
+    fn object_serialization_is_ordered_by_key() {
+        let fields = [
+            ("zeta", Value::from(1)),
+            ("alpha", Value::from("foo")),
+            (
+                "mu",
+                Value::from(BTreeMap::from([
+                    ("y".to_owned(), Value::from(true)),
+                    ("x".to_owned(), Value::Null),
+                ])),
+            ),
+        ];
+
+        // Insert the same fields in every order, each must serialize identically.
+        let orders = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        for order in orders {
+            let mut object = BTreeMap::new();
+            for i in order {
+                let (key, value) = fields[i].clone();
+                object.insert(key.to_owned(), value);
+            }
+            let value = Value::from(object);
+
+            assert_eq!(
+                serde_json::to_string(&value).unwrap(),
+                r#"{"alpha":"foo","mu":{"x":null,"y":true},"zeta":1}"#
+            );
+            assert_eq!(
+                value.coerce_to_bytes(),
+                r#"{"alpha":"foo","mu":{"x":null,"y":true},"zeta":1}"#
+            );
+        }
+    }
 }
@@ -173,10 +173,17 @@ impl ByteSizeOf for JsonValue {
     }
 }
 
+/// The size of a [`Value`] is an approximation, found by walking every nested
+/// object and array once.
+///
+/// It counts the bytes of strings and object keys, but not the unused capacity
+/// of any of the collections, nor the compiled form of a regex, only its
+/// pattern.
 impl ByteSizeOf for Value {
     fn allocated_bytes(&self) -> usize {
         match self {
             Value::Bytes(bytes) => bytes.len(),
+            Value::Regex(regex) => regex.as_bytes_slice().len(),
             Value::Object(map) => map.size_of(),
             Value::Array(arr) => arr.size_of(),
             _ => 0,
@@ -189,3 +196,40 @@ impl ByteSizeOf for DateTime<Utc> {
         0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_large_string() {
+        let len = 1024 * 1024;
+        let value = Value::from("a".repeat(len));
+
+        assert_eq!(value.size_of(), mem::size_of::<Value>() + len);
+    }
+
+    #[test]
+    fn value_nested_array() {
+        let value = Value::Array(vec![Value::from("foo"), Value::Array(vec![Value::Null])]);
+
+        assert_eq!(
+            value.size_of(),
+            4 * mem::size_of::<Value>() + 2 * mem::size_of::<Vec<Value>>() + 3
+        );
+    }
+
+    #[test]
+    fn value_deeply_nested_object() {
+        let depth = 100;
+        let value = (0..depth).fold(Value::Null, |value, _| {
+            Value::Object(BTreeMap::from([("a".to_owned(), value)]))
+        });
+
+        let field = mem::size_of::<Value>()
+            + mem::size_of::<BTreeMap<String, Value>>()
+            + mem::size_of::<String>()
+            + 1;
+        assert_eq!(value.size_of(), depth * field + mem::size_of::<Value>());
+    }
+}
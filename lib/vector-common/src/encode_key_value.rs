@@ -50,11 +50,17 @@ pub fn to_string<V: Serialize>(
         match (input.remove(field), flatten_boolean) {
             (Some(Data::Boolean(false)), true) | (None, _) => (),
             (Some(Data::Boolean(true)), true) => {
-                encode_string(&mut output, field);
+                encode_string(&mut output, field, key_value_delimiter, field_delimiter);
                 output.push_str(field_delimiter);
             }
             (Some(value), _) => {
-                encode_field(&mut output, field, &value.to_string(), key_value_delimiter);
+                encode_field(
+                    &mut output,
+                    field,
+                    &value.to_string(),
+                    key_value_delimiter,
+                    field_delimiter,
+                );
                 output.push_str(field_delimiter);
             }
         };
@@ -64,11 +70,17 @@ pub fn to_string<V: Serialize>(
         match (value, flatten_boolean) {
             (Data::Boolean(false), true) => (),
             (Data::Boolean(true), true) => {
-                encode_string(&mut output, key);
+                encode_string(&mut output, key, key_value_delimiter, field_delimiter);
                 output.push_str(field_delimiter);
             }
             (_, _) => {
-                encode_field(&mut output, key, &value.to_string(), key_value_delimiter);
+                encode_field(
+                    &mut output,
+                    key,
+                    &value.to_string(),
+                    key_value_delimiter,
+                    field_delimiter,
+                );
                 output.push_str(field_delimiter);
             }
         };
@@ -92,14 +104,25 @@ fn flatten<'a>(
     Ok(map)
 }
 
-fn encode_field<'a>(output: &mut String, key: &str, value: &str, key_value_delimiter: &'a str) {
-    encode_string(output, key);
+fn encode_field(
+    output: &mut String,
+    key: &str,
+    value: &str,
+    key_value_delimiter: &str,
+    field_delimiter: &str,
+) {
+    encode_string(output, key, key_value_delimiter, field_delimiter);
     output.push_str(key_value_delimiter);
-    encode_string(output, value);
+    encode_string(output, value, key_value_delimiter, field_delimiter);
 }
 
-fn encode_string(output: &mut String, str: &str) {
-    let needs_quoting = str.chars().any(|c| c.is_whitespace() || c == '"');
+fn encode_string(output: &mut String, str: &str, key_value_delimiter: &str, field_delimiter: &str) {
+    // A string containing either delimiter is quoted, so that it can't be
+    // mistaken for the start of a new key or field.
+    let contains = |delimiter: &str| !delimiter.is_empty() && str.contains(delimiter);
+    let needs_quoting = str.chars().any(|c| c.is_whitespace() || c == '"')
+        || contains(key_value_delimiter)
+        || contains(field_delimiter);
 
     if needs_quoting {
         output.write_char('"').unwrap();
@@ -630,6 +653,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn string_with_delimiters() {
+        assert_eq!(
+            &to_string::<Value>(
+                &btreemap! {
+                    "lvl" => "info",
+                    "msg" => "a=b",
+                    "query" => "x,y",
+                    "key=name" => "foo"
+                },
+                &[],
+                "=",
+                ",",
+                true
+            )
+            .unwrap(),
+            r#""key=name"=foo,lvl=info,msg="a=b",query="x,y""#
+        );
+    }
+
     #[test]
     fn string_with_characters_to_escape() {
         assert_eq!(
@@ -87,6 +87,7 @@ impl Function for SetSemanticMeaning {
                 message: "semantic meaning defined for non-external target".to_owned(),
                 labels,
                 notes: vec![],
+                code: 0,
             };
 
             return Err(Box::new(error) as Box<dyn DiagnosticMessage>);
@@ -112,6 +113,7 @@ impl Function for SetSemanticMeaning {
                     ),
                 ],
                 notes: vec![],
+                code: 0,
             };
 
             return Err(Box::new(error) as Box<dyn DiagnosticMessage>);
@@ -139,6 +141,7 @@ impl Function for SetSemanticMeaning {
                         ),
                     ],
                     notes: vec![],
+                    code: 0,
                 };
 
                 return Err(Box::new(error) as Box<dyn DiagnosticMessage>);
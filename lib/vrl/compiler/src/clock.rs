@@ -0,0 +1,64 @@
+use std::{fmt, sync::Arc};
+
+use chrono::{DateTime, Utc};
+
+/// The source of the current time for functions that read it at runtime, such
+/// as `now`.
+///
+/// The system clock is used by default. A clock with a fixed time makes the
+/// result of a program that reads the time the same on every run, which is
+/// useful for tests.
+#[derive(Clone, Default)]
+pub struct Clock {
+    now: Option<Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>>,
+}
+
+impl Clock {
+    /// Create a clock that reads the current time from the given function.
+    #[must_use]
+    pub fn new(now: impl Fn() -> DateTime<Utc> + Send + Sync + 'static) -> Self {
+        Self {
+            now: Some(Arc::new(now)),
+        }
+    }
+
+    /// Create a clock that always returns the given time.
+    #[must_use]
+    pub fn frozen(time: DateTime<Utc>) -> Self {
+        Self::new(move || time)
+    }
+
+    /// Get the current time.
+    #[must_use]
+    pub fn now(&self) -> DateTime<Utc> {
+        match &self.now {
+            Some(now) => now(),
+            None => Utc::now(),
+        }
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.now {
+            Some(_) => f.write_str("Clock(custom)"),
+            None => f.write_str("Clock(system)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn frozen() {
+        let time = Utc.ymd(2021, 1, 1).and_hms(0, 0, 0);
+        let clock = Clock::frozen(time);
+
+        assert_eq!(clock.now(), time);
+        assert_eq!(clock.now(), time);
+    }
+}
@@ -6,6 +6,7 @@ pub struct CompileConfig {
     /// Custom context injected by the external environment
     custom: AnyMap,
     read_only_paths: BTreeSet<ReadOnlyPath>,
+    truthy_predicates: bool,
 }
 
 impl CompileConfig {
@@ -61,6 +62,23 @@ impl CompileConfig {
         self.read_only_paths
             .insert(ReadOnlyPath { path, recursive });
     }
+
+    /// Returns whether predicates of `if` statements are allowed to resolve to
+    /// any value, instead of only a boolean.
+    #[must_use]
+    pub fn truthy_predicates(&self) -> bool {
+        self.truthy_predicates
+    }
+
+    /// Allows predicates of `if` statements to resolve to any value, which is
+    /// then coerced into a boolean. Only `false`, `null`, an empty string and
+    /// zero are considered `false`.
+    ///
+    /// By default, a predicate that doesn't resolve to a boolean results in a
+    /// compile time error.
+    pub fn set_truthy_predicates(&mut self, truthy: bool) {
+        self.truthy_predicates = truthy;
+    }
 }
 
 impl Default for CompileConfig {
@@ -68,6 +86,7 @@ impl Default for CompileConfig {
         Self {
             custom: AnyMap::new(),
             read_only_paths: BTreeSet::new(),
+            truthy_predicates: false,
         }
     }
 }
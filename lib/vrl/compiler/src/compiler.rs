@@ -1,20 +1,24 @@
+use std::collections::HashSet;
+
 use core::Value;
 use diagnostic::{DiagnosticList, DiagnosticMessage, Note, Severity, Span};
 use lookup::{OwnedTargetPath, OwnedValuePath, PathPrefix};
-use parser::ast::{self, Node, QueryTarget};
+use parser::ast::{self, Ident, Node, QueryTarget};
 
 use crate::function::ArgumentList;
 use crate::state::TypeState;
 use crate::value::VrlValueConvert;
 use crate::{
     expression::{
-        assignment, function_call, literal, predicate, query, Abort, Array, Assignment, Block,
-        Container, Error, Expr, Expression, FunctionArgument, FunctionCall, Group, IfStatement,
-        Literal, Noop, Not, Object, Op, Predicate, Query, Target, Unary, Variable,
+        abort, assignment, function_call, if_statement, literal, loop_control, match_expression,
+        op, predicate, query, return_expression, variable, Abort, Alternative, Array, Assignment,
+        Block, Container, DropEvent, Error, Expr, Expression, FunctionArgument, FunctionCall,
+        Group, IfStatement, Literal, LoopControl, MatchArm, MatchExpression, Noop, Not, Object, Op,
+        Predicate, Query, Return, Target, Try, Unary, Variable,
     },
     parser::ast::RootExpr,
-    program::ProgramInfo,
-    CompileConfig, DeprecationWarning, Function, Program, TypeDef,
+    program::{Dependencies, ProgramInfo},
+    CompileConfig, ControlFlow, DeprecationWarning, Function, Program, TypeDef,
 };
 
 pub(crate) type Diagnostics = Vec<Box<dyn DiagnosticMessage>>;
@@ -37,6 +41,17 @@ pub struct Compiler<'a> {
     external_queries: Vec<OwnedTargetPath>,
     external_assignments: Vec<OwnedTargetPath>,
 
+    /// The external paths queried before the program is known to have
+    /// assigned them.
+    required_external_queries: Vec<OwnedTargetPath>,
+
+    /// The external paths assigned whenever the expression being compiled
+    /// runs.
+    ///
+    /// Assignments within expressions that don't always run, such as the
+    /// blocks of an `if` statement, are only kept if every branch makes them.
+    definite_external_assignments: Vec<OwnedTargetPath>,
+
     /// A list of variables that are missing, because the rhs expression of the
     /// assignment failed to compile.
     ///
@@ -51,6 +66,29 @@ pub struct Compiler<'a> {
     /// nullifies the fallibility of that expression.
     fallible_expression_error: Option<Box<dyn DiagnosticMessage>>,
 
+    /// A list of local variables assigned within the program, together with
+    /// the span of their first assignment.
+    ///
+    /// Any variable in this list that isn't part of `variable_reads` at the
+    /// end of compilation results in an "unused variable" warning.
+    variable_assignments: Vec<(Ident, Span)>,
+
+    /// The local variables read within the program.
+    variable_reads: HashSet<Ident>,
+
+    /// The local variables assigned more than once within the program.
+    reassigned_variables: HashSet<Ident>,
+
+    /// The closures the current expression is nested in, innermost last, and
+    /// whether each of them supports `break` and `continue`.
+    ///
+    /// A closure can run multiple times, so the value of a variable it reads
+    /// can differ between runs.
+    closures: Vec<bool>,
+
+    /// The union of the types of all values returned by `return` expressions.
+    return_type: TypeDef,
+
     config: CompileConfig,
 }
 
@@ -71,12 +109,27 @@ impl<'a> Compiler<'a> {
             abortable: false,
             external_queries: vec![],
             external_assignments: vec![],
+            required_external_queries: vec![],
+            definite_external_assignments: vec![],
             skip_missing_query_target: vec![],
             fallible_expression_error: None,
+            variable_assignments: vec![],
+            variable_reads: HashSet::new(),
+            reassigned_variables: HashSet::new(),
+            closures: vec![],
+            return_type: TypeDef::never(),
             config,
         };
         let expressions = compiler.compile_root_exprs(ast, &mut state);
 
+        // Collected before the result assignment is tracked as a variable read,
+        // as that read doesn't happen at runtime.
+        let dependencies = compiler.dependencies();
+
+        #[cfg(feature = "expr-assignment")]
+        compiler.track_result_assignment(&expressions);
+        compiler.push_unused_variable_warnings();
+
         let (errors, warnings): (Vec<_>, Vec<_>) =
             compiler.diagnostics.into_iter().partition(|diagnostic| {
                 matches!(diagnostic.severity(), Severity::Bug | Severity::Error)
@@ -96,6 +149,8 @@ impl<'a> Compiler<'a> {
                     target_assignments: compiler.external_assignments,
                 },
                 initial_state,
+                return_type: compiler.return_type,
+                dependencies,
             },
             warnings: warnings.into(),
             config: compiler.config,
@@ -103,23 +158,102 @@ impl<'a> Compiler<'a> {
         Ok(result)
     }
 
+    /// The external paths and local variables accessed by the program.
+    fn dependencies(&self) -> Dependencies {
+        Dependencies {
+            target_reads: self.external_queries.iter().cloned().collect(),
+            target_writes: self.external_assignments.iter().cloned().collect(),
+            required_target_reads: self.required_external_queries.iter().cloned().collect(),
+            variable_reads: self.variable_reads.iter().cloned().collect(),
+            variable_writes: self
+                .variable_assignments
+                .iter()
+                .map(|(ident, _)| ident.clone())
+                .collect(),
+        }
+    }
+
+    /// Track a query of the external target. The queried path has to be
+    /// provided by the target, unless the program always assigns it first.
+    fn track_external_query(&mut self, path: OwnedTargetPath) {
+        let assigned = self
+            .definite_external_assignments
+            .iter()
+            .any(|assigned| path.can_start_with(assigned));
+
+        if !assigned {
+            self.required_external_queries.push(path.clone());
+        }
+
+        self.external_queries.push(path);
+    }
+
+    /// The last expression of the program resolves to its result, so any
+    /// variable assigned by that expression is considered to be used.
+    #[cfg(feature = "expr-assignment")]
+    fn track_result_assignment(&mut self, expressions: &[Expr]) {
+        if let Some(Expr::Assignment(assignment)) = expressions.last() {
+            for target in assignment.targets() {
+                if let assignment::Target::Internal(ident, _) = target {
+                    self.variable_reads.insert(ident);
+                }
+            }
+        }
+    }
+
+    fn push_unused_variable_warnings(&mut self) {
+        for (ident, span) in std::mem::take(&mut self.variable_assignments) {
+            // Variables prefixed with an underscore are intentionally unused.
+            if ident.starts_with('_') || self.variable_reads.contains(&ident) {
+                continue;
+            }
+
+            let warning = variable::Error::unused(ident, span);
+            self.diagnostics.push(Box::new(warning));
+        }
+    }
+
     fn compile_exprs(
         &mut self,
         nodes: impl IntoIterator<Item = Node<ast::Expr>>,
         state: &mut TypeState,
     ) -> Option<Vec<Expr>> {
         let mut exprs = vec![];
+        let mut diverging_span = None;
         for node in nodes {
+            self.check_unreachable_abort(&node, diverging_span);
+
+            let span = node.span();
             let expr = self.compile_expr(node, state)?;
+
+            // Whether an expression completes doesn't depend on the state it
+            // runs in, so the state after it is good enough to check this.
+            if diverging_span.is_none() && expr.type_info(state).result.is_never() {
+                diverging_span = Some(span);
+            }
             exprs.push(expr);
         }
         Some(exprs)
     }
 
+    /// An `abort` that follows an expression that never completes, such as
+    /// another `abort` or a `return`, can't run.
+    #[cfg(feature = "expr-abort")]
+    fn check_unreachable_abort(&mut self, node: &Node<ast::Expr>, diverging_span: Option<Span>) {
+        if let (ast::Expr::Abort(_), Some(diverging_span)) = (node.inner(), diverging_span) {
+            let warning = abort::Error::unreachable(node.span(), diverging_span);
+            self.diagnostics.push(Box::new(warning));
+        }
+    }
+
+    #[cfg(not(feature = "expr-abort"))]
+    #[allow(clippy::unused_self)]
+    fn check_unreachable_abort(&mut self, _: &Node<ast::Expr>, _: Option<Span>) {}
+
     fn compile_expr(&mut self, node: Node<ast::Expr>, state: &mut TypeState) -> Option<Expr> {
         use ast::Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Op, Query, Unary,
-            Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Op, Query, Return, Try, Unary, Variable,
         };
         let original_state = state.clone();
 
@@ -136,6 +270,11 @@ impl<'a> Compiler<'a> {
             Variable(node) => self.compile_variable(node, state).map(Into::into),
             Unary(node) => self.compile_unary(node, state).map(Into::into),
             Abort(node) => self.compile_abort(node, state).map(Into::into),
+            Match(node) => self.compile_match(node, state).map(Into::into),
+            LoopControl(node) => self.compile_loop_control(node, state).map(Into::into),
+            Try(node) => self.compile_try(node, state).map(Into::into),
+            Return(node) => self.compile_return(node, state).map(Into::into),
+            Drop(node) => self.compile_drop(node).map(Into::into),
         }?;
 
         // If the previously compiled expression is fallible, _and_ we are
@@ -149,12 +288,68 @@ impl<'a> Compiler<'a> {
             self.fallible_expression_error = Some(Box::new(error) as _);
         }
 
-        Some(expr)
+        Some(self.fold_constant(expr, &type_def))
+    }
+
+    /// Replaces an expression that always resolves to the same value with a
+    /// cheaper equivalent, so that it isn't evaluated again for every event.
+    ///
+    /// This runs after the expression is type-checked, so any diagnostics and
+    /// type information are still produced from the original expression.
+    /// Fallible expressions are never folded, to retain their runtime errors.
+    fn fold_constant(&self, expr: Expr, type_def: &TypeDef) -> Expr {
+        if type_def.is_fallible() {
+            return expr;
+        }
+
+        match expr {
+            // if true { .. } else { .. }
+            #[cfg(all(feature = "expr-if_statement", feature = "expr-literal"))]
+            Expr::IfStatement(if_statement) => match if_statement.predicate.as_literal() {
+                Some(true) => Container::new(if_statement.if_block.into()).into(),
+                Some(false) => match if_statement.alternative {
+                    Some(Alternative::Block(block)) => Container::new(block.into()).into(),
+                    Some(Alternative::IfStatement(if_statement)) => Container::new(
+                        Block::new_scoped(vec![Expr::IfStatement(*if_statement)]).into(),
+                    )
+                    .into(),
+                    None => Literal::Null.into(),
+                },
+                None => Expr::IfStatement(if_statement),
+            },
+
+            // 1 + 2
+            #[cfg(all(feature = "expr-op", feature = "expr-literal"))]
+            Expr::Op(op)
+                if matches!((&*op.lhs, &*op.rhs), (Expr::Literal(_), Expr::Literal(_))) =>
+            {
+                match op.as_value().and_then(Literal::from_value) {
+                    Some(literal) => literal.into(),
+                    None => Expr::Op(op),
+                }
+            }
+
+            // x = 1; x
+            #[cfg(feature = "expr-literal")]
+            Expr::Variable(variable)
+                if self.closures.is_empty()
+                    && !self.reassigned_variables.contains(variable.ident()) =>
+            {
+                match variable.value().cloned().and_then(Literal::from_value) {
+                    Some(literal) => literal.into(),
+                    None => Expr::Variable(variable),
+                }
+            }
+
+            expr => expr,
+        }
     }
 
     #[cfg(feature = "expr-literal")]
     fn compile_literal(&mut self, node: Node<ast::Literal>, state: &mut TypeState) -> Option<Expr> {
-        use ast::Literal::{Boolean, Float, Integer, Null, RawString, Regex, String, Timestamp};
+        use ast::Literal::{
+            Boolean, Duration, Float, Integer, Null, RawString, Regex, String, Timestamp,
+        };
         use bytes::Bytes;
 
         let (span, lit) = node.take();
@@ -183,6 +378,13 @@ impl<'a> Compiler<'a> {
                 .parse()
                 .map(Literal::Timestamp)
                 .map_err(|err| literal::Error::from((span, err))),
+            // Durations are represented as a number of seconds.
+            Duration(v) => {
+                let seconds = ast::duration_seconds(&v).expect("lexed as a valid duration");
+                Ok(Literal::Float(
+                    ordered_float::NotNan::new(seconds).expect("duration is never NaN"),
+                ))
+            }
             Null => Ok(Literal::Null),
         };
 
@@ -226,17 +428,25 @@ impl<'a> Compiler<'a> {
         state: &mut TypeState,
     ) -> Vec<Expr> {
         let mut node_exprs = vec![];
+        let mut diverging_span = None;
 
         for root_expr in nodes {
             match root_expr.into_inner() {
                 RootExpr::Expr(node_expr) => {
                     self.fallible_expression_error = None;
 
+                    self.check_unreachable_abort(&node_expr, diverging_span);
+
+                    let span = node_expr.span();
+
                     if let Some(expr) = self.compile_expr(node_expr, state) {
                         if let Some(error) = self.fallible_expression_error.take() {
                             self.diagnostics.push(error);
                         }
 
+                        if diverging_span.is_none() && expr.type_info(state).result.is_never() {
+                            diverging_span = Some(span);
+                        }
                         node_exprs.push(expr);
                     }
                 }
@@ -307,6 +517,7 @@ impl<'a> Compiler<'a> {
 
         let original_state = state.clone();
 
+        let predicate_span = predicate.span();
         let predicate = self
             .compile_predicate(predicate, state)?
             .map_err(|err| self.diagnostics.push(Box::new(err)))
@@ -314,19 +525,52 @@ impl<'a> Compiler<'a> {
 
         let after_predicate_state = state.clone();
 
+        #[cfg(feature = "expr-query")]
+        predicate.narrow_type_state(state);
+
+        // Only one of the blocks runs, so an external path is only known to be
+        // assigned after the statement if both blocks assign it.
+        let assigned_before = self.definite_external_assignments.clone();
+
+        let if_span = if_node.span();
         let if_block = self.compile_block(if_node, state)?;
 
-        let else_block = if let Some(else_node) = else_node {
+        let assigned_in_if =
+            std::mem::replace(&mut self.definite_external_assignments, assigned_before);
+
+        let else_span = else_node.as_ref().map(Node::span);
+        let alternative = if let Some(else_node) = else_node {
             *state = after_predicate_state;
-            Some(self.compile_block(else_node, state)?)
+            let block = self.compile_block(else_node, state)?;
+
+            self.definite_external_assignments
+                .retain(|path| assigned_in_if.contains(path));
+
+            Some(Alternative::from_block(block))
         } else {
             None
         };
 
+        // A predicate that is (or is folded into) a boolean literal always
+        // takes the same branch, so the other one never runs.
+        #[cfg(feature = "expr-literal")]
+        if let Some(value) = predicate.as_literal() {
+            let block_span = if value { else_span } else { Some(if_span) };
+
+            if let Some(block_span) = block_span {
+                let warning = if_statement::Error::UnreachableBranch {
+                    predicate: value,
+                    predicate_span,
+                    block_span,
+                };
+                self.diagnostics.push(Box::new(warning));
+            }
+        }
+
         let if_statement = IfStatement {
             predicate,
             if_block,
-            else_block,
+            alternative,
         };
 
         // The current state is from one of the branches. Restore it and calculate
@@ -364,9 +608,92 @@ impl<'a> Compiler<'a> {
             Node::new(span, exprs),
             state,
             self.fallible_expression_error.as_deref(),
+            self.config.truthy_predicates(),
         ))
     }
 
+    #[cfg(feature = "expr-match")]
+    fn compile_match(
+        &mut self,
+        node: Node<ast::Match>,
+        state: &mut TypeState,
+    ) -> Option<MatchExpression> {
+        use ast::MatchPattern::{Literal, Wildcard};
+
+        let ast::Match { value, arms } = node.into_inner();
+
+        let original_state = state.clone();
+
+        let value = self.compile_expr(*value, state)?;
+
+        let after_value_state = state.clone();
+
+        // Only one of the arms runs, any external path they assign isn't known
+        // to be assigned after the expression.
+        let assigned_before = self.definite_external_assignments.clone();
+
+        let mut match_arms = vec![];
+        let mut default: Option<(Span, Expr)> = None;
+
+        for arm in arms {
+            let ast::MatchArm { pattern, expr } = arm.into_inner();
+            let (pattern_span, pattern) = pattern.take();
+
+            if let Some((default_span, _)) = &default {
+                let error = match_expression::Error::UnreachableArm {
+                    span: pattern_span,
+                    default_span: *default_span,
+                };
+                self.diagnostics.push(Box::new(error));
+                return None;
+            }
+
+            *state = after_value_state.clone();
+            self.definite_external_assignments = assigned_before.clone();
+            let expr = self.compile_expr(expr, state)?;
+
+            match pattern {
+                Wildcard => default = Some((pattern_span, expr)),
+                Literal(literal) => {
+                    let pattern = self
+                        .compile_literal(Node::new(pattern_span, literal), state)?
+                        .as_value();
+
+                    let pattern = match pattern {
+                        Some(pattern) => pattern,
+                        None => {
+                            let error =
+                                match_expression::Error::NonLiteralPattern { span: pattern_span };
+                            self.diagnostics.push(Box::new(error));
+                            return None;
+                        }
+                    };
+
+                    match_arms.push(MatchArm { pattern, expr });
+                }
+            }
+        }
+
+        self.definite_external_assignments = assigned_before;
+
+        let match_expression = MatchExpression {
+            value: Box::new(value),
+            arms: match_arms,
+            default: default.map(|(_, expr)| Box::new(expr)),
+        };
+
+        // The current state is from one of the arms. Restore it and calculate
+        // the type state from the full "match" expression.
+        *state = original_state;
+        match_expression.apply_type_info(state);
+        Some(match_expression)
+    }
+
+    #[cfg(not(feature = "expr-match"))]
+    fn compile_match(&mut self, node: Node<ast::Match>, _: &mut TypeState) -> Option<Expr> {
+        self.handle_missing_feature_error(node.span(), "expr-match")
+    }
+
     #[cfg(feature = "expr-op")]
     fn compile_op(&mut self, node: Node<ast::Op>, state: &mut TypeState) -> Option<Op> {
         use parser::ast::Opcode;
@@ -385,16 +712,41 @@ impl<'a> Compiler<'a> {
             self.fallible_expression_error = None;
         }
 
+        // The rhs of a short-circuiting operation doesn't always run.
+        let short_circuits = matches!(opcode.inner(), Opcode::Or | Opcode::And | Opcode::Err);
+        let assigned_before = self.definite_external_assignments.len();
+
+        let rhs_state = state.clone();
         let rhs_span = rhs.span();
         let rhs = Node::new(rhs_span, self.compile_expr(*rhs, state)?);
 
+        if short_circuits {
+            self.definite_external_assignments.truncate(assigned_before);
+        }
+
+        let op_span = opcode.span();
         let op = Op::new(lhs, opcode, rhs, state)
             .map_err(|err| self.diagnostics.push(Box::new(err)))
             .ok()?;
 
         // Both "lhs" and "rhs" are compiled above, but "rhs" isn't always executed.
         // The expression can provide a more accurate type state.
-        *state = op.type_info(&original_state).state;
+        let info = op.type_info(&original_state);
+
+        // If neither operand is fallible, the operation itself is, because of
+        // the types of its operands.
+        if info.result.is_fallible() && self.fallible_expression_error.is_none() {
+            let error = op::Error::Fallible {
+                op_span,
+                lhs_span,
+                lhs_kind: op.lhs.type_info(&original_state).result.kind().clone(),
+                rhs_span,
+                rhs_kind: op.rhs.type_info(&rhs_state).result.kind().clone(),
+            };
+            self.fallible_expression_error = Some(Box::new(error) as _);
+        }
+
+        *state = info.state;
         Some(op)
     }
 
@@ -436,7 +788,7 @@ impl<'a> Compiler<'a> {
     ) -> Option<Assignment> {
         use assignment::Variant;
         use ast::{
-            Assignment::{Infallible, Single},
+            Assignment::{Destructure, Infallible, Single},
             AssignmentOp,
         };
 
@@ -444,6 +796,19 @@ impl<'a> Compiler<'a> {
 
         let assignment = node.into_inner();
 
+        match &assignment {
+            Single { target, .. } => self.track_variable_assignment(target),
+            Infallible { ok, err, .. } => {
+                self.track_variable_assignment(ok);
+                self.track_variable_assignment(err);
+            }
+            Destructure { pattern, .. } => {
+                for target in pattern.targets() {
+                    self.track_variable_assignment(target);
+                }
+            }
+        }
+
         let node = match assignment {
             Single { target, op, expr } => {
                 let span = expr.span();
@@ -508,6 +873,39 @@ impl<'a> Compiler<'a> {
 
                 node
             }
+            Destructure {
+                pattern,
+                strict,
+                expr,
+            } => {
+                let span = expr.span();
+                let targets = Assignment::pattern_targets(pattern.into_inner());
+
+                // A strict destructure fails at runtime when a field is
+                // missing, which terminates the program.
+                if strict {
+                    self.fallible = true;
+                }
+
+                let expr = self
+                    .compile_expr(*expr, state)
+                    .map(|expr| Box::new(Node::new(span, expr)))
+                    .or_else(|| {
+                        for (_, target) in &targets {
+                            self.skip_missing_assignment_target(target.clone().into_inner());
+                        }
+                        None
+                    })?;
+
+                Node::new(
+                    span,
+                    Variant::Destructure {
+                        targets,
+                        strict,
+                        expr,
+                    },
+                )
+            }
         };
 
         let assignment = Assignment::new(
@@ -525,6 +923,7 @@ impl<'a> Compiler<'a> {
         // potential external optimizations.
         for target in assignment.targets() {
             if let assignment::Target::External(path) = target {
+                self.definite_external_assignments.push(path.clone());
                 self.external_assignments.push(path);
             }
         }
@@ -537,6 +936,39 @@ impl<'a> Compiler<'a> {
         Some(assignment)
     }
 
+    #[cfg(feature = "expr-assignment")]
+    fn track_variable_assignment(&mut self, target: &Node<ast::AssignmentTarget>) {
+        if let ast::AssignmentTarget::Internal(ident, _) = target.inner() {
+            if self.reject_builtin_constant(ident, target.span()) {
+                return;
+            }
+
+            if self
+                .variable_assignments
+                .iter()
+                .any(|(assigned, _)| assigned == ident)
+            {
+                self.reassigned_variables.insert(ident.clone());
+            } else {
+                self.variable_assignments
+                    .push((ident.clone(), target.span()));
+            }
+        }
+    }
+
+    /// Built-in constants can't be bound as variables, reject them with a
+    /// diagnostic, instead of silently shadowing the constant.
+    fn reject_builtin_constant(&mut self, ident: &Ident, span: Span) -> bool {
+        if !variable::BUILTIN_CONSTANTS.contains(&ident.as_ref()) {
+            return false;
+        }
+
+        let error = variable::Error::builtin_constant(ident.clone(), span);
+        self.diagnostics.push(Box::new(error));
+
+        true
+    }
+
     #[cfg(not(feature = "expr-assignment"))]
     fn compile_assignment(
         &mut self,
@@ -569,7 +1001,7 @@ impl<'a> Compiler<'a> {
                 prefix,
                 path: path.clone(),
             };
-            self.external_queries.push(target_path);
+            self.track_external_query(target_path);
         }
 
         Some(Query::new(target, path))
@@ -704,7 +1136,7 @@ impl<'a> Compiler<'a> {
         //
         // See: https://github.com/vectordotdev/vector/issues/12547
         if ident.as_deref() == "get" {
-            self.external_queries.push(OwnedTargetPath::event_root());
+            self.track_external_query(OwnedTargetPath::event_root());
         }
 
         let arguments: Vec<_> = arguments
@@ -717,6 +1149,24 @@ impl<'a> Compiler<'a> {
             })
             .collect::<Option<_>>()?;
 
+        // `del` removes its target path from the event, so it writes that path
+        // in addition to reading it.
+        #[cfg(feature = "expr-query")]
+        if ident.as_deref() == "del" {
+            let target = arguments
+                .iter()
+                .enumerate()
+                .find_map(|(index, argument)| match argument.keyword() {
+                    Some("target") => Some(argument),
+                    None if index == 0 => Some(argument),
+                    _ => None,
+                });
+
+            if let Some(Expr::Query(query)) = target.map(|argument| argument.expr()) {
+                self.external_assignments.extend(query.external_path());
+            }
+        }
+
         if abort_on_error {
             self.fallible = true;
         }
@@ -725,6 +1175,11 @@ impl<'a> Compiler<'a> {
             Some(closure) => {
                 let span = closure.span();
                 let ast::FunctionClosure { variables, block } = closure.into_inner();
+
+                for variable in &variables {
+                    self.reject_builtin_constant(variable.inner(), variable.span());
+                }
+
                 (Some(Node::new(span, variables)), Some(block))
             }
             None => (None, None),
@@ -763,7 +1218,17 @@ impl<'a> Compiler<'a> {
                 None => None,
                 Some(block) => {
                     let span = block.span();
-                    match self.compile_block_with_type(block, state) {
+
+                    // A closure can run any number of times, including none.
+                    let assigned_before = self.definite_external_assignments.len();
+
+                    self.closures.push(builder.supports_loop_control());
+                    let block_with_type = self.compile_block_with_type(block, state);
+                    self.closures.pop();
+
+                    self.definite_external_assignments.truncate(assigned_before);
+
+                    match block_with_type {
                         Some(block_with_type) => Some(Node::new(span, block_with_type)),
                         None => return None,
                     }
@@ -780,6 +1245,7 @@ impl<'a> Compiler<'a> {
                     local_snapshot,
                     &mut self.fallible_expression_error,
                     &mut self.config,
+                    &mut self.diagnostics,
                 )
                 .map_err(|err| self.diagnostics.push(Box::new(err)))
                 .ok()
@@ -834,6 +1300,8 @@ impl<'a> Compiler<'a> {
     ) -> Option<Variable> {
         let (span, ident) = node.take();
 
+        self.variable_reads.insert(ident.clone());
+
         if self
             .skip_missing_query_target
             .contains(&(QueryTarget::Internal(ident.clone()), OwnedValuePath::root()))
@@ -900,6 +1368,119 @@ impl<'a> Compiler<'a> {
         self.handle_missing_feature_error(node.span(), "expr-abort")
     }
 
+    #[cfg(feature = "expr-loop_control")]
+    fn compile_loop_control(
+        &mut self,
+        node: Node<ast::LoopControl>,
+        _: &mut TypeState,
+    ) -> Option<LoopControl> {
+        let (span, loop_control) = node.take();
+
+        let control_flow = match loop_control {
+            ast::LoopControl::Break => ControlFlow::Break,
+            ast::LoopControl::Continue => ControlFlow::Continue,
+        };
+
+        if self.closures.last() != Some(&true) {
+            let error = loop_control::Error::OutsideLoop {
+                keyword: match control_flow {
+                    ControlFlow::Break => "break",
+                    ControlFlow::Continue => "continue",
+                },
+                span,
+            };
+            self.diagnostics.push(Box::new(error));
+            return None;
+        }
+
+        Some(LoopControl::new(control_flow))
+    }
+
+    #[cfg(not(feature = "expr-loop_control"))]
+    fn compile_loop_control(
+        &mut self,
+        node: Node<ast::LoopControl>,
+        _: &mut TypeState,
+    ) -> Option<Expr> {
+        self.handle_missing_feature_error(node.span(), "expr-loop_control")
+    }
+
+    #[cfg(feature = "expr-try")]
+    fn compile_try(&mut self, node: Node<ast::Try>, state: &mut TypeState) -> Option<Try> {
+        let ast::Try { block, fallback } = node.into_inner();
+
+        let original_state = state.clone();
+
+        // Errors of the try block are handled by the fallback, so they don't
+        // make the surrounding expression fallible.
+        let fallible_expression_error = self.fallible_expression_error.take();
+        let assigned_before = self.definite_external_assignments.len();
+        let block_span = block.span();
+        let block = Node::new(block_span, self.compile_block(block, state)?);
+        self.fallible_expression_error = fallible_expression_error;
+
+        // The try block can stop at any of its expressions, and the fallback
+        // doesn't always run.
+        self.definite_external_assignments.truncate(assigned_before);
+
+        // The fallback can run after any expression of the try block failed.
+        *state = original_state.clone().merge(state.clone());
+        let fallback_span = fallback.span();
+        let fallback = Node::new(fallback_span, self.compile_block(fallback, state)?);
+        self.definite_external_assignments.truncate(assigned_before);
+
+        let try_catch = Try::new(block, fallback, &original_state)
+            .map_err(|err| self.diagnostics.push(Box::new(err)))
+            .ok()?;
+
+        *state = original_state;
+        try_catch.apply_type_info(state);
+        Some(try_catch)
+    }
+
+    #[cfg(not(feature = "expr-try"))]
+    fn compile_try(&mut self, node: Node<ast::Try>, _: &mut TypeState) -> Option<Expr> {
+        self.handle_missing_feature_error(node.span(), "expr-try")
+    }
+
+    #[cfg(feature = "expr-return")]
+    fn compile_return(&mut self, node: Node<ast::Return>, state: &mut TypeState) -> Option<Return> {
+        let (span, ast::Return { value }) = node.take();
+
+        // A closure can't stop the program that called the function it
+        // belongs to.
+        if !self.closures.is_empty() {
+            let error = return_expression::Error::InsideClosure { span };
+            self.diagnostics.push(Box::new(error));
+            return None;
+        }
+
+        let original_state = state.clone();
+        let value = self.compile_expr(*value, state)?;
+
+        let type_def = value.type_info(&original_state).result;
+        self.return_type = self.return_type.clone().union(type_def);
+
+        Some(Return::new(value))
+    }
+
+    #[cfg(not(feature = "expr-return"))]
+    fn compile_return(&mut self, node: Node<ast::Return>, _: &mut TypeState) -> Option<Expr> {
+        self.handle_missing_feature_error(node.span(), "expr-return")
+    }
+
+    #[cfg(feature = "expr-drop")]
+    fn compile_drop(&mut self, _: Node<ast::DropEvent>) -> Option<DropEvent> {
+        // Unlike `return`, a `drop` unwinds through the function calling the
+        // closure, so it can be used within a closure too.
+        Some(DropEvent)
+    }
+
+    #[cfg(not(feature = "expr-drop"))]
+    fn compile_drop(&mut self, node: Node<ast::DropEvent>) -> Option<Expr> {
+        self.handle_missing_feature_error(node.span(), "expr-drop")
+    }
+
     fn handle_parser_error(&mut self, error: parser::Error) {
         self.diagnostics.push(Box::new(error));
     }
@@ -970,3 +1551,277 @@ fn get_metadata_key(
             .to_string(),
     ))
 }
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use core::TargetValue;
+    use value::Secrets;
+    use vector_common::TimeZone;
+
+    use super::*;
+    use crate::{state::Runtime, Context};
+    use ast::Opcode;
+
+    fn compile(source: &str) -> Program {
+        compile_with_config(source, CompileConfig::default()).unwrap()
+    }
+
+    fn compile_with_config(source: &str, config: CompileConfig) -> Result<Program, DiagnosticList> {
+        let ast = parser::parse(source).unwrap();
+
+        Compiler::compile(&[], ast, &TypeState::default(), config).map(|result| result.program)
+    }
+
+    fn resolve(expr: &dyn Expression) -> Value {
+        let mut target = TargetValue {
+            value: Value::Object(BTreeMap::new()),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+        let mut runtime = Runtime::default();
+        let timezone = TimeZone::default();
+        let mut ctx = Context::new(&mut target, &mut runtime, &timezone);
+
+        expr.resolve(&mut ctx).unwrap()
+    }
+
+    fn if_statement(predicate: bool, if_value: &str, else_value: Option<&str>) -> Expr {
+        IfStatement {
+            predicate: Predicate::new_unchecked(vec![Literal::from(predicate).into()]),
+            if_block: Block::new_scoped(vec![Literal::from(if_value).into()]),
+            alternative: else_value
+                .map(|v| Block::new_scoped(vec![Literal::from(v).into()]).into()),
+        }
+        .into()
+    }
+
+    fn op(lhs: i64, opcode: Opcode, rhs: i64) -> Expr {
+        Op {
+            lhs: Box::new(Literal::from(lhs).into()),
+            rhs: Box::new(Literal::from(rhs).into()),
+            opcode,
+        }
+        .into()
+    }
+
+    #[test]
+    fn fold_if_statement() {
+        let cases = [
+            (
+                "if true { \"a\" } else { \"b\" }",
+                if_statement(true, "a", Some("b")),
+            ),
+            (
+                "if false { \"a\" } else { \"b\" }",
+                if_statement(false, "a", Some("b")),
+            ),
+            ("if false { \"a\" }", if_statement(false, "a", None)),
+        ];
+
+        for (source, unfolded) in cases {
+            let program = compile(source);
+
+            assert!(
+                !matches!(program.expressions.exprs()[0], Expr::IfStatement(_)),
+                "{source}"
+            );
+            assert_eq!(
+                resolve(&program.expressions),
+                resolve(&unfolded),
+                "{source}"
+            );
+        }
+    }
+
+    #[test]
+    fn truthy_predicates() {
+        let cases = [
+            ("\"\"", "b"),
+            ("\"foo\"", "a"),
+            ("0", "b"),
+            ("1", "a"),
+            ("0.0", "b"),
+            ("null", "b"),
+            ("[]", "a"),
+            ("false", "b"),
+        ];
+
+        for (predicate, want) in cases {
+            let mut config = CompileConfig::default();
+            config.set_truthy_predicates(true);
+
+            let source = format!("if {predicate} {{ \"a\" }} else {{ \"b\" }}");
+            let program = compile_with_config(&source, config).unwrap();
+
+            assert_eq!(resolve(&program.expressions), Value::from(want), "{source}");
+        }
+    }
+
+    #[test]
+    fn strict_predicates_by_default() {
+        let result = compile_with_config(r#"if "foo" { "a" }"#, CompileConfig::default());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fold_nested_op() {
+        let program = compile("1 + 2 * 3");
+        let unfolded = Expr::from(Op {
+            lhs: Box::new(Literal::from(1).into()),
+            rhs: Box::new(op(2, Opcode::Mul, 3)),
+            opcode: Opcode::Add,
+        });
+
+        assert_eq!(program.expressions.exprs(), &vec![Literal::from(7).into()]);
+        assert_eq!(resolve(&program.expressions), resolve(&unfolded));
+    }
+
+    #[test]
+    fn fold_predicate() {
+        let program = compile("if 1 == 1 { \"a\" } else { \"b\" }");
+
+        assert!(matches!(program.expressions.exprs()[0], Expr::Container(_)));
+        assert_eq!(resolve(&program.expressions), Value::from("a"));
+    }
+
+    #[test]
+    fn unreachable_branch_warnings() {
+        let cases = [
+            ("if false { 1 }", 1),
+            ("if true { 1 }", 0),
+            ("if true { 1 } else { 2 }", 1),
+            ("if 1 == 2 { 1 } else { 2 }", 1),
+            ("if .foo == true { 1 } else { 2 }", 0),
+        ];
+
+        for (source, want) in cases {
+            let ast = parser::parse(source).unwrap();
+            let result =
+                Compiler::compile(&[], ast, &TypeState::default(), CompileConfig::default())
+                    .unwrap();
+
+            assert_eq!(result.warnings.len(), want, "{source}");
+        }
+    }
+
+    #[test]
+    fn unreachable_abort_warnings() {
+        let cases = [
+            ("abort", 0),
+            (".foo = 1\nabort", 0),
+            ("abort\nabort", 1),
+            ("{ abort\nabort }", 1),
+            ("return 1\nabort\nabort", 2),
+            ("if .foo == 1 { abort } else { abort }\nabort", 1),
+            ("if .foo == 1 { abort }\nabort", 0),
+        ];
+
+        for (source, want) in cases {
+            let ast = parser::parse(source).unwrap();
+            let result =
+                Compiler::compile(&[], ast, &TypeState::default(), CompileConfig::default())
+                    .unwrap();
+
+            assert_eq!(result.warnings.len(), want, "{source}");
+        }
+    }
+
+    #[test]
+    fn abortable_type_def() {
+        let cases = [
+            ("abort", true),
+            ("{ .foo = 1\nabort }", true),
+            ("if .foo == 1 { abort } else { 1 }", true),
+            ("if .foo == 1 { 1 } else if .foo == 2 { abort }", true),
+            ("if .foo == 1 { 1 } else { 2 }", false),
+        ];
+
+        for (source, want) in cases {
+            let program = compile(source);
+            let type_def = program.expressions.type_info(&TypeState::default()).result;
+
+            assert_eq!(type_def.is_abortable(), want, "{source}");
+        }
+    }
+
+    #[test]
+    fn flatten_else_if_chain() {
+        let program =
+            compile(".a = 3\nif .a == 1 { 1 } else if .a == 2 { 2 } else if .a == 3 { 3 }");
+
+        match &program.expressions.exprs()[1] {
+            Expr::IfStatement(IfStatement {
+                alternative: Some(Alternative::IfStatement(if_statement)),
+                ..
+            }) => assert!(matches!(
+                if_statement.alternative,
+                Some(Alternative::IfStatement(_))
+            )),
+            expr => panic!("expected a flattened else-if chain, got {:?}", expr),
+        }
+
+        assert_eq!(resolve(&program.expressions), Value::from(3));
+    }
+
+    #[test]
+    fn else_if_predicate_scope() {
+        let result = compile_with_config(
+            "if .a == 1 { 1 } else if (x = 2; .a == x) { x }\nx",
+            CompileConfig::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn skip_fallible_op() {
+        let program = compile("5 / 0 ?? 1");
+
+        assert!(matches!(program.expressions.exprs()[0], Expr::Op(_)));
+        assert_eq!(resolve(&program.expressions), Value::from(1));
+    }
+
+    #[test]
+    fn propagate_constant_variable() {
+        let program = compile("x = 1\nx + 1");
+
+        assert_eq!(program.expressions.exprs()[1], Literal::from(2).into());
+        assert_eq!(resolve(&program.expressions), Value::from(2));
+    }
+
+    #[test]
+    fn propagate_constant_variable_predicate() {
+        let program = compile("x = true\nif x { \"a\" } else { \"b\" }");
+
+        assert!(matches!(program.expressions.exprs()[1], Expr::Container(_)));
+        assert_eq!(resolve(&program.expressions), Value::from("a"));
+    }
+
+    #[test]
+    fn skip_reassigned_variable() {
+        let program = compile("x = 1\nx = 2\nx");
+
+        assert!(matches!(program.expressions.exprs()[2], Expr::Variable(_)));
+        assert_eq!(resolve(&program.expressions), Value::from(2));
+    }
+
+    #[test]
+    fn skip_dynamic_variable() {
+        let program = compile("x = .foo\nx");
+
+        assert!(matches!(program.expressions.exprs()[1], Expr::Variable(_)));
+    }
+
+    #[test]
+    fn skip_dynamic_predicate() {
+        let program = compile("if .foo == 1 { \"a\" } else { \"b\" }");
+
+        assert!(matches!(
+            program.expressions.exprs()[0],
+            Expr::IfStatement(_)
+        ));
+    }
+}
@@ -1,11 +1,33 @@
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use value::Value;
 use vector_common::TimeZone;
 
-use crate::{state::Runtime, Target};
+#[cfg(feature = "profiling")]
+use crate::Profile;
+use crate::{state::Runtime, ExpressionError, Rng, Target};
 
 pub struct Context<'a> {
     target: &'a mut dyn Target,
     state: &'a mut Runtime,
     timezone: &'a TimeZone,
+    control_flow: Option<ControlFlow>,
+    return_value: Option<Value>,
+    dropped: bool,
+    deadline: Option<Instant>,
+    timed_out: bool,
+}
+
+/// A signal to the loop the program is currently running in, set by a `break`
+/// or `continue` expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlFlow {
+    /// Stop the loop.
+    Break,
+
+    /// Skip the remainder of the current iteration of the loop.
+    Continue,
 }
 
 impl<'a> Context<'a> {
@@ -15,6 +37,11 @@ impl<'a> Context<'a> {
             target,
             state,
             timezone,
+            control_flow: None,
+            return_value: None,
+            dropped: false,
+            deadline: None,
+            timed_out: false,
         }
     }
 
@@ -40,9 +67,127 @@ impl<'a> Context<'a> {
         self.state
     }
 
+    /// Get a mutable reference to the [`Rng`] of the runtime state.
+    pub fn rng_mut(&mut self) -> &mut Rng {
+        self.state.rng_mut()
+    }
+
+    /// Get the current time, from the [`Clock`](crate::Clock) of the runtime
+    /// state.
+    #[must_use]
+    pub fn now(&self) -> DateTime<Utc> {
+        self.state.clock().now()
+    }
+
+    /// Get a mutable reference to the execution counts of the runtime state,
+    /// if profiling is enabled.
+    #[cfg(feature = "profiling")]
+    pub fn profile_mut(&mut self) -> Option<&mut Profile> {
+        self.state.profile_mut()
+    }
+
     /// Get a reference to the [`TimeZone`]
     #[must_use]
     pub fn timezone(&self) -> &TimeZone {
         self.timezone
     }
+
+    /// Get the pending [`ControlFlow`] signal, if any.
+    #[must_use]
+    pub fn control_flow(&self) -> Option<ControlFlow> {
+        self.control_flow
+    }
+
+    /// Signal the loop the program is running in to break or continue.
+    pub fn set_control_flow(&mut self, control_flow: ControlFlow) {
+        self.control_flow = Some(control_flow);
+    }
+
+    /// Take the pending [`ControlFlow`] signal, if any.
+    ///
+    /// This is called by the loop after each iteration, so that the signal
+    /// doesn't affect the loop's surrounding expressions, and the error the
+    /// iteration stopped with can be discarded.
+    pub fn take_control_flow(&mut self) -> Option<ControlFlow> {
+        self.control_flow.take()
+    }
+
+    /// Returns `true` if a `return` expression stopped the program.
+    #[must_use]
+    pub fn has_returned(&self) -> bool {
+        self.return_value.is_some()
+    }
+
+    /// Stop the program, resolving it to the given value.
+    pub fn set_return_value(&mut self, value: Value) {
+        self.return_value = Some(value);
+    }
+
+    /// Take the value of the `return` expression that stopped the program, if
+    /// any.
+    pub fn take_return_value(&mut self) -> Option<Value> {
+        self.return_value.take()
+    }
+
+    /// Stop the program, and mark the event as dropped.
+    ///
+    /// This isn't an error, the caller is expected to discard the event
+    /// instead of routing it as either processed or aborted.
+    pub fn drop_event(&mut self) {
+        self.dropped = true;
+    }
+
+    /// Returns `true` if a `drop` expression stopped the program.
+    #[must_use]
+    pub fn is_dropped(&self) -> bool {
+        self.dropped
+    }
+
+    /// Get the time by which the program has to complete, if any.
+    #[must_use]
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Set the time by which the program has to complete.
+    ///
+    /// The deadline is checked cooperatively, after each expression of a
+    /// block, so a single expensive expression can still run past it.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
+    /// Returns an error, and stops the program, if the deadline of the
+    /// program has passed.
+    ///
+    /// Like a `drop`, the error can't be handled by the program itself. The
+    /// caller is expected to treat the event as failed.
+    pub fn check_deadline(&mut self) -> Result<(), ExpressionError> {
+        if let Some(deadline) = self.deadline {
+            self.timed_out = self.timed_out || Instant::now() >= deadline;
+        }
+
+        if self.timed_out {
+            return Err("program exceeded its timeout".into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the program was stopped because its deadline passed.
+    #[must_use]
+    pub fn is_timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Returns `true` if a `drop`, `return`, `break` or `continue` expression,
+    /// or a passed deadline, is unwinding the program.
+    ///
+    /// These expressions stop the expressions around them by returning an
+    /// error, which expressions handling errors (such as `??` or `try`) have
+    /// to pass on, instead of handling it.
+    #[must_use]
+    pub fn is_unwinding(&self) -> bool {
+        self.dropped || self.timed_out || self.return_value.is_some() || self.control_flow.is_some()
+    }
 }
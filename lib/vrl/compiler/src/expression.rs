@@ -7,37 +7,47 @@ use value::Value;
 use crate::{Context, Span, TypeDef};
 
 #[cfg(feature = "expr-abort")]
-mod abort;
+pub(crate) mod abort;
 mod array;
 mod block;
 mod function_argument;
 mod group;
-#[cfg(feature = "expr-if_statement")]
-mod if_statement;
 mod levenstein;
 mod noop;
 #[cfg(feature = "expr-unary")]
 mod not;
 mod object;
-#[cfg(feature = "expr-op")]
-mod op;
 #[cfg(feature = "expr-unary")]
 mod unary;
-mod variable;
 
 #[cfg(feature = "expr-assignment")]
 pub(crate) mod assignment;
 pub(crate) mod container;
+#[cfg(feature = "expr-drop")]
+pub(crate) mod drop_event;
 #[cfg(feature = "expr-function_call")]
 pub(crate) mod function;
 #[cfg(feature = "expr-function_call")]
 pub(crate) mod function_call;
+#[cfg(feature = "expr-if_statement")]
+pub(crate) mod if_statement;
 #[cfg(feature = "expr-literal")]
 pub(crate) mod literal;
+#[cfg(feature = "expr-loop_control")]
+pub(crate) mod loop_control;
+#[cfg(feature = "expr-match")]
+pub(crate) mod match_expression;
+#[cfg(feature = "expr-op")]
+pub(crate) mod op;
 #[cfg(feature = "expr-if_statement")]
 pub(crate) mod predicate;
 #[cfg(feature = "expr-query")]
 pub mod query;
+#[cfg(feature = "expr-return")]
+pub(crate) mod return_expression;
+#[cfg(feature = "expr-try")]
+pub(crate) mod try_catch;
+pub(crate) mod variable;
 
 pub use core::{ExpressionError, Resolved};
 
@@ -49,6 +59,8 @@ pub use array::Array;
 pub use assignment::Assignment;
 pub use block::Block;
 pub use container::{Container, Variant};
+#[cfg(feature = "expr-drop")]
+pub use drop_event::DropEvent;
 #[cfg(feature = "expr-function_call")]
 pub use function::FunctionExpression;
 pub use function_argument::FunctionArgument;
@@ -56,9 +68,13 @@ pub use function_argument::FunctionArgument;
 pub use function_call::FunctionCall;
 pub use group::Group;
 #[cfg(feature = "expr-if_statement")]
-pub use if_statement::IfStatement;
+pub use if_statement::{Alternative, IfStatement};
 #[cfg(feature = "expr-literal")]
 pub use literal::Literal;
+#[cfg(feature = "expr-loop_control")]
+pub use loop_control::LoopControl;
+#[cfg(feature = "expr-match")]
+pub use match_expression::{MatchArm, MatchExpression};
 pub use noop::Noop;
 #[cfg(feature = "expr-unary")]
 pub use not::Not;
@@ -69,6 +85,10 @@ pub use op::Op;
 pub use predicate::Predicate;
 #[cfg(feature = "expr-query")]
 pub use query::{Query, Target};
+#[cfg(feature = "expr-return")]
+pub use return_expression::Return;
+#[cfg(feature = "expr-try")]
+pub use try_catch::Try;
 #[cfg(feature = "expr-unary")]
 pub use unary::Unary;
 pub use variable::Variable;
@@ -124,6 +144,20 @@ pub trait Expression: Send + Sync + fmt::Debug + DynClone {
 
 clone_trait_object!(Expression);
 
+/// The relative runtime cost of expressions, used to estimate the complexity
+/// of a program (see [`Program::complexity`](crate::Program::complexity)).
+pub(crate) mod cost {
+    /// Reading or writing a path of the event, its metadata or a variable.
+    pub(crate) const PATH_ACCESS: usize = 1;
+
+    /// Calling a function, excluding the cost of its arguments.
+    pub(crate) const FUNCTION_CALL: usize = 5;
+
+    /// The number of times the closure of an iterating function (such as
+    /// `for_each`) is assumed to run.
+    pub(crate) const LOOP_ITERATIONS: usize = 10;
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
     #[cfg(feature = "expr-literal")]
@@ -145,14 +179,24 @@ pub enum Expr {
     Unary(Unary),
     #[cfg(feature = "expr-abort")]
     Abort(Abort),
+    #[cfg(feature = "expr-drop")]
+    Drop(DropEvent),
+    #[cfg(feature = "expr-match")]
+    Match(MatchExpression),
+    #[cfg(feature = "expr-loop_control")]
+    LoopControl(LoopControl),
+    #[cfg(feature = "expr-try")]
+    Try(Try),
+    #[cfg(feature = "expr-return")]
+    Return(Return),
 }
 
 impl Expr {
     pub fn as_str(&self) -> &str {
         use container::Variant::{Array, Block, Group, Object};
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Noop, Op, Query,
-            Unary, Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Noop, Op, Query, Return, Try, Unary, Variable,
         };
 
         match self {
@@ -180,6 +224,16 @@ impl Expr {
             Unary(..) => "unary operation",
             #[cfg(feature = "expr-abort")]
             Abort(..) => "abort operation",
+            #[cfg(feature = "expr-drop")]
+            Drop(..) => "drop expression",
+            #[cfg(feature = "expr-match")]
+            Match(..) => "match expression",
+            #[cfg(feature = "expr-loop_control")]
+            LoopControl(..) => "loop control",
+            #[cfg(feature = "expr-try")]
+            Try(..) => "try expression",
+            #[cfg(feature = "expr-return")]
+            Return(..) => "return expression",
         }
     }
 
@@ -224,6 +278,47 @@ impl Expr {
         })
     }
 
+    /// Returns an estimate of the runtime cost of the expression, including all
+    /// nested expressions.
+    pub(crate) fn complexity(&self) -> usize {
+        use Expr::{
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Noop, Op, Query, Return, Try, Unary, Variable,
+        };
+
+        match self {
+            #[cfg(feature = "expr-literal")]
+            Literal(..) => 0,
+            Container(v) => v.complexity(),
+            #[cfg(feature = "expr-if_statement")]
+            IfStatement(v) => v.complexity(),
+            #[cfg(feature = "expr-op")]
+            Op(v) => v.complexity(),
+            #[cfg(feature = "expr-assignment")]
+            Assignment(v) => v.complexity(),
+            #[cfg(feature = "expr-query")]
+            Query(v) => v.complexity(),
+            #[cfg(feature = "expr-function_call")]
+            FunctionCall(v) => v.complexity(),
+            Variable(..) => cost::PATH_ACCESS,
+            Noop(..) => 0,
+            #[cfg(feature = "expr-unary")]
+            Unary(v) => v.complexity(),
+            #[cfg(feature = "expr-abort")]
+            Abort(v) => v.complexity(),
+            #[cfg(feature = "expr-drop")]
+            Drop(..) => 0,
+            #[cfg(feature = "expr-match")]
+            Match(v) => v.complexity(),
+            #[cfg(feature = "expr-loop_control")]
+            LoopControl(..) => 0,
+            #[cfg(feature = "expr-try")]
+            Try(v) => v.complexity(),
+            #[cfg(feature = "expr-return")]
+            Return(v) => v.complexity(),
+        }
+    }
+
     pub fn as_enum(
         &self,
         keyword: &'static str,
@@ -243,8 +338,8 @@ impl Expr {
 impl Expression for Expr {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Noop, Op, Query,
-            Unary, Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Noop, Op, Query, Return, Try, Unary, Variable,
         };
 
         match self {
@@ -267,13 +362,23 @@ impl Expression for Expr {
             Unary(v) => v.resolve(ctx),
             #[cfg(feature = "expr-abort")]
             Abort(v) => v.resolve(ctx),
+            #[cfg(feature = "expr-drop")]
+            Drop(v) => v.resolve(ctx),
+            #[cfg(feature = "expr-match")]
+            Match(v) => v.resolve(ctx),
+            #[cfg(feature = "expr-loop_control")]
+            LoopControl(v) => v.resolve(ctx),
+            #[cfg(feature = "expr-try")]
+            Try(v) => v.resolve(ctx),
+            #[cfg(feature = "expr-return")]
+            Return(v) => v.resolve(ctx),
         }
     }
 
     fn as_value(&self) -> Option<Value> {
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Noop, Op, Query,
-            Unary, Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Noop, Op, Query, Return, Try, Unary, Variable,
         };
 
         match self {
@@ -296,13 +401,23 @@ impl Expression for Expr {
             Unary(v) => Expression::as_value(v),
             #[cfg(feature = "expr-abort")]
             Abort(v) => Expression::as_value(v),
+            #[cfg(feature = "expr-drop")]
+            Drop(v) => Expression::as_value(v),
+            #[cfg(feature = "expr-match")]
+            Match(v) => Expression::as_value(v),
+            #[cfg(feature = "expr-loop_control")]
+            LoopControl(v) => Expression::as_value(v),
+            #[cfg(feature = "expr-try")]
+            Try(v) => Expression::as_value(v),
+            #[cfg(feature = "expr-return")]
+            Return(v) => Expression::as_value(v),
         }
     }
 
     fn type_info(&self, state: &TypeState) -> TypeInfo {
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Noop, Op, Query,
-            Unary, Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Noop, Op, Query, Return, Try, Unary, Variable,
         };
 
         match self {
@@ -325,6 +440,16 @@ impl Expression for Expr {
             Unary(v) => v.type_info(state),
             #[cfg(feature = "expr-abort")]
             Abort(v) => v.type_info(state),
+            #[cfg(feature = "expr-drop")]
+            Drop(v) => v.type_info(state),
+            #[cfg(feature = "expr-match")]
+            Match(v) => v.type_info(state),
+            #[cfg(feature = "expr-loop_control")]
+            LoopControl(v) => v.type_info(state),
+            #[cfg(feature = "expr-try")]
+            Try(v) => v.type_info(state),
+            #[cfg(feature = "expr-return")]
+            Return(v) => v.type_info(state),
         }
     }
 }
@@ -332,8 +457,8 @@ impl Expression for Expr {
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Noop, Op, Query,
-            Unary, Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Noop, Op, Query, Return, Try, Unary, Variable,
         };
 
         match self {
@@ -356,6 +481,16 @@ impl fmt::Display for Expr {
             Unary(v) => v.fmt(f),
             #[cfg(feature = "expr-abort")]
             Abort(v) => v.fmt(f),
+            #[cfg(feature = "expr-drop")]
+            Drop(v) => v.fmt(f),
+            #[cfg(feature = "expr-match")]
+            Match(v) => v.fmt(f),
+            #[cfg(feature = "expr-loop_control")]
+            LoopControl(v) => v.fmt(f),
+            #[cfg(feature = "expr-try")]
+            Try(v) => v.fmt(f),
+            #[cfg(feature = "expr-return")]
+            Return(v) => v.fmt(f),
         }
     }
 }
@@ -436,6 +571,41 @@ impl From<Abort> for Expr {
     }
 }
 
+#[cfg(feature = "expr-drop")]
+impl From<DropEvent> for Expr {
+    fn from(drop: DropEvent) -> Self {
+        Expr::Drop(drop)
+    }
+}
+
+#[cfg(feature = "expr-match")]
+impl From<MatchExpression> for Expr {
+    fn from(match_expression: MatchExpression) -> Self {
+        Expr::Match(match_expression)
+    }
+}
+
+#[cfg(feature = "expr-loop_control")]
+impl From<LoopControl> for Expr {
+    fn from(loop_control: LoopControl) -> Self {
+        Expr::LoopControl(loop_control)
+    }
+}
+
+#[cfg(feature = "expr-try")]
+impl From<Try> for Expr {
+    fn from(expr: Try) -> Self {
+        Expr::Try(expr)
+    }
+}
+
+#[cfg(feature = "expr-return")]
+impl From<Return> for Expr {
+    fn from(expr: Return) -> Self {
+        Expr::Return(expr)
+    }
+}
+
 #[cfg(feature = "expr-literal")]
 impl From<Value> for Expr {
     fn from(value: Value) -> Self {
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use diagnostic::{DiagnosticMessage, Label, Note, Urls};
+use diagnostic::{DiagnosticMessage, Label, Note, Severity, Urls};
 use parser::ast::Node;
 
 use super::Expr;
@@ -46,6 +46,12 @@ impl Abort {
 
         Ok(Self { span, message })
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.message
+            .as_ref()
+            .map_or(0, |message| message.complexity())
+    }
 }
 
 impl Expression for Abort {
@@ -65,7 +71,7 @@ impl Expression for Abort {
     }
 
     fn type_info(&self, state: &TypeState) -> TypeInfo {
-        TypeInfo::new(state, TypeDef::never())
+        TypeInfo::new(state, TypeDef::never().abortable())
     }
 }
 
@@ -83,12 +89,25 @@ pub struct Error {
     expr_span: Span,
 }
 
+impl Error {
+    /// An `abort` that can't run, because it follows an expression that never
+    /// completes.
+    pub(crate) fn unreachable(span: Span, diverging_span: Span) -> Self {
+        Self {
+            variant: ErrorVariant::Unreachable { diverging_span },
+            expr_span: span,
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ErrorVariant {
     #[error("unhandled fallible expression")]
     FallibleExpr,
     #[error("non-string abort message")]
     NonString(Kind),
+    #[error("unreachable abort")]
+    Unreachable { diverging_span: Span },
 }
 
 impl fmt::Display for Error {
@@ -105,11 +124,12 @@ impl std::error::Error for Error {
 
 impl DiagnosticMessage for Error {
     fn code(&self) -> usize {
-        use ErrorVariant::{FallibleExpr, NonString};
+        use ErrorVariant::{FallibleExpr, NonString, Unreachable};
 
         match self.variant {
             FallibleExpr => 631,
             NonString(_) => 300,
+            Unreachable { .. } => 704,
         }
     }
 
@@ -135,6 +155,10 @@ impl DiagnosticMessage for Error {
                     self.expr_span,
                 ),
             ],
+            ErrorVariant::Unreachable { diverging_span } => vec![
+                Label::primary("this abort is unreachable", self.expr_span),
+                Label::context("because this expression never completes", diverging_span),
+            ],
         }
     }
 
@@ -148,6 +172,17 @@ impl DiagnosticMessage for Error {
                     Urls::func_docs("#coerce-functions"),
                 ),
             ],
+            ErrorVariant::Unreachable { .. } => vec![Note::SeeDocs(
+                "abort expressions".to_owned(),
+                Urls::expression_docs_url("#abort"),
+            )],
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self.variant {
+            ErrorVariant::Unreachable { .. } => Severity::Warning,
+            ErrorVariant::FallibleExpr | ErrorVariant::NonString(_) => Severity::Error,
         }
     }
 }
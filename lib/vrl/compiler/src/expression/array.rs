@@ -17,6 +17,10 @@ impl Array {
     pub(crate) fn new(inner: Vec<Expr>) -> Self {
         Self { inner }
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.inner.iter().map(Expr::complexity).sum()
+    }
 }
 
 impl Deref for Array {
@@ -2,11 +2,11 @@ use std::{convert::TryFrom, fmt};
 
 use diagnostic::{DiagnosticMessage, Label, Note};
 use lookup::lookup_v2::OwnedTargetPath;
-use lookup::{LookupBuf, OwnedValuePath, PathPrefix, SegmentBuf};
+use lookup::{LookupBuf, OwnedSegment, OwnedValuePath, PathPrefix, SegmentBuf};
 use value::{Kind, Value};
 
 use crate::{
-    expression::{assignment::ErrorVariant::InvalidParentPathSegment, Expr, Resolved},
+    expression::{assignment::ErrorVariant::InvalidParentPathSegment, cost, Expr, Resolved},
     parser::{
         ast::{self, Ident},
         Node,
@@ -14,7 +14,7 @@ use crate::{
     state::{TypeInfo, TypeState},
     type_def::Details,
     value::kind::DefaultValue,
-    CompileConfig, Context, Expression, Span, TypeDef,
+    CompileConfig, Context, Expression, ExpressionError, Span, TypeDef,
 };
 
 #[derive(Clone, PartialEq)]
@@ -148,15 +148,100 @@ impl Assignment {
                     default: default_value,
                 }
             }
+
+            Variant::Destructure {
+                targets,
+                strict,
+                expr,
+            } => {
+                let expr_span = expr.span();
+                let first_span = targets
+                    .first()
+                    .map_or(expr_span, |(_, target)| target.span());
+                let last_span = targets
+                    .last()
+                    .map_or(expr_span, |(_, target)| target.span());
+                let assignment_span = Span::new(first_span.start(), last_span.end());
+
+                // Fallible expressions can't be destructured.
+                if fallible_rhs.is_some() {
+                    return Err(Error {
+                        variant: ErrorVariant::FallibleDestructure,
+                        expr_span,
+                        assignment_span,
+                    });
+                }
+
+                // A pattern of only no-op targets is useless.
+                if targets
+                    .iter()
+                    .all(|(_, target)| matches!(target.as_ref(), ast::AssignmentTarget::Noop))
+                {
+                    return Err(Error {
+                        variant: ErrorVariant::UnnecessaryNoop(assignment_span),
+                        expr_span,
+                        assignment_span,
+                    });
+                }
+
+                let targets = targets
+                    .into_iter()
+                    .map(|(path, target)| {
+                        Target::try_from(target.into_inner()).map(|target| (path, target))
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Variant::Destructure {
+                    targets,
+                    strict,
+                    expr: Box::new(expr.into_inner()),
+                }
+            }
         };
 
         Ok(Self { variant })
     }
 
+    /// Get the path into the destructured value for each target of the
+    /// pattern.
+    ///
+    /// The fields of an object pattern are the names of its variables, the
+    /// elements of an array pattern are their position.
+    pub(crate) fn pattern_targets(
+        pattern: ast::AssignmentPattern,
+    ) -> Vec<(OwnedValuePath, Node<ast::AssignmentTarget>)> {
+        match pattern {
+            ast::AssignmentPattern::Object(targets) => targets
+                .into_iter()
+                .map(|target| {
+                    let path = match target.as_ref() {
+                        ast::AssignmentTarget::Internal(ident, _) => {
+                            OwnedValuePath::single_field(ident)
+                        }
+                        _ => OwnedValuePath::root(),
+                    };
+
+                    (path, target)
+                })
+                .collect(),
+            ast::AssignmentPattern::Array(targets) => targets
+                .into_iter()
+                .enumerate()
+                .map(|(index, target)| {
+                    let mut path = OwnedValuePath::root();
+                    path.push_index(index as isize);
+
+                    (path, target)
+                })
+                .collect(),
+        }
+    }
+
     /// Get a list of targets for this assignment.
     ///
     /// For regular assignments, this contains a single target, for infallible
-    /// assignments, it'll contain both the `ok` and `err` target.
+    /// assignments, it'll contain both the `ok` and `err` target, and for
+    /// destructuring assignments, each target of the pattern.
     pub(crate) fn targets(&self) -> Vec<Target> {
         let mut targets = Vec::with_capacity(2);
 
@@ -166,10 +251,30 @@ impl Assignment {
                 targets.push(ok.clone());
                 targets.push(err.clone());
             }
+            Variant::Destructure {
+                targets: pattern, ..
+            } => targets.extend(pattern.iter().map(|(_, target)| target.clone())),
         }
 
         targets
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        let (expr, targets) = match &self.variant {
+            Variant::Single { target, expr } => (expr, vec![target]),
+            Variant::Infallible { ok, err, expr, .. } => (expr, vec![ok, err]),
+            Variant::Destructure { targets, expr, .. } => {
+                (expr, targets.iter().map(|(_, target)| target).collect())
+            }
+        };
+
+        let writes = targets
+            .into_iter()
+            .filter(|target| !matches!(target, Target::Noop))
+            .count();
+
+        expr.complexity() + writes * cost::PATH_ACCESS
+    }
 }
 
 fn verify_mutable(
@@ -223,6 +328,9 @@ fn verify_overwriteable(
     // Walk the entire path from back to front. If the popped segment is a field
     // or index, check the segment before it, and ensure that its kind is an
     // object or array.
+    //
+    // A parent that doesn't exist is created by the assignment, so it only
+    // needs to be checked whether its own parent can hold it.
     while let Some(last) = path.pop_back() {
         let parent_kind = root_kind.at_path(&path);
 
@@ -235,7 +343,9 @@ fn verify_overwriteable(
                 parent_span = Span::new(parent_span.start(), segment_start.saturating_sub(1));
                 remainder_str.insert_str(0, &format!(".{}", segment_str));
 
-                ("object", segment_span, parent_kind.contains_object())
+                let valid = parent_kind.contains_object() || parent_kind.is_undefined();
+
+                ("object", segment_span, valid)
             }
             SegmentBuf::Index(index) => {
                 let segment_start = parent_span.end() - format!("[{index}]").len();
@@ -244,7 +354,9 @@ fn verify_overwriteable(
                 parent_span = Span::new(parent_span.start(), segment_start);
                 remainder_str.insert_str(0, &format!("[{index}]"));
 
-                ("array", segment_span, parent_kind.contains_array())
+                let valid = parent_kind.contains_array() || parent_kind.is_undefined();
+
+                ("array", segment_span, valid)
             }
         };
 
@@ -253,7 +365,10 @@ fn verify_overwriteable(
         }
 
         let parent_str = match target {
-            Target::Internal(ident, _) => format!("{ident}{}", path),
+            Target::Internal(ident, _) => match path.iter().next() {
+                Some(SegmentBuf::Field(_) | SegmentBuf::Coalesce(_)) => format!("{ident}.{path}"),
+                Some(SegmentBuf::Index(_)) | None => format!("{ident}{path}"),
+            },
             Target::External(_) => {
                 if path.is_root() && remainder_str.starts_with('.') {
                     remainder_str = remainder_str[1..].to_owned();
@@ -294,24 +409,31 @@ impl Expression for Assignment {
 
 impl fmt::Display for Assignment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Variant::{Infallible, Single};
-
-        match &self.variant {
-            Single { target, expr } => write!(f, "{} = {}", target, expr),
-            Infallible { ok, err, expr, .. } => write!(f, "{}, {} = {}", ok, err, expr),
-        }
+        self.variant.fmt(f)
     }
 }
 
 impl fmt::Debug for Assignment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Variant::{Infallible, Single};
+        use Variant::{Destructure, Infallible, Single};
 
         match &self.variant {
             Single { target, expr } => write!(f, "{:?} = {:?}", target, expr),
             Infallible { ok, err, expr, .. } => {
                 write!(f, "Ok({:?}), Err({:?}) = {:?}", ok, err, expr)
             }
+            Destructure {
+                targets,
+                strict,
+                expr,
+            } => {
+                let targets = targets.iter().map(|(_, target)| target).collect::<Vec<_>>();
+                write!(
+                    f,
+                    "Destructure({:?}, strict: {}) = {:?}",
+                    targets, strict, expr
+                )
+            }
         }
     }
 }
@@ -493,6 +615,14 @@ pub(crate) enum Variant<T, U> {
         /// The default `ok` value used when the expression results in an error.
         default: Value,
     },
+    Destructure {
+        /// The path into the value of the expression, for each target.
+        targets: Vec<(OwnedValuePath, T)>,
+
+        /// Whether a missing path is an error, instead of assigning `null`.
+        strict: bool,
+        expr: Box<U>,
+    },
 }
 
 impl<U> Expression for Variant<Target, U>
@@ -500,7 +630,7 @@ where
     U: Expression + Clone,
 {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        use Variant::{Infallible, Single};
+        use Variant::{Destructure, Infallible, Single};
 
         let value = match self {
             Single { target, expr } => {
@@ -519,6 +649,9 @@ where
                     err.insert(Value::Null, ctx);
                     value
                 }
+                // A dropped event, a passed deadline, or a `return` or loop
+                // control expression, isn't an error the program can handle.
+                Err(error) if ctx.is_unwinding() => return Err(error),
                 Err(error) => {
                     ok.insert(default.clone(), ctx);
                     let value = Value::from(error.to_string());
@@ -526,6 +659,30 @@ where
                     value
                 }
             },
+            Destructure {
+                targets,
+                strict,
+                expr,
+            } => {
+                let value = expr.resolve(ctx)?;
+
+                // Every path is checked before any target is assigned, so that a
+                // strict pattern doesn't assign only some of them.
+                let values = targets
+                    .iter()
+                    .map(|(path, _)| match value.get(path) {
+                        Some(value) => Ok(value.clone()),
+                        None if *strict => Err(missing_path_error(path, &value)),
+                        None => Ok(Value::Null),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                for ((_, target), value) in targets.iter().zip(values) {
+                    target.insert(value, ctx);
+                }
+
+                value
+            }
         };
 
         Ok(value)
@@ -563,21 +720,85 @@ where
 
                 TypeInfo::new(state, assignment_result)
             }
+            Variant::Destructure {
+                targets,
+                strict,
+                expr,
+            } => {
+                let expr_result = expr.apply_type_info(&mut state);
+                let literal = expr.as_value();
+
+                for (path, target) in targets {
+                    // A strict pattern only assigns values that exist, others
+                    // assign `null` for missing paths.
+                    let (kind, value) = if *strict {
+                        let kind = expr_result.kind().at_path(path).without_undefined();
+                        let value = literal.as_ref().and_then(|value| value.get(path).cloned());
+
+                        (kind, value)
+                    } else {
+                        let kind = expr_result.kind().get(path);
+                        let value = literal
+                            .as_ref()
+                            .map(|value| value.get(path).cloned().unwrap_or(Value::Null));
+
+                        (kind, value)
+                    };
+
+                    target.insert_type_def(&mut state, TypeDef::from(kind), value);
+                }
+
+                TypeInfo::new(state, expr_result)
+            }
         }
     }
 }
 
+fn missing_path_error(path: &OwnedValuePath, value: &Value) -> ExpressionError {
+    let missing = match path.segments.first() {
+        Some(OwnedSegment::Index(index)) => format!("index {}", index),
+        Some(OwnedSegment::Field(field)) => format!(r#"field "{}""#, field),
+        _ => path.to_string(),
+    };
+
+    format!(
+        "unable to destructure {}: missing {}",
+        value.kind(),
+        missing
+    )
+    .into()
+}
+
 impl<T, U> fmt::Display for Variant<T, U>
 where
     T: fmt::Display,
     U: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Variant::{Infallible, Single};
+        use Variant::{Destructure, Infallible, Single};
 
         match self {
             Single { target, expr } => write!(f, "{} = {}", target, expr),
             Infallible { ok, err, expr, .. } => write!(f, "{}, {} = {}", ok, err, expr),
+            Destructure {
+                targets,
+                strict,
+                expr,
+            } => {
+                let (open, close) =
+                    match targets.first().and_then(|(path, _)| path.segments.first()) {
+                        Some(OwnedSegment::Index(_)) => ("[", "]"),
+                        _ => ("{", "}"),
+                    };
+                let targets = targets
+                    .iter()
+                    .map(|(_, target)| target.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let strict = if *strict { "!" } else { "" };
+
+                write!(f, "{}{}{}{} = {}", open, targets, close, strict, expr)
+            }
         }
     }
 }
@@ -603,6 +824,9 @@ pub(crate) enum ErrorVariant {
     #[error("unnecessary error assignment")]
     InfallibleAssignment(String, String, Span, Span),
 
+    #[error("unhandled fallible assignment")]
+    FallibleDestructure,
+
     #[error("invalid assignment target")]
     InvalidTarget(Span),
 
@@ -636,12 +860,13 @@ impl std::error::Error for Error {
 impl DiagnosticMessage for Error {
     fn code(&self) -> usize {
         use ErrorVariant::{
-            FallibleAssignment, InfallibleAssignment, InvalidTarget, ReadOnly, UnnecessaryNoop,
+            FallibleAssignment, FallibleDestructure, InfallibleAssignment, InvalidTarget, ReadOnly,
+            UnnecessaryNoop,
         };
 
         match &self.variant {
             UnnecessaryNoop(..) => 640,
-            FallibleAssignment(..) => 103,
+            FallibleAssignment(..) | FallibleDestructure => 103,
             InfallibleAssignment(..) => 104,
             InvalidTarget(..) => 641,
             InvalidParentPathSegment { .. } => 642,
@@ -651,7 +876,8 @@ impl DiagnosticMessage for Error {
 
     fn labels(&self) -> Vec<Label> {
         use ErrorVariant::{
-            FallibleAssignment, InfallibleAssignment, InvalidTarget, ReadOnly, UnnecessaryNoop,
+            FallibleAssignment, FallibleDestructure, InfallibleAssignment, InvalidTarget, ReadOnly,
+            UnnecessaryNoop,
         };
 
         match &self.variant {
@@ -669,6 +895,13 @@ impl DiagnosticMessage for Error {
                 ),
                 Label::context(format!("{}, err = {}", target, expr), self.assignment_span),
             ],
+            FallibleDestructure => vec![
+                Label::primary("this expression is fallible", self.expr_span),
+                Label::context(
+                    "update the expression to be infallible, before destructuring it",
+                    self.assignment_span,
+                ),
+            ],
             InfallibleAssignment(target, expr, ok_span, err_span) => vec![
                 Label::primary("this error assignment is unnecessary", err_span),
                 Label::context("because this expression can't fail", self.expr_span),
@@ -706,10 +939,12 @@ impl DiagnosticMessage for Error {
     }
 
     fn notes(&self) -> Vec<Note> {
-        use ErrorVariant::{FallibleAssignment, InfallibleAssignment};
+        use ErrorVariant::{FallibleAssignment, FallibleDestructure, InfallibleAssignment};
 
         match &self.variant {
-            FallibleAssignment(..) | InfallibleAssignment(..) => vec![Note::SeeErrorDocs],
+            FallibleAssignment(..) | FallibleDestructure | InfallibleAssignment(..) => {
+                vec![Note::SeeErrorDocs]
+            }
             InvalidParentPathSegment {
                 variant,
                 parent_str,
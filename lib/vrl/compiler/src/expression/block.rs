@@ -40,6 +40,10 @@ impl Block {
     pub fn exprs(&self) -> &Vec<Expr> {
         &self.inner
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.inner.iter().map(Expr::complexity).sum()
+    }
 }
 
 impl Expression for Block {
@@ -48,11 +52,15 @@ impl Expression for Block {
         // in scope can be accessed here, so it doesn't need to be checked at runtime.
         let (last, other) = self.inner.split_last().expect("at least one expression");
 
-        other
-            .iter()
-            .try_for_each(|expr| expr.resolve(ctx).map(|_| ()))?;
+        for expr in other {
+            expr.resolve(ctx)?;
+            ctx.check_deadline()?;
+        }
+
+        let value = last.resolve(ctx)?;
+        ctx.check_deadline()?;
 
-        last.resolve(ctx)
+        Ok(value)
     }
 
     fn type_info(&self, state: &TypeState) -> TypeInfo {
@@ -61,6 +69,7 @@ impl Expression for Block {
         let mut state = state.clone();
         let mut result = TypeDef::null();
         let mut fallible = false;
+        let mut abortable = false;
 
         for expr in &self.inner {
             result = expr.apply_type_info(&mut state);
@@ -68,6 +77,9 @@ impl Expression for Block {
             if result.is_fallible() {
                 fallible = true;
             }
+            if result.is_abortable() {
+                abortable = true;
+            }
             if result.is_never() {
                 break;
             }
@@ -77,7 +89,12 @@ impl Expression for Block {
             state.local = parent_locals.apply_child_scope(state.local);
         }
 
-        TypeInfo::new(state, result.with_fallibility(fallible))
+        TypeInfo::new(
+            state,
+            result
+                .with_fallibility(fallible)
+                .with_abortability(abortable),
+        )
     }
 }
 
@@ -97,3 +114,45 @@ impl fmt::Display for Block {
         f.write_str("\n}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use value::Value;
+    use vector_common::TimeZone;
+
+    use super::*;
+    use crate::{expression::Literal, state::Runtime};
+
+    fn block() -> Block {
+        Block::new_inline(vec![Literal::from(1).into(), Literal::from(2).into()])
+    }
+
+    #[test]
+    fn deadline_not_reached() {
+        let mut target = Value::Object(Default::default());
+        let mut runtime = Runtime::default();
+        let timezone = TimeZone::default();
+        let mut ctx = Context::new(&mut target, &mut runtime, &timezone);
+        ctx.set_deadline(Some(Instant::now() + Duration::from_secs(60)));
+
+        assert_eq!(block().resolve(&mut ctx), Ok(Value::from(2)));
+        assert!(!ctx.is_timed_out());
+    }
+
+    #[test]
+    fn deadline_exceeded() {
+        let mut target = Value::Object(Default::default());
+        let mut runtime = Runtime::default();
+        let timezone = TimeZone::default();
+        let mut ctx = Context::new(&mut target, &mut runtime, &timezone);
+        ctx.set_deadline(Some(Instant::now()));
+
+        assert_eq!(
+            block().resolve(&mut ctx).map_err(|err| err.to_string()),
+            Err("program exceeded its timeout".to_owned())
+        );
+        assert!(ctx.is_timed_out());
+    }
+}
@@ -16,6 +16,17 @@ impl Container {
     pub fn new(variant: Variant) -> Self {
         Self { variant }
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        use Variant::{Array, Block, Group, Object};
+
+        match &self.variant {
+            Group(v) => v.complexity(),
+            Block(v) => v.complexity(),
+            Array(v) => v.complexity(),
+            Object(v) => v.complexity(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -0,0 +1,46 @@
+use std::fmt;
+
+use crate::state::{TypeInfo, TypeState};
+use crate::{expression::Resolved, Context, Expression, TypeDef};
+
+/// A `drop` expression, stopping the program and discarding the event.
+///
+/// Unlike `abort`, dropping an event isn't an error, the caller is expected to
+/// discard the event without routing it anywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DropEvent;
+
+impl Expression for DropEvent {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        ctx.drop_event();
+
+        // The error unwinds every expression the `drop` is nested in,
+        // including the closures it's called from. Expressions that handle
+        // errors pass it on once the event is dropped, and the runtime
+        // reports the dropped event instead of the error.
+        Err("event dropped".into())
+    }
+
+    fn type_info(&self, state: &TypeState) -> TypeInfo {
+        TypeInfo::new(state, TypeDef::never())
+    }
+}
+
+impl fmt::Display for DropEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("drop")
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use super::*;
+    use crate::test_type_def;
+
+    test_type_def![drop_is_never {
+        expr: |_| DropEvent,
+        want: TypeDef::never(),
+    }];
+}
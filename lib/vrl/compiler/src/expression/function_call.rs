@@ -4,7 +4,7 @@ use std::{fmt, sync::Arc};
 use super::Block;
 use crate::state::{TypeInfo, TypeState};
 use crate::{
-    expression::{levenstein, ExpressionError, FunctionArgument},
+    expression::{cost, levenstein, ExpressionError, FunctionArgument},
     function::{
         closure::{self, VariableKind},
         ArgumentList, Example, FunctionClosure, FunctionCompileContext, Parameter,
@@ -54,15 +54,15 @@ impl<'a> Builder<'a> {
         {
             function
         } else {
-            let idents = funcs
+            let functions = funcs
                 .iter()
-                .map(|func| func.identifier())
+                .map(|func| (func.identifier(), func.parameters()))
                 .collect::<Vec<_>>();
 
             return Err(Error::Undefined {
                 ident_span,
                 ident: ident.clone(),
-                idents,
+                functions,
             });
         };
 
@@ -385,6 +385,14 @@ impl<'a> Builder<'a> {
         Ok(closure)
     }
 
+    /// Returns whether `break` and `continue` can be used within the closure of
+    /// this function call.
+    pub(crate) fn supports_loop_control(&self) -> bool {
+        self.function
+            .closure()
+            .map_or(false, |closure| closure.supports_loop_control)
+    }
+
     pub(crate) fn compile(
         mut self,
         state_before_function_args: &TypeState,
@@ -393,6 +401,7 @@ impl<'a> Builder<'a> {
         local_snapshot: LocalEnv,
         fallible_expression_error: &mut Option<Box<dyn DiagnosticMessage>>,
         config: &mut CompileConfig,
+        warnings: &mut Vec<Box<dyn DiagnosticMessage>>,
     ) -> Result<FunctionCall, Error> {
         let (closure, closure_fallible) =
             self.compile_closure(closure_block, local_snapshot, state)?;
@@ -416,8 +425,11 @@ impl<'a> Builder<'a> {
             )
             .map_err(|error| Error::Compilation { call_span, error })?;
 
-        // Re-insert the external context into the compiler state.
-        let _ = std::mem::replace(config, compile_ctx.into_config());
+        // Re-insert the external context into the compiler state, and hand
+        // over any warnings reported by the function.
+        let (compile_config, function_warnings) = compile_ctx.into_parts();
+        let _ = std::mem::replace(config, compile_config);
+        warnings.extend(function_warnings);
 
         // Asking for an infallible function to abort on error makes no sense.
         // We consider this an error at compile-time, because it makes the
@@ -536,8 +548,6 @@ pub struct FunctionCall {
     expr: Box<dyn Expression>,
     arguments_with_unknown_type_validity: Vec<(Parameter, Node<FunctionArgument>)>,
     closure_fallible: bool,
-    // will be used with: https://github.com/vectordotdev/vector/issues/13782
-    #[allow(dead_code)]
     closure: Option<FunctionClosure>,
 
     // used for enhancing runtime error messages (using abort-instruction).
@@ -608,6 +618,11 @@ impl FunctionCall {
         Ok(result)
     }
 
+    /// The arguments passed to the function, in the order they were written.
+    pub(crate) fn arguments(&self) -> &[Node<FunctionArgument>] {
+        &self.arguments
+    }
+
     #[must_use]
     pub fn arguments_fmt(&self) -> Vec<String> {
         self.arguments
@@ -623,10 +638,30 @@ impl FunctionCall {
             .map(|arg| format!("{:?}", arg.inner()))
             .collect::<Vec<_>>()
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        let arguments: usize = self
+            .arguments
+            .iter()
+            .map(|argument| argument.inner().expr().complexity())
+            .sum();
+
+        // Closures are only used by functions iterating over a collection.
+        let closure = self.closure.as_ref().map_or(0, |closure| {
+            closure.block.complexity() * cost::LOOP_ITERATIONS
+        });
+
+        cost::FUNCTION_CALL + arguments + closure
+    }
 }
 
 impl Expression for FunctionCall {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
+        #[cfg(feature = "profiling")]
+        if let Some(profile) = ctx.profile_mut() {
+            profile.record_expression(self.span);
+        }
+
         self.expr.resolve(ctx).map_err(|err| match err {
             #[cfg(feature = "expr-abort")]
             ExpressionError::Abort { .. } => {
@@ -636,6 +671,7 @@ impl Expression for FunctionCall {
                 message,
                 mut labels,
                 notes,
+                code,
             } => {
                 labels.push(Label::primary(message.clone(), self.span));
 
@@ -649,6 +685,7 @@ impl Expression for FunctionCall {
                     ),
                     labels,
                     notes,
+                    code,
                 }
             }
         })
@@ -810,7 +847,7 @@ pub(crate) enum Error {
     Undefined {
         ident_span: Span,
         ident: Ident,
-        idents: Vec<&'static str>,
+        functions: Vec<(&'static str, &'static [Parameter])>,
     },
 
     #[error("wrong number of function arguments")]
@@ -915,27 +952,35 @@ impl DiagnosticMessage for Error {
             Undefined {
                 ident_span,
                 ident,
-                idents,
+                functions,
             } => {
                 let mut vec = vec![Label::primary("undefined function", ident_span)];
                 let ident_chars = ident.as_ref().chars().collect::<Vec<_>>();
 
-                if let Some((idx, _)) = idents
+                // Only suggest a function if it is within a third of the
+                // identifier length, unrelated names aren't helpful.
+                if let Some((guessed, parameters, _)) = functions
                     .iter()
-                    .map(|possible| {
+                    .map(|(possible, parameters)| {
                         let possible_chars = possible.chars().collect::<Vec<_>>();
-                        levenstein::distance(&ident_chars, &possible_chars)
+                        let score = levenstein::distance(&ident_chars, &possible_chars);
+                        (possible, parameters, score)
                     })
-                    .enumerate()
-                    .min_by_key(|(_, score)| *score)
+                    .filter(|(_, _, score)| score * 3 < ident_chars.len())
+                    .min_by_key(|(_, _, score)| *score)
                 {
-                    {
-                        let guessed: &str = idents[idx];
-                        vec.push(Label::context(
-                            format!(r#"did you mean "{}"?"#, guessed),
-                            ident_span,
-                        ));
-                    }
+                    let required = parameters.iter().filter(|p| p.required).count();
+                    let arity = match (required, parameters.len()) {
+                        (_, 0) => "no arguments".to_owned(),
+                        (1, 1) => "1 argument".to_owned(),
+                        (required, max) if required == max => format!("{} arguments", max),
+                        (required, max) => format!("{} to {} arguments", required, max),
+                    };
+
+                    vec.push(Label::context(
+                        format!(r#"did you mean "{}" ({})?"#, guessed, arity),
+                        ident_span,
+                    ));
                 }
 
                 vec
@@ -1241,6 +1286,37 @@ mod tests {
         }
     }
 
+    fn undefined_function_labels(ident: &str) -> Vec<String> {
+        Error::Undefined {
+            ident_span: Span::new(0, 0),
+            ident: Ident::new(ident),
+            functions: vec![("test", TestFn.parameters())],
+        }
+        .labels()
+        .into_iter()
+        .map(|label| label.message)
+        .collect()
+    }
+
+    #[test]
+    fn undefined_function_suggestion() {
+        assert_eq!(
+            undefined_function_labels("tset"),
+            vec![
+                "undefined function".to_owned(),
+                r#"did you mean "test" (0 to 3 arguments)?"#.to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_function_no_suggestion() {
+        assert_eq!(
+            undefined_function_labels("unknown"),
+            vec!["undefined function".to_owned()]
+        );
+    }
+
     #[cfg(feature = "expr-literal")]
     fn create_node<T>(inner: T) -> Node<T> {
         Node::new(Span::new(0, 0), inner)
@@ -1279,6 +1355,7 @@ mod tests {
             LocalEnv::default(),
             &mut None,
             &mut config,
+            &mut vec![],
         )
         .unwrap()
     }
@@ -17,6 +17,10 @@ impl Group {
             inner: Box::new(inner),
         }
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.inner.complexity()
+    }
 }
 
 impl Expression for Group {
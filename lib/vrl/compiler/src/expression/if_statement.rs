@@ -1,31 +1,53 @@
 use std::fmt;
 
+use diagnostic::{DiagnosticMessage, Label, Note, Severity, Urls};
 use value::Value;
 
 use crate::state::{TypeInfo, TypeState};
 use crate::{
-    expression::{Block, Predicate, Resolved},
+    expression::{Block, Expr, Predicate, Resolved},
     value::VrlValueConvert,
-    Context, Expression,
+    Context, Expression, Span,
 };
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct IfStatement {
     pub predicate: Predicate,
     pub if_block: Block,
-    pub else_block: Option<Block>,
+    pub alternative: Option<Alternative>,
+}
+
+impl IfStatement {
+    /// Both branches are counted, since either of them can run.
+    pub(crate) fn complexity(&self) -> usize {
+        self.predicate.complexity()
+            + self.if_block.complexity()
+            + self.alternative.as_ref().map_or(0, Alternative::complexity)
+    }
 }
 
 impl Expression for IfStatement {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        let predicate = self.predicate.resolve(ctx)?.try_boolean()?;
-
-        match predicate {
-            true => self.if_block.resolve(ctx),
-            false => self
-                .else_block
-                .as_ref()
-                .map_or(Ok(Value::Null), |block| block.resolve(ctx)),
+        // Walk an `else if` chain in a loop, instead of recursing into each link.
+        let mut if_statement = self;
+
+        loop {
+            let predicate = if_statement.predicate.resolve(ctx)?.try_boolean()?;
+
+            #[cfg(feature = "profiling")]
+            if let Some(profile) = ctx.profile_mut() {
+                profile.record_branch(if_statement.predicate.span(), predicate);
+            }
+
+            if predicate {
+                return if_statement.if_block.resolve(ctx);
+            }
+
+            match &if_statement.alternative {
+                Some(Alternative::IfStatement(next)) => if_statement = next,
+                Some(Alternative::Block(block)) => return block.resolve(ctx),
+                None => return Ok(Value::Null),
+            }
         }
     }
 
@@ -33,15 +55,23 @@ impl Expression for IfStatement {
         let mut state = state.clone();
         self.predicate.apply_type_info(&mut state);
 
-        let if_info = self.if_block.type_info(&state);
+        // the "if" block only runs if the predicate holds, which can narrow the
+        // type of the path it checks.
+        let mut if_state = state.clone();
+        #[cfg(feature = "expr-query")]
+        self.predicate.narrow_type_state(&mut if_state);
 
-        if let Some(else_block) = &self.else_block {
-            let else_info = else_block.type_info(&state);
+        let if_info = self.if_block.type_info(&if_state);
 
-            // final state will be from either the "if" or "else" block, but not the original
+        if let Some(alternative) = &self.alternative {
+            let else_info = alternative.type_info(&state);
+
+            // final state will be from either the "if" or "else" branch, but not the original.
+            // A path assigned in both branches can't be undefined in either state, so it is
+            // still known to exist after the merge.
             let final_state = if_info.state.merge(else_info.state);
 
-            // result is from either "if" or the "else" block
+            // result is from either "if" or the "else" branch
             let result = if_info.result.union(else_info.result);
 
             TypeInfo::new(final_state, result)
@@ -64,11 +94,123 @@ impl fmt::Display for IfStatement {
         f.write_str(" ")?;
         self.if_block.fmt(f)?;
 
-        if let Some(alt) = &self.else_block {
-            f.write_str(" else")?;
-            alt.fmt(f)?;
+        match &self.alternative {
+            Some(Alternative::Block(block)) => {
+                f.write_str(" else")?;
+                block.fmt(f)?;
+            }
+            Some(Alternative::IfStatement(if_statement)) => {
+                f.write_str(" else ")?;
+                if_statement.fmt(f)?;
+            }
+            None => {}
         }
 
         Ok(())
     }
 }
+
+/// The branch of an [`IfStatement`] that runs if its predicate doesn't hold.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Alternative {
+    /// `else { .. }`
+    Block(Block),
+
+    /// `else if .. { .. }`
+    IfStatement(Box<IfStatement>),
+}
+
+impl Alternative {
+    /// An `else if` is parsed as an `else` block holding only the nested
+    /// if-statement. That if-statement is taken out of the block, so that a
+    /// chain of them resolves without going through a block for every link.
+    pub(crate) fn from_block(block: Block) -> Self {
+        if !matches!(block.exprs().as_slice(), [Expr::IfStatement(_)]) {
+            return Self::Block(block);
+        }
+
+        match block.into_inner().pop() {
+            Some(Expr::IfStatement(if_statement)) => Self::IfStatement(Box::new(if_statement)),
+            _ => unreachable!("block holds a single if-statement"),
+        }
+    }
+
+    fn complexity(&self) -> usize {
+        match self {
+            Self::Block(block) => block.complexity(),
+            Self::IfStatement(if_statement) => if_statement.complexity(),
+        }
+    }
+
+    fn type_info(&self, state: &TypeState) -> TypeInfo {
+        match self {
+            Self::Block(block) => block.type_info(state),
+            Self::IfStatement(if_statement) => {
+                // Variables assigned in the predicate are scoped the same as
+                // they were in the block the if-statement was taken out of.
+                let parent_locals = state.local.clone();
+                let mut info = if_statement.type_info(state);
+                info.state.local = parent_locals.apply_child_scope(info.state.local);
+
+                info
+            }
+        }
+    }
+}
+
+impl From<Block> for Alternative {
+    fn from(block: Block) -> Self {
+        Self::Block(block)
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+    #[error("unreachable branch")]
+    UnreachableBranch {
+        predicate: bool,
+        predicate_span: Span,
+        block_span: Span,
+    },
+}
+
+impl DiagnosticMessage for Error {
+    fn code(&self) -> usize {
+        use Error::UnreachableBranch;
+
+        match self {
+            UnreachableBranch { .. } => 703,
+        }
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        use Error::UnreachableBranch;
+
+        match self {
+            UnreachableBranch {
+                predicate,
+                predicate_span,
+                block_span,
+            } => vec![
+                Label::primary("this branch is unreachable", block_span),
+                Label::context(
+                    format!("this predicate always resolves to {}", predicate),
+                    predicate_span,
+                ),
+            ],
+        }
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        vec![Note::SeeDocs(
+            "if expressions".to_owned(),
+            Urls::expression_docs_url("#if"),
+        )]
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+}
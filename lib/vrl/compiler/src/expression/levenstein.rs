@@ -1,4 +1,6 @@
-use std::cmp::min;
+use std::{cmp::min, collections::HashMap, hash::Hash};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 fn min3<T>(a: T, b: T, c: T) -> T
 where
@@ -9,9 +11,19 @@ where
 
 // Calculates the damerau-levenstein distance - the number of edits needed to
 // change one word into another, taking into account transposed letters.
-pub(crate) fn distance(word1: &[char], word2: &[char]) -> usize {
-    let m = word1.len() + 1;
-    let n = word2.len() + 1;
+//
+// Unlike the "optimal string alignment" variant, this allows the transposed
+// letters to be edited again, so the triangle inequality holds and suggestions
+// are ranked consistently.
+pub(crate) fn distance<T>(word1: &[T], word2: &[T]) -> usize
+where
+    T: Eq + Hash + Copy,
+{
+    // The matrix has an extra row and column holding an upper bound, so
+    // transpositions never need to check for an out-of-bounds position.
+    let max = word1.len() + word2.len();
+    let m = word1.len() + 2;
+    let n = word2.len() + 2;
 
     // Setup a matrix between the two strings
     let mut matrix = (0..m * n).map(|_| 0_usize).collect::<Vec<_>>();
@@ -19,43 +31,69 @@ pub(crate) fn distance(word1: &[char], word2: &[char]) -> usize {
     // Make it easier to get the correct index in the matrix
     let pos = |a, b| b * m + a;
 
-    for col in 1..m {
-        matrix[pos(col, 0)] = col;
+    matrix[pos(0, 0)] = max;
+
+    for col in 0..m - 1 {
+        matrix[pos(col + 1, 0)] = max;
+        matrix[pos(col + 1, 1)] = col;
     }
 
-    for row in 1..n {
-        matrix[pos(0, row)] = row;
+    for row in 0..n - 1 {
+        matrix[pos(0, row + 1)] = max;
+        matrix[pos(1, row + 1)] = row;
     }
 
-    for row in 1..n {
-        for col in 1..m {
+    // The last row in which each letter of `word2` was seen.
+    let mut last_rows = HashMap::new();
+
+    for row in 1..n - 1 {
+        // The last column in which the letter of this row was seen in `word1`.
+        let mut last_col = 0;
+
+        for col in 1..m - 1 {
+            let last_row = last_rows.get(&word1[col - 1]).copied().unwrap_or(0);
+            let prev_col = last_col;
+
             let cost = if word1[col - 1] == word2[row - 1] {
+                last_col = col;
                 0
             } else {
                 1
             };
 
-            matrix[pos(col, row)] = min3(
-                matrix[pos(col - 1, row)] + 1,
-                matrix[pos(col, row - 1)] + 1,
-                matrix[pos(col - 1, row - 1)] + cost,
+            // Check for transposed letters, with any letters in between them
+            // deleted or inserted.
+            let transposition =
+                matrix[pos(prev_col, last_row)] + (row - last_row - 1) + 1 + (col - prev_col - 1);
+
+            matrix[pos(col + 1, row + 1)] = min(
+                min3(
+                    matrix[pos(col, row + 1)] + 1,
+                    matrix[pos(col + 1, row)] + 1,
+                    matrix[pos(col, row)] + cost,
+                ),
+                transposition,
             );
-
-            // Check for transposed letters.
-            if row > 1
-                && col > 1
-                && word1[col - 1] == word2[row - 2]
-                && word1[col - 2] == word2[row - 1]
-            {
-                matrix[pos(col, row)] =
-                    min(matrix[pos(col, row)], matrix[pos(col - 2, row - 2)] + 1)
-            }
         }
+
+        last_rows.insert(word2[row - 1], row);
     }
 
     matrix[matrix.len() - 1]
 }
 
+// Calculates the distance between the grapheme clusters of two words, so a
+// letter with combining accents, or an emoji made of multiple code points,
+// counts as a single letter.
+//
+// This is slower than calling `distance` with the characters of both words.
+pub(crate) fn grapheme_distance(word1: &str, word2: &str) -> usize {
+    let word1 = word1.graphemes(true).collect::<Vec<_>>();
+    let word2 = word2.graphemes(true).collect::<Vec<_>>();
+
+    distance(&word1, &word2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,5 +163,90 @@ mod tests {
                 &"".chars().collect::<Vec<_>>()
             )
         );
+
+        // Transposed letters can be edited again.
+        assert_eq!(
+            2,
+            distance(
+                &"ca".chars().collect::<Vec<_>>(),
+                &"abc".chars().collect::<Vec<_>>()
+            )
+        );
+    }
+
+    #[test]
+    fn test_grapheme_distance() {
+        // "e" followed by a combining acute accent.
+        assert_eq!(1, grapheme_distance("cafe\u{301}", "cafe"));
+        assert_eq!(0, grapheme_distance("cafe\u{301}", "cafe\u{301}"));
+
+        // A family emoji, made of multiple code points joined together.
+        assert_eq!(
+            1,
+            grapheme_distance("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "")
+        );
+        assert_eq!(1, grapheme_distance("a_\u{1F600}", "a_b"));
+    }
+
+    /// Returns all words of up to `max_len` letters from a small alphabet.
+    fn words(max_len: usize) -> Vec<Vec<char>> {
+        let mut words = vec![vec![]];
+        let mut last = vec![vec![]];
+
+        for _ in 0..max_len {
+            last = last
+                .iter()
+                .flat_map(|word: &Vec<char>| {
+                    ['a', 'b', 'c'].into_iter().map(move |c| {
+                        let mut word = word.clone();
+                        word.push(c);
+                        word
+                    })
+                })
+                .collect();
+
+            words.extend(last.iter().cloned());
+        }
+
+        words
+    }
+
+    #[test]
+    fn test_levenstein_identity() {
+        for word in words(4) {
+            assert_eq!(0, distance(&word, &word), "{:?}", word);
+        }
+    }
+
+    #[test]
+    fn test_levenstein_symmetry() {
+        let words = words(4);
+
+        for a in &words {
+            for b in &words {
+                assert_eq!(distance(a, b), distance(b, a), "{:?} {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_levenstein_triangle_inequality() {
+        let words = words(3);
+
+        for a in &words {
+            for b in &words {
+                let ab = distance(a, b);
+
+                for c in &words {
+                    assert!(
+                        distance(a, c) <= ab + distance(b, c),
+                        "{:?} {:?} {:?}",
+                        a,
+                        b,
+                        c
+                    );
+                }
+            }
+        }
     }
 }
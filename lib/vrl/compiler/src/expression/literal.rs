@@ -44,6 +44,25 @@ impl Literal {
             Null => Value::Null,
         }
     }
+
+    /// Convert a `Value` into a literal.
+    ///
+    /// This returns `None` for values that can't be represented as a literal,
+    /// such as arrays and objects.
+    pub(crate) fn from_value(value: Value) -> Option<Self> {
+        let literal = match value {
+            Value::Bytes(v) => Literal::String(v),
+            Value::Integer(v) => Literal::Integer(v),
+            Value::Float(v) => Literal::Float(v),
+            Value::Boolean(v) => Literal::Boolean(v),
+            Value::Regex(v) => Literal::Regex(v),
+            Value::Timestamp(v) => Literal::Timestamp(v),
+            Value::Null => Literal::Null,
+            Value::Array(_) | Value::Object(_) => return None,
+        };
+
+        Some(literal)
+    }
 }
 
 impl Expression for Literal {
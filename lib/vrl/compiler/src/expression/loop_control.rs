@@ -0,0 +1,105 @@
+use std::fmt;
+
+use diagnostic::{DiagnosticMessage, Label, Note, Urls};
+
+use crate::state::{TypeInfo, TypeState};
+use crate::{expression::Resolved, Context, ControlFlow, Expression, Span, TypeDef};
+
+/// A `break` or `continue` expression, signaling the enclosing loop to stop,
+/// or to skip to its next iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopControl {
+    control_flow: ControlFlow,
+}
+
+impl LoopControl {
+    pub(crate) fn new(control_flow: ControlFlow) -> Self {
+        Self { control_flow }
+    }
+}
+
+impl Expression for LoopControl {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        ctx.set_control_flow(self.control_flow);
+
+        // The error skips the remaining expressions of the loop iteration, up
+        // to the loop, which takes the signal and discards the error.
+        Err(match self.control_flow {
+            ControlFlow::Break => "loop stopped".into(),
+            ControlFlow::Continue => "loop iteration skipped".into(),
+        })
+    }
+
+    fn type_info(&self, state: &TypeState) -> TypeInfo {
+        // No expression after this one runs within the loop iteration.
+        TypeInfo::new(state, TypeDef::never())
+    }
+}
+
+impl fmt::Display for LoopControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.control_flow {
+            ControlFlow::Break => f.write_str("break"),
+            ControlFlow::Continue => f.write_str("continue"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+    #[error("{keyword} outside of a loop")]
+    OutsideLoop { keyword: &'static str, span: Span },
+}
+
+impl DiagnosticMessage for Error {
+    fn code(&self) -> usize {
+        use Error::OutsideLoop;
+
+        match self {
+            OutsideLoop { .. } => 680,
+        }
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        use Error::OutsideLoop;
+
+        match self {
+            OutsideLoop { keyword, span } => vec![Label::primary(
+                format!("`{}` can only be used within a loop", keyword),
+                span,
+            )],
+        }
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        vec![
+            Note::Hint("loops are created by the closure of the `for_each` function".to_owned()),
+            Note::SeeDocs(
+                "loop control expressions".to_owned(),
+                Urls::expression_docs_url("#loop-control"),
+            ),
+        ]
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use super::*;
+    use crate::test_type_def;
+
+    test_type_def![
+        break_is_never {
+            expr: |_| LoopControl::new(ControlFlow::Break),
+            want: TypeDef::never(),
+        }
+
+        continue_is_never {
+            expr: |_| LoopControl::new(ControlFlow::Continue),
+            want: TypeDef::never(),
+        }
+    ];
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use diagnostic::{DiagnosticMessage, Label, Note, Urls};
+use value::Value;
+
+use crate::state::{TypeInfo, TypeState};
+use crate::{
+    expression::{Expr, Resolved},
+    value::VrlValueArithmetic,
+    Context, Expression, Span,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchExpression {
+    pub value: Box<Expr>,
+    pub arms: Vec<MatchArm>,
+    pub default: Option<Box<Expr>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Value,
+    pub expr: Expr,
+}
+
+impl MatchExpression {
+    /// Returns the expressions of all arms, including the default arm (if any),
+    /// in the order in which they are matched.
+    fn arm_exprs(&self) -> impl Iterator<Item = &Expr> {
+        self.arms
+            .iter()
+            .map(|arm| &arm.expr)
+            .chain(self.default.as_deref())
+    }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.value.complexity() + self.arm_exprs().map(Expr::complexity).sum::<usize>()
+    }
+}
+
+impl Expression for MatchExpression {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        match self.arms.iter().find(|arm| arm.pattern.eq_lossy(&value)) {
+            Some(arm) => arm.expr.resolve(ctx),
+            None => self
+                .default
+                .as_ref()
+                .map_or(Ok(Value::Null), |expr| expr.resolve(ctx)),
+        }
+    }
+
+    fn type_info(&self, state: &TypeState) -> TypeInfo {
+        let mut state = state.clone();
+        self.value.apply_type_info(&mut state);
+
+        // Every arm starts from the state right after the value is resolved,
+        // only one of them runs at runtime.
+        let mut arms = self.arm_exprs().map(|expr| expr.type_info(&state));
+
+        let first = arms.next().expect("at least one match arm");
+        let (final_state, result) = arms.fold(
+            (first.state, first.result),
+            |(final_state, result), info| {
+                (final_state.merge(info.state), result.union(info.result))
+            },
+        );
+
+        if self.default.is_some() {
+            TypeInfo::new(final_state, result)
+        } else {
+            // state changes from the arms are optional, so merge it with the original
+            let final_state = final_state.merge(state);
+
+            // if no arm matches, "null" is returned.
+            TypeInfo::new(final_state, result.or_null())
+        }
+    }
+}
+
+impl fmt::Display for MatchExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "match {} {{", self.value)?;
+
+        for arm in &self.arms {
+            writeln!(f, "\t{} => {},", arm.pattern, arm.expr)?;
+        }
+
+        if let Some(default) = &self.default {
+            writeln!(f, "\t_ => {},", default)?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+    #[error("non-literal match pattern")]
+    NonLiteralPattern { span: Span },
+
+    #[error("unreachable match arm")]
+    UnreachableArm { span: Span, default_span: Span },
+}
+
+impl DiagnosticMessage for Error {
+    fn code(&self) -> usize {
+        use Error::{NonLiteralPattern, UnreachableArm};
+
+        match self {
+            NonLiteralPattern { .. } => 670,
+            UnreachableArm { .. } => 671,
+        }
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        use Error::{NonLiteralPattern, UnreachableArm};
+
+        match self {
+            NonLiteralPattern { span } => vec![
+                Label::primary("this pattern must be a literal value", span),
+                Label::context("templated strings can't be used as patterns", span),
+            ],
+            UnreachableArm { span, default_span } => vec![
+                Label::primary("this arm is never matched", span),
+                Label::context("because this arm matches any value", default_span),
+            ],
+        }
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        vec![Note::SeeDocs(
+            "match expressions".to_owned(),
+            Urls::expression_docs_url("#match"),
+        )]
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use super::*;
+    use crate::{expression::Literal, test_type_def, TypeDef};
+
+    fn arm(pattern: impl Into<Value>, expr: impl Into<Literal>) -> MatchArm {
+        MatchArm {
+            pattern: pattern.into(),
+            expr: expr.into().into(),
+        }
+    }
+
+    test_type_def![
+        all_arms_same_type {
+            expr: |_| MatchExpression {
+                value: Box::new(Literal::from("a").into()),
+                arms: vec![arm("a", 1), arm("b", 2)],
+                default: Some(Box::new(Literal::from(3).into())),
+            },
+            want: TypeDef::integer(),
+        }
+
+        arms_union_with_default {
+            expr: |_| MatchExpression {
+                value: Box::new(Literal::from("a").into()),
+                arms: vec![arm("a", 1), arm("b", "two")],
+                default: Some(Box::new(Literal::from(true).into())),
+            },
+            want: TypeDef::integer().or_bytes().or_boolean(),
+        }
+
+        no_default_adds_null {
+            expr: |_| MatchExpression {
+                value: Box::new(Literal::from("a").into()),
+                arms: vec![arm("a", 1)],
+                default: None,
+            },
+            want: TypeDef::integer().or_null(),
+        }
+
+        only_default {
+            expr: |_| MatchExpression {
+                value: Box::new(Literal::from("a").into()),
+                arms: vec![],
+                default: Some(Box::new(Literal::from("x").into())),
+            },
+            want: TypeDef::bytes(),
+        }
+    ];
+}
@@ -34,6 +34,10 @@ impl Not {
             inner: Box::new(expr),
         })
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.inner.complexity()
+    }
 }
 
 impl Expression for Not {
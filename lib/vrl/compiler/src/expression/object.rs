@@ -18,6 +18,10 @@ impl Object {
     pub fn new(inner: BTreeMap<String, Expr>) -> Self {
         Self { inner }
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.inner.values().map(Expr::complexity).sum()
+    }
 }
 
 impl Deref for Object {
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use diagnostic::{DiagnosticMessage, Label, Note, Span, Urls};
-use value::Value;
+use value::{Kind, Value};
 
 use crate::state::{TypeInfo, TypeState};
 use crate::{
@@ -25,11 +25,11 @@ impl Op {
         rhs: Node<Expr>,
         state: &TypeState,
     ) -> Result<Self, Error> {
-        use ast::Opcode::{Eq, Ge, Gt, Le, Lt, Ne};
+        use ast::Opcode::{BitAnd, BitOr, BitXor, Eq, Ge, Gt, Le, Lt, Merge, Ne, Shl, Shr};
 
         let mut state = state.clone();
 
-        let (op_span, opcode) = opcode.take();
+        let (op_span, mut opcode) = opcode.take();
 
         let (lhs_span, lhs) = lhs.take();
         let lhs_type_def = lhs.apply_type_info(&mut state);
@@ -37,6 +37,15 @@ impl Op {
 
         let (rhs_span, rhs) = rhs.take();
 
+        // `|` is a bitwise OR when applied to integers, instead of a merge. One
+        // side has to be known to be an integer, the other one can be unknown.
+        if opcode == Merge
+            && ((lhs_type_def.is_integer() && rhs_type_def.contains_integer())
+                || (lhs_type_def.contains_integer() && rhs_type_def.is_integer()))
+        {
+            opcode = BitOr;
+        }
+
         if matches!(opcode, Eq | Ne | Lt | Le | Gt | Ge) {
             if let Expr::Op(op) = &lhs {
                 if matches!(op.opcode, Eq | Ne | Lt | Le | Gt | Ge) {
@@ -46,7 +55,7 @@ impl Op {
         }
 
         if let ast::Opcode::Err = opcode {
-            if lhs_type_def.is_infallible() {
+            if lhs_type_def.is_infallible() && !lhs_type_def.is_superset(&value::Kind::null()) {
                 return Err(Error::UnnecessaryCoalesce {
                     lhs_span,
                     rhs_span,
@@ -55,7 +64,7 @@ impl Op {
             }
         }
 
-        if let ast::Opcode::Merge = opcode {
+        if let Merge = opcode {
             if !(lhs_type_def.is_object() && rhs_type_def.is_object()) {
                 return Err(Error::MergeNonObjects {
                     lhs_span: if lhs_type_def.is_object() {
@@ -72,21 +81,51 @@ impl Op {
             }
         }
 
+        if matches!(opcode, BitAnd | BitOr | BitXor | Shl | Shr)
+            && !(lhs_type_def.contains_integer() && rhs_type_def.contains_integer())
+        {
+            return Err(Error::BitwiseNonIntegers {
+                lhs_span: if lhs_type_def.contains_integer() {
+                    None
+                } else {
+                    Some(lhs_span)
+                },
+                rhs_span: if rhs_type_def.contains_integer() {
+                    None
+                } else {
+                    Some(rhs_span)
+                },
+            });
+        }
+
         Ok(Op {
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
             opcode,
         })
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.lhs.complexity() + self.rhs.complexity()
+    }
 }
 
 impl Expression for Op {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        use ast::Opcode::{Add, And, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Sub};
+        use ast::Opcode::{
+            Add, And, BitAnd, BitOr, BitXor, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Shl,
+            Shr, Sub,
+        };
         use value::Value::{Boolean, Null};
 
         match self.opcode {
-            Err => return self.lhs.resolve(ctx).or_else(|_| self.rhs.resolve(ctx)),
+            Err => {
+                return match self.lhs.resolve(ctx) {
+                    Ok(value) if !value.is_null() => Ok(value),
+                    Err(err) if ctx.is_unwinding() => Err(err),
+                    _ => self.rhs.resolve(ctx),
+                };
+            }
             Or => {
                 return self
                     .lhs
@@ -118,13 +157,59 @@ impl Expression for Op {
             Lt => lhs.try_lt(rhs),
             Le => lhs.try_le(rhs),
             Merge => lhs.try_merge(rhs),
+            BitAnd => lhs.try_bit_and(rhs),
+            BitOr => lhs.try_bit_or(rhs),
+            BitXor => lhs.try_bit_xor(rhs),
+            Shl => lhs.try_shl(rhs),
+            Shr => lhs.try_shr(rhs),
             And | Or | Err => unreachable!(),
         }
         .map_err(Into::into)
     }
 
+    fn as_value(&self) -> Option<Value> {
+        use ast::Opcode::{
+            Add, And, BitAnd, BitOr, BitXor, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Shl,
+            Shr, Sub,
+        };
+        use value::Value::{Boolean, Null};
+
+        let lhs = self.lhs.as_value()?;
+        let rhs = self.rhs.as_value()?;
+
+        match self.opcode {
+            Err if lhs.is_null() => Ok(rhs),
+            Err => Ok(lhs),
+            Or => lhs.try_or(|| Ok(rhs.clone())),
+            And => match lhs {
+                Null | Boolean(false) => Ok(false.into()),
+                v => v.try_and(rhs),
+            },
+            Mul => lhs.try_mul(rhs),
+            Div => lhs.try_div(rhs),
+            Add => lhs.try_add(rhs),
+            Sub => lhs.try_sub(rhs),
+            Eq => Ok(lhs.eq_lossy(&rhs).into()),
+            Ne => Ok((!lhs.eq_lossy(&rhs)).into()),
+            Gt => lhs.try_gt(rhs),
+            Ge => lhs.try_ge(rhs),
+            Lt => lhs.try_lt(rhs),
+            Le => lhs.try_le(rhs),
+            Merge => lhs.try_merge(rhs),
+            BitAnd => lhs.try_bit_and(rhs),
+            BitOr => lhs.try_bit_or(rhs),
+            BitXor => lhs.try_bit_xor(rhs),
+            Shl => lhs.try_shl(rhs),
+            Shr => lhs.try_shr(rhs),
+        }
+        .ok()
+    }
+
     fn type_info(&self, state: &TypeState) -> TypeInfo {
-        use ast::Opcode::{Add, And, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Sub};
+        use ast::Opcode::{
+            Add, And, BitAnd, BitOr, BitXor, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Shl,
+            Shr, Sub,
+        };
         use value::Kind as K;
 
         let mut state = state.clone();
@@ -137,11 +222,18 @@ impl Expression for Op {
         let rhs_def = self.rhs.apply_type_info(&mut state);
 
         let result = match self.opcode {
-            // ok/err ?? ok
-            Err if rhs_def.is_infallible() => lhs_def.union(rhs_def).infallible(),
+            // ok/err/null ?? ok
+            Err if rhs_def.is_infallible() => {
+                // If the lhs is null, the rhs is returned instead.
+                lhs_def.remove_null();
+                lhs_def.union(rhs_def).infallible()
+            }
 
             // ... ?? ...
-            Err => lhs_def.union(rhs_def),
+            Err => {
+                lhs_def.remove_null();
+                lhs_def.union(rhs_def)
+            }
 
             // null || ...
             Or if lhs_def.is_null() => rhs_def,
@@ -165,6 +257,34 @@ impl Expression for Op {
             // ... | ...
             Merge => lhs_def.merge_overwrite(rhs_def),
 
+            // 1 << 1
+            // 1 >> 1
+            Shl | Shr if lhs_def.is_integer() && rhs_def.is_integer() => {
+                // Shifting is infallible if the rhs is a literal in range.
+                let td = lhs_def.union(rhs_def).with_kind(K::integer());
+
+                match self.rhs.as_value() {
+                    Some(Value::Integer(v)) if (0..64).contains(&v) => td,
+                    _ => td.fallible(),
+                }
+            }
+
+            // 1 & 1
+            // 1 | 1
+            // 1 ^ 1
+            BitAnd | BitOr | BitXor if lhs_def.is_integer() && rhs_def.is_integer() => {
+                lhs_def.union(rhs_def).with_kind(K::integer())
+            }
+
+            // ... & ...
+            // ... | ...
+            // ... ^ ...
+            // ... << ...
+            // ... >> ...
+            BitAnd | BitOr | BitXor | Shl | Shr => {
+                lhs_def.union(rhs_def).fallible().with_kind(K::integer())
+            }
+
             // null && ...
             And if lhs_def.is_null() => rhs_def
                 .fallible_unless(K::null().or_boolean())
@@ -219,6 +339,17 @@ impl Expression for Op {
                 .union(rhs_def.fallible_unless(K::bytes().or_null()))
                 .with_kind(K::bytes()),
 
+            // t'...' + 5m
+            // t'...' - 5m
+            Add | Sub if lhs_def.is_timestamp() && (rhs_def.is_integer() || rhs_def.is_float()) => {
+                lhs_def.union(rhs_def).with_kind(K::timestamp())
+            }
+
+            // t'...' - t'...'
+            Sub if lhs_def.is_timestamp() && rhs_def.is_timestamp() => {
+                lhs_def.union(rhs_def).with_kind(K::float())
+            }
+
             // ... + 1.0
             // ... - 1.0
             // ... * 1.0
@@ -227,10 +358,19 @@ impl Expression for Op {
             // 1.0 - ...
             // 1.0 * ...
             // 1.0 % ...
-            Add | Sub | Mul if lhs_def.is_float() || rhs_def.is_float() => lhs_def
-                .fallible_unless(K::integer().or_float())
-                .union(rhs_def.fallible_unless(K::integer().or_float()))
-                .with_kind(K::float()),
+            Add | Sub | Mul if lhs_def.is_float() || rhs_def.is_float() => {
+                let mut kind = K::float();
+
+                // A timestamp is shifted by the number of seconds instead.
+                if matches!(self.opcode, Add | Sub) && lhs_def.contains_timestamp() {
+                    kind.add_timestamp();
+                }
+
+                lhs_def
+                    .fallible_unless(K::integer().or_float())
+                    .union(rhs_def.fallible_unless(K::integer().or_float()))
+                    .with_kind(kind)
+            }
 
             // 1 + 1
             // 1 - 1
@@ -252,16 +392,26 @@ impl Expression for Op {
 
             // ... + ...
             // ... * ...
-            Add | Mul => lhs_def
-                .union(rhs_def)
-                .fallible()
-                .with_kind(K::bytes().or_integer().or_float()),
+            Add | Mul => {
+                let mut kind = K::bytes().or_integer().or_float();
+
+                if matches!(self.opcode, Add) && lhs_def.contains_timestamp() {
+                    kind.add_timestamp();
+                }
+
+                lhs_def.union(rhs_def).fallible().with_kind(kind)
+            }
 
             // ... - ...
-            Sub => lhs_def
-                .union(rhs_def)
-                .fallible()
-                .with_kind(K::integer().or_float()),
+            Sub => {
+                let mut kind = K::integer().or_float();
+
+                if lhs_def.contains_timestamp() {
+                    kind.add_timestamp();
+                }
+
+                lhs_def.union(rhs_def).fallible().with_kind(kind)
+            }
         };
         TypeInfo::new(state, result)
     }
@@ -299,18 +449,38 @@ pub enum Error {
         rhs_span: Option<Span>,
     },
 
+    #[error("bitwise operators only apply to integers")]
+    BitwiseNonIntegers {
+        lhs_span: Option<Span>,
+        rhs_span: Option<Span>,
+    },
+
+    #[error("unhandled error")]
+    Fallible {
+        op_span: Span,
+        lhs_span: Span,
+        lhs_kind: Kind,
+        rhs_span: Span,
+        rhs_kind: Kind,
+    },
+
     #[error("fallible operation")]
     Expr(#[from] expression::Error),
 }
 
 impl DiagnosticMessage for Error {
     fn code(&self) -> usize {
-        use Error::{ChainedComparison, Expr, MergeNonObjects, UnnecessaryCoalesce};
+        use Error::{
+            BitwiseNonIntegers, ChainedComparison, Expr, Fallible, MergeNonObjects,
+            UnnecessaryCoalesce,
+        };
 
         match self {
+            Fallible { .. } => 100,
             ChainedComparison { .. } => 650,
             UnnecessaryCoalesce { .. } => 651,
             MergeNonObjects { .. } => 652,
+            BitwiseNonIntegers { .. } => 653,
             Expr(err) => err.code(),
         }
     }
@@ -325,9 +495,29 @@ impl DiagnosticMessage for Error {
     }
 
     fn labels(&self) -> Vec<Label> {
-        use Error::{ChainedComparison, Expr, MergeNonObjects, UnnecessaryCoalesce};
+        use Error::{
+            BitwiseNonIntegers, ChainedComparison, Expr, Fallible, MergeNonObjects,
+            UnnecessaryCoalesce,
+        };
 
         match self {
+            Fallible {
+                op_span,
+                lhs_span,
+                lhs_kind,
+                rhs_span,
+                rhs_kind,
+            } => vec![
+                Label::primary("expression can result in runtime error", op_span),
+                Label::context(
+                    format!("this expression resolves to {}", lhs_kind),
+                    lhs_span,
+                ),
+                Label::context(
+                    format!("this expression resolves to {}", rhs_kind),
+                    rhs_span,
+                ),
+            ],
             ChainedComparison { span } => vec![Label::primary("", span)],
             UnnecessaryCoalesce {
                 lhs_span,
@@ -355,14 +545,20 @@ impl DiagnosticMessage for Error {
 
                 labels
             }
+            BitwiseNonIntegers { lhs_span, rhs_span } => [lhs_span, rhs_span]
+                .into_iter()
+                .flatten()
+                .map(|span| Label::primary("this expression must resolve to an integer", span))
+                .collect(),
             Expr(err) => err.labels(),
         }
     }
 
     fn notes(&self) -> Vec<Note> {
-        use Error::{ChainedComparison, Expr};
+        use Error::{ChainedComparison, Expr, Fallible};
 
         match self {
+            Fallible { .. } => vec![Note::SeeErrorDocs],
             ChainedComparison { .. } => vec![Note::SeeDocs(
                 "comparisons".to_owned(),
                 Urls::expression_docs_url("#comparison"),
@@ -381,8 +577,12 @@ mod tests {
 
     use ast::{
         Ident,
-        Opcode::{Add, And, Div, Eq, Err, Ge, Gt, Le, Lt, Mul, Ne, Or, Sub},
+        Opcode::{
+            Add, And, BitAnd, BitOr, BitXor, Div, Eq, Err, Ge, Gt, Le, Lt, Mul, Ne, Or, Shl, Shr,
+            Sub,
+        },
     };
+    use chrono::{DateTime, TimeZone, Utc};
     use ordered_float::NotNan;
 
     use super::*;
@@ -419,6 +619,10 @@ mod tests {
         NotNan::new(f).unwrap()
     }
 
+    fn timestamp() -> DateTime<Utc> {
+        Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)
+    }
+
     test_type_def![
         or_exact {
             expr: |_| op(Or, "foo", true),
@@ -530,6 +734,26 @@ mod tests {
             want: TypeDef::integer().fallible().or_float(),
         }
 
+        add_timestamp_seconds {
+            expr: |_| op(Add, timestamp(), 300.0),
+            want: TypeDef::timestamp().infallible(),
+        }
+
+        subtract_timestamp_seconds {
+            expr: |_| op(Sub, timestamp(), 5),
+            want: TypeDef::timestamp().infallible(),
+        }
+
+        subtract_timestamps {
+            expr: |_| op(Sub, timestamp(), timestamp()),
+            want: TypeDef::float().infallible(),
+        }
+
+        add_seconds_timestamp {
+            expr: |_| op(Add, 5, timestamp()),
+            want: TypeDef::bytes().or_integer().or_float().fallible(),
+        }
+
         divide_integer_literal {
             expr: |_| op(Div, 1, 1),
             want: TypeDef::float().infallible(),
@@ -715,6 +939,41 @@ mod tests {
             want: TypeDef::boolean().fallible(),
         }
 
+        bit_and_integer {
+            expr: |_| op(BitAnd, 6, 3),
+            want: TypeDef::integer().infallible(),
+        }
+
+        bit_or_integer {
+            expr: |_| op(BitOr, 6, 3),
+            want: TypeDef::integer().infallible(),
+        }
+
+        bit_xor_integer {
+            expr: |_| op(BitXor, 6, 3),
+            want: TypeDef::integer().infallible(),
+        }
+
+        bit_and_other {
+            expr: |_| op(BitAnd, 6, "foo"),
+            want: TypeDef::integer().fallible(),
+        }
+
+        shift_left_in_range {
+            expr: |_| op(Shl, 1, 63),
+            want: TypeDef::integer().infallible(),
+        }
+
+        shift_right_out_of_range {
+            expr: |_| op(Shr, 1, 64),
+            want: TypeDef::integer().fallible(),
+        }
+
+        shift_negative {
+            expr: |_| op(Shl, 1, -1),
+            want: TypeDef::integer().fallible(),
+        }
+
         error_or_rhs_infallible {
             expr: |_| Op {
                 lhs: Box::new(Op {
@@ -766,13 +1025,45 @@ mod tests {
             want: TypeDef::float().or_bytes(),
         }
 
+        null_or_rhs_infallible {
+            expr: |_| Op {
+                lhs: Box::new(
+                    IfStatement {
+                        predicate: Predicate::new_unchecked(vec![Literal::from(true).into()]),
+                        if_block: Block::new_scoped(vec![Literal::from("string").into()]),
+                        alternative: None,
+                    }.into()),
+                rhs: Box::new(Literal::from(1).into()),
+                opcode: Err,
+            },
+            want: TypeDef::bytes().or_integer(),
+        }
+
+        null_or_rhs_fallible {
+            expr: |_| Op {
+                lhs: Box::new(
+                    IfStatement {
+                        predicate: Predicate::new_unchecked(vec![Literal::from(true).into()]),
+                        if_block: Block::new_scoped(vec![Literal::from("string").into()]),
+                        alternative: None,
+                    }.into()),
+                rhs: Box::new(Op {
+                    lhs: Box::new(Literal::from(true).into()),
+                    rhs: Box::new(Literal::from(1).into()),
+                    opcode: Div,
+                }.into()),
+                opcode: Err,
+            },
+            want: TypeDef::bytes().or_float().fallible(),
+        }
+
         or_nullable {
             expr: |_| Op {
                 lhs: Box::new(
                     IfStatement {
                         predicate: Predicate::new_unchecked(vec![Literal::from(true).into()]),
                         if_block: Block::new_scoped(vec![Literal::from("string").into()]),
-                        else_block: None,
+                        alternative: None,
                     }.into()),
                 rhs: Box::new(Literal::from("another string").into()),
                 opcode: Or,
@@ -786,7 +1077,7 @@ mod tests {
                     IfStatement {
                         predicate: Predicate::new_unchecked(vec![Literal::from(true).into()]),
                         if_block: Block::new_scoped(vec![Literal::from("string").into()]),
-                        else_block:  Some(Block::new_scoped(vec![Literal::from(42).into()]))
+                        alternative: Some(Block::new_scoped(vec![Literal::from(42).into()]).into())
                 }.into()),
                 rhs: Box::new(Literal::from("another string").into()),
                 opcode: Or,
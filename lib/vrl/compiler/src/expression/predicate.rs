@@ -1,21 +1,34 @@
 use std::fmt;
 
 use diagnostic::{DiagnosticMessage, Label, Note, Urls};
+use value::Value;
 
 use crate::expression::Block;
+#[cfg(feature = "expr-literal")]
+use crate::expression::Literal;
+#[cfg(feature = "expr-query")]
+use crate::expression::{query, Query};
+#[cfg(feature = "expr-query")]
+use crate::type_def::Details;
 use crate::{
     expression::{Expr, Resolved},
     parser::Node,
     state::{TypeInfo, TypeState},
     value::Kind,
-    Context, Expression, Span,
+    Context, Expression, Span, TypeDef,
 };
+#[cfg(feature = "expr-query")]
+use lookup::{lookup_v2::OwnedSegment, LookupBuf, PathPrefix};
 
 pub(crate) type Result = std::result::Result<Predicate, Error>;
 
 #[derive(Clone, PartialEq)]
 pub struct Predicate {
     inner: Block,
+    span: Span,
+
+    /// Whether the predicate is coerced into a boolean, see [`is_truthy`].
+    truthy: bool,
 }
 
 impl Predicate {
@@ -23,6 +36,7 @@ impl Predicate {
         node: Node<Vec<Expr>>,
         state: &TypeState,
         fallible_predicate: Option<&dyn DiagnosticMessage>,
+        truthy: bool,
     ) -> Result {
         let (span, exprs) = node.take();
 
@@ -36,31 +50,234 @@ impl Predicate {
 
         let block = Block::new_inline(exprs);
         let type_def = block.type_info(state).result;
-        if !type_def.is_boolean() {
+        if !truthy && !type_def.is_boolean() {
             return Err(Error::NonBoolean {
                 kind: type_def.into(),
                 span,
             });
         }
 
-        Ok(Self { inner: block })
+        Ok(Self {
+            inner: block,
+            span,
+            truthy,
+        })
     }
 
     #[must_use]
     pub fn new_unchecked(inner: Vec<Expr>) -> Self {
         Self {
             inner: Block::new_inline(inner),
+            span: Span::default(),
+            truthy: false,
+        }
+    }
+
+    /// The span of the predicate in the program source.
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the value of the predicate if it consists of a single boolean
+    /// literal, and thus always resolves to the same value.
+    #[cfg(feature = "expr-literal")]
+    pub(crate) fn as_literal(&self) -> Option<bool> {
+        match self.inner.exprs().as_slice() {
+            [Expr::Literal(Literal::Boolean(value))] => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Narrows the type of the path checked by the predicate, assuming the
+    /// predicate resolved to `true`.
+    ///
+    /// Only a single guard expression on a variable or a simple path is
+    /// recognized (see [`Guard`]), any other predicate leaves the state
+    /// untouched.
+    #[cfg(feature = "expr-query")]
+    pub(crate) fn narrow_type_state(&self, state: &mut TypeState) {
+        let (expr, guard) = match self.guard() {
+            Some(guard) => guard,
+            None => return,
+        };
+
+        let query = match expr {
+            Expr::Query(query) if is_simple_path(query) => query,
+            Expr::Variable(variable) => {
+                let ident = variable.ident();
+                if let Some(Details { type_def, value }) = state.local.variable(ident).cloned() {
+                    if let Some(kind) = guard.narrow(type_def.into()) {
+                        let details = Details {
+                            type_def: kind.into(),
+                            value,
+                        };
+                        state.local.insert_variable(ident.clone(), details);
+                    }
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        let kind = match guard.narrow(query.type_info(state).result.into()) {
+            Some(kind) => kind,
+            None => return,
+        };
+
+        let path = LookupBuf::from(query.path().clone());
+
+        match query.target() {
+            query::Target::External(PathPrefix::Event) => {
+                let Details { type_def, value } = state.external.target().clone();
+                state.external.update_target(Details {
+                    type_def: type_def.with_type_inserted(&path, kind.into()),
+                    value,
+                });
+            }
+            query::Target::External(PathPrefix::Metadata) => {
+                let mut metadata = state.external.metadata_kind().clone();
+                metadata.insert(query.path(), kind);
+                state.external.update_metadata(metadata);
+            }
+            query::Target::Internal(variable) => {
+                if let Some(Details { type_def, value }) =
+                    state.local.variable(variable.ident()).cloned()
+                {
+                    let details = Details {
+                        type_def: type_def.with_type_inserted(&path, kind.into()),
+                        value,
+                    };
+                    state
+                        .local
+                        .insert_variable(variable.ident().clone(), details);
+                }
+            }
+            query::Target::FunctionCall(_) | query::Target::Container(_) => {}
+        }
+    }
+
+    #[cfg(feature = "expr-query")]
+    fn guard(&self) -> Option<(&Expr, Guard)> {
+        let (expr, guard) = match self.inner.exprs().as_slice() {
+            #[cfg(feature = "expr-function_call")]
+            [Expr::FunctionCall(call)] => {
+                let guard = match call.ident {
+                    "exists" => Guard::Exists,
+                    "is_string" => Guard::String,
+                    _ => return None,
+                };
+
+                match call.arguments() {
+                    [argument] => (argument.inner().expr(), guard),
+                    _ => return None,
+                }
+            }
+            #[cfg(all(feature = "expr-op", feature = "expr-literal"))]
+            [Expr::Op(op)] if op.opcode == crate::parser::ast::Opcode::Ne => {
+                match (&*op.lhs, &*op.rhs) {
+                    (expr, Expr::Literal(Literal::Null)) | (Expr::Literal(Literal::Null), expr) => {
+                        (expr, Guard::NotNull)
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        Some((expr, guard))
+    }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.inner.complexity()
+    }
+}
+
+/// A query on an event, metadata or variable path without coalescing.
+#[cfg(feature = "expr-query")]
+fn is_simple_path(query: &Query) -> bool {
+    let simple_target = matches!(
+        query.target(),
+        query::Target::External(_) | query::Target::Internal(_)
+    );
+
+    simple_target
+        && query
+            .path()
+            .segments
+            .iter()
+            .all(|segment| matches!(segment, OwnedSegment::Field(_) | OwnedSegment::Index(_)))
+}
+
+/// A predicate that, when `true`, guarantees something about the type of the
+/// path it checks.
+#[cfg(feature = "expr-query")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Guard {
+    /// `exists(path)`: the path is defined, but can still be `null`.
+    Exists,
+
+    /// `is_string(path)`: the path is a string.
+    String,
+
+    /// `path != null`: the path is neither `null` nor undefined.
+    NotNull,
+}
+
+#[cfg(feature = "expr-query")]
+impl Guard {
+    /// Returns the narrowed type, or `None` if the guard can never hold for
+    /// the given type, in which case the type is left as-is.
+    fn narrow(self, mut kind: Kind) -> Option<Kind> {
+        match self {
+            Guard::Exists => {
+                kind.remove_undefined();
+            }
+            Guard::String if kind.contains_bytes() => kind = Kind::bytes(),
+            Guard::String => return None,
+            Guard::NotNull => {
+                kind.remove_null();
+                kind.remove_undefined();
+            }
         }
+
+        (!kind.is_never()).then_some(kind)
+    }
+}
+
+/// Coerces the value of a truthy predicate into a boolean. Only `false`,
+/// `null`, an empty string and zero are considered `false`.
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Boolean(value) => *value,
+        Value::Null => false,
+        Value::Bytes(value) => !value.is_empty(),
+        Value::Integer(value) => *value != 0,
+        Value::Float(value) => value.into_inner().abs() > 0.0,
+        Value::Regex(_) | Value::Timestamp(_) | Value::Object(_) | Value::Array(_) => true,
     }
 }
 
 impl Expression for Predicate {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        self.inner.resolve(ctx)
+        let value = self.inner.resolve(ctx)?;
+
+        if self.truthy {
+            Ok(is_truthy(&value).into())
+        } else {
+            Ok(value)
+        }
     }
 
     fn type_info(&self, state: &TypeState) -> TypeInfo {
-        self.inner.type_info(state)
+        let info = self.inner.type_info(state);
+
+        if self.truthy {
+            let result = TypeDef::boolean().with_fallibility(info.result.is_fallible());
+            TypeInfo::new(info.state, result)
+        } else {
+            info
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    expression::{Container, Resolved, Variable},
+    expression::{cost, Container, Resolved, Variable},
     parser::ast::Ident,
     state::ExternalEnv,
     state::{TypeInfo, TypeState},
@@ -95,6 +95,19 @@ impl Query {
             }
         }
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        let target = match &self.target {
+            Target::Internal(_) | Target::External(_) => 0,
+            #[cfg(feature = "expr-function_call")]
+            Target::FunctionCall(call) => call.complexity(),
+            #[cfg(not(feature = "expr-function_call"))]
+            Target::FunctionCall(_) => 0,
+            Target::Container(container) => container.complexity(),
+        };
+
+        target + cost::PATH_ACCESS
+    }
 }
 
 impl Expression for Query {
@@ -189,7 +202,7 @@ impl fmt::Display for Target {
             Internal(v) => v.fmt(f),
             External(prefix) => match prefix {
                 PathPrefix::Event => write!(f, "."),
-                PathPrefix::Metadata => write!(f, "@"),
+                PathPrefix::Metadata => write!(f, "%"),
             },
             FunctionCall(v) => v.fmt(f),
             Container(v) => v.fmt(f),
@@ -234,4 +247,20 @@ mod tests {
 
         assert!(object.is_any());
     }
+
+    #[test]
+    fn test_display() {
+        let event = Query {
+            target: Target::External(PathPrefix::Event),
+            path: lookup::lookup_v2::parse_value_path("foo.bar"),
+        };
+
+        let metadata = Query {
+            target: Target::External(PathPrefix::Metadata),
+            path: lookup::lookup_v2::parse_value_path("foo.bar"),
+        };
+
+        assert_eq!(event.to_string(), ".foo.bar");
+        assert_eq!(metadata.to_string(), "%foo.bar");
+    }
 }
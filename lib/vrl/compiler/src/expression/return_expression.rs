@@ -0,0 +1,114 @@
+use std::fmt;
+
+use diagnostic::{DiagnosticMessage, Label, Note, Urls};
+
+use crate::state::{TypeInfo, TypeState};
+use crate::{
+    expression::{Expr, Resolved},
+    Context, Expression, Span, TypeDef,
+};
+
+/// A `return` expression, stopping the program and resolving it to the given
+/// value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Return {
+    value: Box<Expr>,
+}
+
+impl Return {
+    pub(crate) fn new(value: Expr) -> Self {
+        Self {
+            value: Box::new(value),
+        }
+    }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.value.complexity()
+    }
+}
+
+impl Expression for Return {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        ctx.set_return_value(value);
+
+        // The error skips the remainder of the program, which then resolves to
+        // the returned value.
+        Err("program returned".into())
+    }
+
+    fn type_info(&self, state: &TypeState) -> TypeInfo {
+        let info = self.value.type_info(state);
+
+        // No expression after this one runs, the value is tracked as a result
+        // of the program instead.
+        let result = TypeDef::never().with_fallibility(info.result.is_fallible());
+
+        TypeInfo::new(info.state, result)
+    }
+}
+
+impl fmt::Display for Return {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "return {}", self.value)
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+    #[error("return inside a closure")]
+    InsideClosure { span: Span },
+}
+
+impl DiagnosticMessage for Error {
+    fn code(&self) -> usize {
+        use Error::InsideClosure;
+
+        match self {
+            InsideClosure { .. } => 681,
+        }
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        use Error::InsideClosure;
+
+        match self {
+            InsideClosure { span } => vec![Label::primary(
+                "`return` can't be used within a closure",
+                span,
+            )],
+        }
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        vec![
+            Note::Hint("use `break` to stop a `for_each` loop early".to_owned()),
+            Note::SeeDocs(
+                "return expressions".to_owned(),
+                Urls::expression_docs_url("#return"),
+            ),
+        ]
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use super::*;
+    use crate::{expression::Literal, test_type_def};
+
+    test_type_def![
+        return_is_never {
+            expr: |_| Return::new(Literal::from(1).into()),
+            want: TypeDef::never(),
+        }
+
+        return_object_is_never {
+            expr: |_| Return::new(Literal::from("foo").into()),
+            want: TypeDef::never(),
+        }
+    ];
+}
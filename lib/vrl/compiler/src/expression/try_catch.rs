@@ -0,0 +1,154 @@
+use std::fmt;
+
+use diagnostic::{DiagnosticMessage, Label};
+
+use crate::state::{TypeInfo, TypeState};
+use crate::{
+    expression::{Block, Resolved},
+    parser::Node,
+    Context, Expression, Span,
+};
+
+/// A `try { .. } catch { .. }` expression, resolving the fallback block if the
+/// try block fails.
+///
+/// Changes made by the try block before it failed aren't undone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Try {
+    block: Block,
+    fallback: Block,
+}
+
+impl Try {
+    pub(crate) fn new(
+        block: Node<Block>,
+        fallback: Node<Block>,
+        state: &TypeState,
+    ) -> Result<Self, Error> {
+        let (block_span, block) = block.take();
+        let (fallback_span, fallback) = fallback.take();
+
+        if block.type_info(state).result.is_infallible() {
+            return Err(Error::UnnecessaryTry {
+                block_span,
+                fallback_span,
+            });
+        }
+
+        Ok(Self { block, fallback })
+    }
+
+    pub(crate) fn complexity(&self) -> usize {
+        self.block.complexity() + self.fallback.complexity()
+    }
+}
+
+impl Expression for Try {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        match self.block.resolve(ctx) {
+            Ok(value) => Ok(value),
+            #[cfg(feature = "expr-abort")]
+            Err(err @ crate::expression::ExpressionError::Abort { .. }) => Err(err),
+            Err(err) if ctx.is_unwinding() => Err(err),
+            Err(_) => self.fallback.resolve(ctx),
+        }
+    }
+
+    fn type_info(&self, state: &TypeState) -> TypeInfo {
+        let block_info = self.block.type_info(state);
+
+        // The try block can fail after any of its expressions, so the fallback
+        // starts from either the original state or the state after the block.
+        let fallback_state = state.clone().merge(block_info.state.clone());
+        let fallback_info = self.fallback.type_info(&fallback_state);
+
+        // Errors of the try block are handled, only the fallback can fail.
+        let result = block_info.result.infallible().union(fallback_info.result);
+
+        TypeInfo::new(block_info.state.merge(fallback_info.state), result)
+    }
+}
+
+impl fmt::Display for Try {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "try {} catch {}", self.block, self.fallback)
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+    #[error("unnecessary try expression")]
+    UnnecessaryTry {
+        block_span: Span,
+        fallback_span: Span,
+    },
+}
+
+impl DiagnosticMessage for Error {
+    fn code(&self) -> usize {
+        use Error::UnnecessaryTry;
+
+        match self {
+            UnnecessaryTry { .. } => 690,
+        }
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        use Error::UnnecessaryTry;
+
+        match self {
+            UnnecessaryTry {
+                block_span,
+                fallback_span,
+            } => vec![
+                Label::primary("this block can't fail", block_span),
+                Label::context("this block never runs", fallback_span),
+            ],
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use super::*;
+    use crate::{
+        expression::{Expr, Literal},
+        test_type_def, TypeDef,
+    };
+
+    fn block(expr: impl Into<Expr>) -> Block {
+        Block::new_scoped(vec![expr.into()])
+    }
+
+    test_type_def![
+        union_of_both_blocks {
+            expr: |_| Try {
+                block: block(Literal::from(1)),
+                fallback: block(Literal::from("one")),
+            },
+            want: TypeDef::integer().or_bytes(),
+        }
+
+        same_type {
+            expr: |_| Try {
+                block: block(Literal::from(1)),
+                fallback: block(Literal::from(2)),
+            },
+            want: TypeDef::integer(),
+        }
+    ];
+
+    #[test]
+    fn infallible_block_is_rejected() {
+        let try_block = Node::new(Span::new(0, 1), block(Literal::from(1)));
+        let fallback = Node::new(Span::new(2, 3), block(Literal::from(2)));
+
+        let error = Try::new(try_block, fallback, &TypeState::default()).unwrap_err();
+
+        assert_eq!(error.code(), 690);
+    }
+}
@@ -16,6 +16,12 @@ impl Unary {
     pub fn new(variant: Variant) -> Self {
         Self { variant }
     }
+
+    pub(crate) fn complexity(&self) -> usize {
+        match &self.variant {
+            Variant::Not(v) => v.complexity(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1,5 +1,6 @@
-use diagnostic::{DiagnosticMessage, Label};
+use diagnostic::{DiagnosticMessage, Label, Note, Severity};
 use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
 use value::Value;
 
 use crate::state::{TypeInfo, TypeState};
@@ -10,10 +11,17 @@ use crate::{
     Context, Expression, Span, TypeDef,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// The built-in constants, which look like identifiers but can't be used as
+/// variable names.
+pub(crate) const BUILTIN_CONSTANTS: [&str; 3] = ["null", "true", "false"];
+
+#[derive(Debug, Clone)]
 pub struct Variable {
     ident: Ident,
     value: Option<Value>,
+
+    // used for attributing runtime errors to the variable in the source.
+    span: Span,
 }
 
 impl Variable {
@@ -29,7 +37,7 @@ impl Variable {
             return Err(Error::undefined(ident, span, idents));
         };
 
-        Ok(Self { ident, value })
+        Ok(Self { ident, value, span })
     }
 
     pub fn ident(&self) -> &Ident {
@@ -39,8 +47,24 @@ impl Variable {
     pub fn value(&self) -> Option<&Value> {
         self.value.as_ref()
     }
+
+    /// The location of the variable in the source.
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+// The span is ignored, two references to the same variable are equal
+// regardless of where they're located in the source.
+impl PartialEq for Variable {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident && self.value == other.value
+    }
 }
 
+impl Eq for Variable {}
+
 impl Expression for Variable {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         Ok(ctx
@@ -81,12 +105,34 @@ impl Error {
             span,
         }
     }
+
+    pub(crate) fn unused(ident: Ident, span: Span) -> Self {
+        Error {
+            variant: ErrorVariant::Unused,
+            ident,
+            span,
+        }
+    }
+
+    pub(crate) fn builtin_constant(ident: Ident, span: Span) -> Self {
+        Error {
+            variant: ErrorVariant::BuiltinConstant,
+            ident,
+            span,
+        }
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ErrorVariant {
     #[error("call to undefined variable")]
     Undefined { idents: Vec<Ident> },
+
+    #[error("unused variable")]
+    Unused,
+
+    #[error("variable name is a built-in constant")]
+    BuiltinConstant,
 }
 
 impl fmt::Display for Error {
@@ -103,46 +149,172 @@ impl std::error::Error for Error {
 
 impl DiagnosticMessage for Error {
     fn code(&self) -> usize {
-        use ErrorVariant::Undefined;
+        use ErrorVariant::{BuiltinConstant, Undefined, Unused};
 
         match &self.variant {
             Undefined { .. } => 701,
+            Unused => 702,
+            BuiltinConstant => 706,
         }
     }
 
     fn labels(&self) -> Vec<Label> {
-        use ErrorVariant::Undefined;
+        use ErrorVariant::{BuiltinConstant, Undefined, Unused};
 
         match &self.variant {
             Undefined { idents } => {
                 let mut vec = vec![Label::primary("undefined variable", self.span)];
-                let ident_chars = self.ident.as_ref().chars().collect::<Vec<_>>();
 
-                let mut builtin = vec![Ident::new("null"), Ident::new("true"), Ident::new("false")];
+                let mut builtin = BUILTIN_CONSTANTS.map(Ident::new).to_vec();
                 let mut idents = idents.clone();
 
                 idents.append(&mut builtin);
 
-                if let Some((idx, _)) = idents
+                // Don't suggest anything for identifiers that are nowhere
+                // close to any known variable.
+                let max_score = std::cmp::max(2, self.ident.as_ref().graphemes(true).count() / 2);
+
+                let mut candidates = idents
                     .iter()
                     .map(|possible| {
-                        let possible_chars = possible.chars().collect::<Vec<_>>();
-                        levenstein::distance(&ident_chars, &possible_chars)
+                        let score = levenstein::grapheme_distance(&self.ident, possible);
+                        (score, &**possible)
                     })
-                    .enumerate()
-                    .min_by_key(|(_, score)| *score)
-                {
-                    {
-                        let guessed = &idents[idx];
-                        vec.push(Label::context(
-                            format!(r#"did you mean "{}"?"#, guessed),
-                            self.span,
-                        ));
-                    }
+                    .filter(|(score, _)| *score <= max_score)
+                    .collect::<Vec<_>>();
+
+                // Rank by distance, with ties broken alphabetically to keep
+                // the diagnostic deterministic.
+                candidates.sort_unstable();
+                candidates.dedup();
+
+                let guesses = candidates
+                    .into_iter()
+                    .take(3)
+                    .map(|(_, guessed)| format!(r#""{}""#, guessed))
+                    .collect::<Vec<_>>();
+
+                let guessed = match guesses.as_slice() {
+                    [] => None,
+                    [one] => Some(one.clone()),
+                    [one, two] => Some(format!("{} or {}", one, two)),
+                    [rest @ .., last] => Some(format!("{}, or {}", rest.join(", "), last)),
+                };
+
+                if let Some(guessed) = guessed {
+                    vec.push(Label::context(
+                        format!("did you mean {}?", guessed),
+                        self.span,
+                    ));
                 }
 
                 vec
             }
+            Unused => vec![Label::primary("unused variable", self.span)],
+            BuiltinConstant => vec![
+                Label::primary(
+                    format!(r#""{}" is a built-in constant"#, self.ident),
+                    self.span,
+                ),
+                Label::context("use a different variable name", self.span),
+            ],
         }
     }
+
+    fn notes(&self) -> Vec<Note> {
+        use ErrorVariant::{BuiltinConstant, Undefined, Unused};
+
+        match &self.variant {
+            Undefined { .. } | BuiltinConstant => vec![],
+            Unused => vec![Note::Hint(format!(
+                r#"if this is intentional, prefix it with an underscore: "_{}""#,
+                self.ident
+            ))],
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        use ErrorVariant::{BuiltinConstant, Undefined, Unused};
+
+        match &self.variant {
+            Undefined { .. } | BuiltinConstant => Severity::Error,
+            Unused => Severity::Warning,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_def::Details;
+
+    fn undefined_labels(ident: &str, idents: &[&str]) -> Vec<String> {
+        let idents = idents.iter().map(|ident| Ident::new(*ident)).collect();
+
+        Error::undefined(Ident::new(ident), Span::new(0, 0), idents)
+            .labels()
+            .into_iter()
+            .map(|label| label.message)
+            .collect()
+    }
+
+    #[test]
+    fn undefined_close_typo() {
+        assert_eq!(
+            undefined_labels("my_varable", &["my_variable"]),
+            vec![
+                "undefined variable".to_owned(),
+                r#"did you mean "my_variable"?"#.to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_ranked_suggestions() {
+        assert_eq!(
+            undefined_labels("foo", &["fob", "foe", "fooo", "bar", "fo"]),
+            vec![
+                "undefined variable".to_owned(),
+                r#"did you mean "fo", "fob", or "foe"?"#.to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_two_suggestions() {
+        assert_eq!(
+            undefined_labels("nul", &["nil"]),
+            vec![
+                "undefined variable".to_owned(),
+                r#"did you mean "nil" or "null"?"#.to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_far_off_identifier() {
+        assert_eq!(
+            undefined_labels("xyzzy", &["my_variable"]),
+            vec!["undefined variable".to_owned()]
+        );
+    }
+
+    #[test]
+    fn span_is_retained_but_ignored_for_equality() {
+        let mut local = LocalEnv::default();
+        local.insert_variable(
+            Ident::new("foo"),
+            Details {
+                type_def: TypeDef::integer(),
+                value: Some(1.into()),
+            },
+        );
+
+        let a = Variable::new(Span::new(0, 3), Ident::new("foo"), &local).unwrap();
+        let b = Variable::new(Span::new(10, 13), Ident::new("foo"), &local).unwrap();
+
+        assert_eq!(a.span(), Span::new(0, 3));
+        assert_eq!(b.span(), Span::new(10, 13));
+        assert_eq!(a, b);
+    }
 }
@@ -82,12 +82,17 @@ pub struct Example {
 pub struct FunctionCompileContext {
     span: Span,
     config: CompileConfig,
+    warnings: Vec<Box<dyn DiagnosticMessage>>,
 }
 
 impl FunctionCompileContext {
     #[must_use]
     pub fn new(span: Span, config: CompileConfig) -> Self {
-        Self { span, config }
+        Self {
+            span,
+            config,
+            warnings: vec![],
+        }
     }
 
     /// Span information for the function call.
@@ -112,11 +117,25 @@ impl FunctionCompileContext {
         self.config.is_read_only_path(path)
     }
 
+    /// Report a warning about the function call.
+    ///
+    /// Warnings don't stop the program from compiling, they are returned
+    /// alongside the compiled program.
+    pub fn push_warning(&mut self, warning: impl DiagnosticMessage + 'static) {
+        self.warnings.push(Box::new(warning));
+    }
+
     /// Consume the `FunctionCompileContext`, returning the (potentially mutated) `AnyMap`.
     #[must_use]
     pub fn into_config(self) -> CompileConfig {
         self.config
     }
+
+    /// Consume the `FunctionCompileContext`, returning the (potentially mutated) `AnyMap`,
+    /// and any warnings reported by the function.
+    pub(crate) fn into_parts(self) -> (CompileConfig, Vec<Box<dyn DiagnosticMessage>>) {
+        (self.config, self.warnings)
+    }
 }
 
 // -----------------------------------------------------------------------------
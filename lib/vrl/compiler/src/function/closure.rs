@@ -29,6 +29,14 @@ pub struct Definition {
     /// collection elements to determine the eventual type definition of the
     /// closure variable(s) (see `Variable`).
     pub is_iterator: bool,
+
+    /// Defines whether `break` and `continue` can be used within the closure.
+    ///
+    /// This should only be enabled for closures whose return value is
+    /// ignored, and requires the function to call `Context::take_control_flow`
+    /// after each run of the closure. A run stopped by `break` or `continue`
+    /// returns an error, which the function discards if a signal was taken.
+    pub supports_loop_control: bool,
 }
 
 /// One input variant for a function-closure.
@@ -177,12 +185,12 @@ where
         let old_key = insert(ctx.state_mut(), key_ident, cloned_key.into());
         let old_value = insert(ctx.state_mut(), value_ident, cloned_value);
 
-        let value = (self.runner)(ctx)?;
+        let value = (self.runner)(ctx);
 
         cleanup(ctx.state_mut(), key_ident, old_key);
         cleanup(ctx.state_mut(), value_ident, old_value);
 
-        Ok(value)
+        value
     }
 
     /// Run the closure to completion, given the provided index/value pair, and
@@ -206,12 +214,12 @@ where
         let old_index = insert(ctx.state_mut(), index_ident, index.into());
         let old_value = insert(ctx.state_mut(), value_ident, cloned_value);
 
-        let value = (self.runner)(ctx)?;
+        let value = (self.runner)(ctx);
 
         cleanup(ctx.state_mut(), index_ident, old_index);
         cleanup(ctx.state_mut(), value_ident, old_value);
 
-        Ok(value)
+        value
     }
 
     /// Run the closure to completion, given the provided key, and the runtime
@@ -28,11 +28,15 @@
     clippy::too_many_lines, // allowed in initial deny commit
 )]
 
+mod clock;
 mod compile_config;
 mod compiler;
 mod context;
 mod deprecation_warning;
+#[cfg(feature = "profiling")]
+mod profile;
 mod program;
+mod rng;
 mod test_util;
 
 pub mod expression;
@@ -41,6 +45,7 @@ pub mod state;
 pub mod type_def;
 pub mod value;
 
+pub use self::clock::Clock;
 pub use self::compile_config::CompileConfig;
 pub use self::deprecation_warning::DeprecationWarning;
 pub use compiler::{CompilationResult, Compiler};
@@ -51,13 +56,16 @@ pub use core::{
 use std::fmt::Debug;
 use std::{fmt::Display, str::FromStr};
 
-pub use context::Context;
+pub use context::{Context, ControlFlow};
 use diagnostic::DiagnosticList;
 pub(crate) use diagnostic::Span;
 pub use expression::{Expression, FunctionExpression};
 pub use function::{Function, Parameter};
 pub use paste::paste;
-pub use program::{Program, ProgramInfo};
+#[cfg(feature = "profiling")]
+pub use profile::{Branches, Profile};
+pub use program::{Dependencies, Program, ProgramInfo};
+pub use rng::Rng;
 pub use state::{TypeInfo, TypeState};
 pub use type_def::TypeDef;
 use vector_config::configurable_component;
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::Span;
+
+/// Execution counts of the expressions of a program, collected while
+/// profiling.
+///
+/// Expressions are identified by their span in the program source. Function
+/// calls are counted each time they are resolved, and if-statements count how
+/// often their predicate held.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Profile {
+    expressions: BTreeMap<Span, u64>,
+    branches: BTreeMap<Span, Branches>,
+}
+
+/// How often the predicate of an if-statement held.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Branches {
+    /// The number of times the `if` block ran.
+    pub taken: u64,
+
+    /// The number of times the predicate didn't hold, and the `else` block
+    /// ran, if there is one.
+    pub not_taken: u64,
+}
+
+impl Profile {
+    /// Count a single run of the expression at the given span.
+    pub fn record_expression(&mut self, span: Span) {
+        *self.expressions.entry(span).or_default() += 1;
+    }
+
+    /// Count a single run of the if-statement with the predicate at the given
+    /// span.
+    pub fn record_branch(&mut self, span: Span, taken: bool) {
+        let branches = self.branches.entry(span).or_default();
+
+        if taken {
+            branches.taken += 1;
+        } else {
+            branches.not_taken += 1;
+        }
+    }
+
+    /// The number of runs of each expression, ordered by span.
+    pub fn expressions(&self) -> impl Iterator<Item = (Span, u64)> + '_ {
+        self.expressions.iter().map(|(span, count)| (*span, *count))
+    }
+
+    /// The branches taken by each if-statement, ordered by the span of its
+    /// predicate.
+    pub fn branches(&self) -> impl Iterator<Item = (Span, Branches)> + '_ {
+        self.branches
+            .iter()
+            .map(|(span, branches)| (*span, *branches))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.expressions.is_empty() && self.branches.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.expressions.clear();
+        self.branches.clear();
+    }
+
+    /// Render the counts in the "folded" format read by flamegraph tools, one
+    /// `<frame> <count>` line per expression, ordered by span.
+    ///
+    /// Each frame is the position and source text of the expression, the
+    /// branches of an if-statement are nested frames of its predicate.
+    #[must_use]
+    pub fn folded(&self, source: &str) -> String {
+        let mut frames = self
+            .expressions
+            .iter()
+            .map(|(span, count)| (*span, frame(source, *span), *count))
+            .collect::<Vec<_>>();
+
+        for (span, branches) in &self.branches {
+            let predicate = frame(source, *span);
+
+            frames.push((*span, format!("{};taken", predicate), branches.taken));
+            frames.push((
+                *span,
+                format!("{};not taken", predicate),
+                branches.not_taken,
+            ));
+        }
+
+        frames.sort_by_key(|(span, _, _)| *span);
+
+        frames
+            .into_iter()
+            .fold(String::new(), |mut folded, (_, frame, count)| {
+                let _ = writeln!(folded, "{} {}", frame, count);
+                folded
+            })
+    }
+}
+
+/// The position of the span, followed by the first line of source text it
+/// covers.
+fn frame(source: &str, span: Span) -> String {
+    let before = source.get(..span.start()).unwrap_or_default();
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+    let text = source
+        .get(span.start()..span.end())
+        .unwrap_or_default()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .replace(';', ",");
+
+    format!("{}:{} {}", line, column, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record() {
+        let mut profile = Profile::default();
+
+        profile.record_expression(Span::new(0, 3));
+        profile.record_expression(Span::new(0, 3));
+        profile.record_expression(Span::new(4, 8));
+        profile.record_branch(Span::new(10, 12), true);
+        profile.record_branch(Span::new(10, 12), false);
+        profile.record_branch(Span::new(10, 12), false);
+
+        assert_eq!(
+            profile.expressions().collect::<Vec<_>>(),
+            vec![(Span::new(0, 3), 2), (Span::new(4, 8), 1)]
+        );
+        assert_eq!(
+            profile.branches().collect::<Vec<_>>(),
+            vec![(
+                Span::new(10, 12),
+                Branches {
+                    taken: 1,
+                    not_taken: 2
+                }
+            )]
+        );
+
+        profile.clear();
+        assert!(profile.is_empty());
+    }
+
+    #[test]
+    fn folded() {
+        let source = "foo = now()\nif .bar {\n  upcase(.baz)\n}";
+        let mut profile = Profile::default();
+
+        profile.record_expression(Span::new(6, 11));
+        profile.record_expression(Span::new(24, 36));
+        profile.record_branch(Span::new(15, 19), true);
+        profile.record_branch(Span::new(15, 19), false);
+
+        assert_eq!(
+            profile.folded(source),
+            "1:7 now() 1\n2:4 .bar;taken 1\n2:4 .bar;not taken 1\n3:3 upcase(.baz) 1\n"
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::BTreeSet;
+
 use lookup::OwnedTargetPath;
+use parser::ast::Ident;
 
 use crate::state::TypeState;
 use crate::{
     expression::{Block, Resolved},
-    Context, Expression,
+    Context, Expression, TypeDef,
 };
 
 #[derive(Debug, Clone)]
@@ -12,6 +15,12 @@ pub struct Program {
     pub(crate) initial_state: TypeState,
     pub(crate) expressions: Block,
     pub(crate) info: ProgramInfo,
+
+    /// The union of the types of all values returned by `return` expressions.
+    pub(crate) return_type: TypeDef,
+
+    /// The paths and variables the program reads and writes.
+    pub(crate) dependencies: Dependencies,
 }
 
 impl Program {
@@ -27,6 +36,18 @@ impl Program {
         self.expressions.type_info(&self.initial_state).state
     }
 
+    /// Retrieves the type of the value the program resolves to.
+    ///
+    /// This includes the values of any `return` expressions, in addition to
+    /// the value of the last expression of the program.
+    #[must_use]
+    pub fn type_def(&self) -> TypeDef {
+        self.expressions
+            .type_info(&self.initial_state)
+            .result
+            .union(self.return_type.clone())
+    }
+
     /// Get detailed information about the program, as collected by the VRL
     /// compiler.
     #[must_use]
@@ -34,13 +55,49 @@ impl Program {
         &self.info
     }
 
+    /// Retrieves the paths and variables the program may read and write at
+    /// runtime.
+    #[must_use]
+    pub fn dependencies(&self) -> &Dependencies {
+        &self.dependencies
+    }
+
+    /// Retrieves the external paths the program reads before it's known to
+    /// have assigned them, which the events it runs against have to provide.
+    ///
+    /// This includes reads in every block of an `if` statement. A path
+    /// assigned in only one of the blocks is still required when it's read
+    /// after the statement.
+    #[must_use]
+    pub fn required_external_fields(&self) -> &BTreeSet<OwnedTargetPath> {
+        &self.dependencies.required_target_reads
+    }
+
+    /// Returns an estimate of how expensive the program is to run.
+    ///
+    /// The estimate is only meaningful relative to that of other programs. It
+    /// is based on the number of function calls and path accesses, where the
+    /// closures of iterating functions (such as `for_each`) are weighted by
+    /// the number of times they are expected to run. Both blocks of an `if`
+    /// statement are counted, regardless of which one runs.
+    #[must_use]
+    pub fn complexity(&self) -> usize {
+        self.expressions.complexity()
+    }
+
     /// Resolve the program to its final [`Value`].
     ///
     /// # Errors
     ///
     /// Returns an error if the program resulted in a runtime error.
     pub fn resolve(&self, ctx: &mut Context) -> Resolved {
-        self.expressions.resolve(ctx)
+        let result = self.expressions.resolve(ctx);
+
+        // A `return` expression stops the program early, with its own value.
+        match ctx.take_return_value() {
+            Some(value) => Ok(value),
+            None => result,
+        }
     }
 }
 
@@ -65,3 +122,252 @@ pub struct ProgramInfo {
     /// runtime.
     pub target_assignments: Vec<OwnedTargetPath>,
 }
+
+/// The external paths and local variables a program may read and write at
+/// runtime.
+///
+/// These include the accesses of expressions that don't always run, such as
+/// both blocks of an `if` statement.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dependencies {
+    /// The paths queried from the external [`Target`].
+    pub target_reads: BTreeSet<OwnedTargetPath>,
+
+    /// The paths assigned to in the external [`Target`], or deleted from it
+    /// by `del`.
+    pub target_writes: BTreeSet<OwnedTargetPath>,
+
+    /// The paths queried from the external [`Target`] before the program is
+    /// known to have assigned them.
+    pub required_target_reads: BTreeSet<OwnedTargetPath>,
+
+    /// The local variables read by the program.
+    pub variable_reads: BTreeSet<Ident>,
+
+    /// The local variables assigned by the program.
+    pub variable_writes: BTreeSet<Ident>,
+}
+
+impl Dependencies {
+    /// Returns whether running this program and the `other` one against the
+    /// same target can affect each other, because one of them writes a path
+    /// the other one reads or writes.
+    ///
+    /// A path overlaps with both its parent and child paths. Local variables
+    /// are ignored, as they aren't shared between programs.
+    #[must_use]
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        fn overlaps(writes: &BTreeSet<OwnedTargetPath>, paths: &BTreeSet<OwnedTargetPath>) -> bool {
+            writes.iter().any(|write| {
+                paths
+                    .iter()
+                    .any(|path| write.can_start_with(path) || path.can_start_with(write))
+            })
+        }
+
+        overlaps(&self.target_writes, &other.target_reads)
+            || overlaps(&self.target_writes, &other.target_writes)
+            || overlaps(&other.target_writes, &self.target_reads)
+    }
+}
+
+#[cfg(all(test, feature = "expressions"))]
+mod tests {
+    use super::*;
+    use crate::{
+        function::{ArgumentList, Compiled, Example, FunctionCompileContext},
+        value::kind,
+        CompileConfig, Compiler, Function, FunctionExpression, Parameter,
+    };
+
+    /// Stands in for the `del` function of the standard library, which the
+    /// compiler treats as a write of its target path.
+    #[derive(Clone, Debug)]
+    struct Del;
+
+    impl Function for Del {
+        fn identifier(&self) -> &'static str {
+            "del"
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn parameters(&self) -> &'static [Parameter] {
+            &[
+                Parameter {
+                    keyword: "target",
+                    kind: kind::ANY,
+                    required: true,
+                },
+                Parameter {
+                    keyword: "compact",
+                    kind: kind::BOOLEAN,
+                    required: false,
+                },
+            ]
+        }
+
+        fn compile(
+            &self,
+            _state: &TypeState,
+            _ctx: &mut FunctionCompileContext,
+            _arguments: ArgumentList,
+        ) -> Compiled {
+            Ok(Del.as_expr())
+        }
+    }
+
+    impl FunctionExpression for Del {
+        fn resolve(&self, _ctx: &mut Context) -> Resolved {
+            todo!()
+        }
+
+        fn type_def(&self, _state: &TypeState) -> TypeDef {
+            TypeDef::any().infallible()
+        }
+    }
+
+    fn compile(source: &str) -> Program {
+        let ast = parser::parse(source).unwrap();
+        let fns: Vec<Box<dyn Function>> = vec![Box::new(Del)];
+
+        Compiler::compile(&fns, ast, &TypeState::default(), CompileConfig::default())
+            .unwrap()
+            .program
+    }
+
+    fn complexity(source: &str) -> usize {
+        compile(source).complexity()
+    }
+
+    #[test]
+    fn literals_are_free() {
+        assert_eq!(complexity(r#"[1, "two", { "three": 3.0 }]"#), 0);
+    }
+
+    #[test]
+    fn path_accesses() {
+        assert_eq!(complexity(".foo"), 1);
+        assert_eq!(complexity(".foo = .bar"), 2);
+        assert_eq!(complexity("x = .foo; x"), 3);
+    }
+
+    #[test]
+    fn if_statement_counts_both_blocks() {
+        assert_eq!(complexity("if .foo == true { .bar }"), 2);
+        assert_eq!(
+            complexity("if .foo == true { .bar } else { .baz; .qux }"),
+            4
+        );
+    }
+
+    fn target_paths(paths: &[&str]) -> BTreeSet<OwnedTargetPath> {
+        paths
+            .iter()
+            .map(|path| OwnedTargetPath::event(lookup::lookup_v2::parse_value_path(path)))
+            .collect()
+    }
+
+    fn idents(idents: &[&str]) -> BTreeSet<Ident> {
+        idents.iter().copied().map(Ident::new).collect()
+    }
+
+    #[test]
+    fn dependencies_of_conditional_writes() {
+        let program = compile(
+            r#"
+            if .foo == true {
+                .bar = 1
+                x = .baz
+            } else {
+                .qux.quux = 2
+                y = 3
+            }
+            x
+            "#,
+        );
+        let dependencies = program.dependencies();
+
+        assert_eq!(dependencies.target_reads, target_paths(&["foo", "baz"]));
+        assert_eq!(
+            dependencies.target_writes,
+            target_paths(&["bar", "qux.quux"])
+        );
+        assert_eq!(dependencies.variable_reads, idents(&["x"]));
+        assert_eq!(dependencies.variable_writes, idents(&["x", "y"]));
+    }
+
+    #[test]
+    fn required_external_fields() {
+        let program = compile(
+            r#"
+            .foo = 1
+            .bar = .baz
+            if .qux == true {
+                .quux = 1
+                .corge = 2
+            } else {
+                .quux = 2
+                .grault
+            }
+            [.foo, .bar.baz, .quux, .corge, .grault]
+            "#,
+        );
+
+        assert_eq!(
+            program.required_external_fields(),
+            &target_paths(&["baz", "qux", "grault", "corge"])
+        );
+    }
+
+    #[test]
+    fn required_external_fields_read_before_assignment() {
+        let program = compile(".foo = .foo; .bar; .bar = 1; .baz.qux = 2; .baz");
+
+        assert_eq!(
+            program.required_external_fields(),
+            &target_paths(&["foo", "bar", "baz"])
+        );
+    }
+
+    #[test]
+    fn conflicting_dependencies() {
+        let writer = compile(".foo.bar = 1");
+        let dependencies = writer.dependencies();
+
+        assert!(dependencies.conflicts_with(compile(".foo").dependencies()));
+        assert!(dependencies.conflicts_with(compile(".foo.bar.baz").dependencies()));
+        assert!(dependencies.conflicts_with(compile(".foo.bar = 2").dependencies()));
+        assert!(compile("if .foo.bar == true { 1 }")
+            .dependencies()
+            .conflicts_with(dependencies));
+        assert!(!dependencies.conflicts_with(compile(".foo.baz = .qux").dependencies()));
+        assert!(!dependencies.conflicts_with(compile("x = 1; x").dependencies()));
+    }
+
+    #[test]
+    fn deletions_conflict() {
+        let deleter = compile("del(.foo)");
+        let dependencies = deleter.dependencies();
+
+        assert_eq!(dependencies.target_writes, target_paths(&["foo"]));
+        assert!(dependencies.conflicts_with(compile("del(.foo)").dependencies()));
+        assert!(dependencies.conflicts_with(compile(".foo.bar").dependencies()));
+        assert!(compile(".foo").dependencies().conflicts_with(dependencies));
+        assert!(
+            dependencies.conflicts_with(compile("del(compact: true, target: .foo)").dependencies())
+        );
+        assert!(!dependencies.conflicts_with(compile("del(.bar)").dependencies()));
+    }
+
+    #[test]
+    fn type_def_includes_returned_values() {
+        let program = compile(r#"if .foo == true { return 1 }; "two""#);
+        assert_eq!(program.type_def(), TypeDef::bytes().or_integer());
+
+        let program = compile(r#"return "one""#);
+        assert_eq!(program.type_def(), TypeDef::bytes());
+    }
+}
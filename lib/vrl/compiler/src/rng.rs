@@ -0,0 +1,81 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// A pseudo-random number generator for functions that make random decisions
+/// at runtime, such as `sample`.
+///
+/// The generated sequence is fully determined by the seed, so a runtime created
+/// with a known seed produces the same decisions on every run. This is not
+/// suitable for cryptographic use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Create a generator that produces the sequence for the given seed.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Get the next number in the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64, see: https://prng.di.unimi.it/splitmix64.c
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Get the next number in the sequence, as a float in the range `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        // The 53 most significant bits fit in the mantissa of a float exactly.
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+impl Default for Rng {
+    /// A generator with a random seed, taken from the randomly keyed hasher of
+    /// the standard library.
+    fn default() -> Self {
+        Self::new(RandomState::new().build_hasher().finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn known_sequence() {
+        // Reference values from the SplitMix64 implementation for seed 0.
+        let mut rng = Rng::new(0);
+
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+        assert_eq!(rng.next_u64(), 0x06C4_5D18_8009_454F);
+    }
+
+    #[test]
+    fn float_range() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..1000 {
+            let n = rng.next_f64();
+            assert!((0.0..1.0).contains(&n), "{}", n);
+        }
+    }
+}
@@ -2,7 +2,9 @@ use lookup::PathPrefix;
 use std::collections::{hash_map::Entry, HashMap};
 use value::{Kind, Value};
 
-use crate::{parser::ast::Ident, type_def::Details, value::Collection, TypeDef};
+#[cfg(feature = "profiling")]
+use crate::Profile;
+use crate::{parser::ast::Ident, type_def::Details, value::Collection, Clock, Rng, TypeDef};
 
 #[derive(Debug, Clone)]
 pub struct TypeInfo {
@@ -164,9 +166,54 @@ impl ExternalEnv {
 pub struct Runtime {
     /// The [`Value`] stored in each variable.
     variables: HashMap<Ident, Value>,
+
+    /// The generator for random decisions made by the program.
+    ///
+    /// This isn't reset by [`Runtime::clear`], so that consecutive runs
+    /// continue the same sequence.
+    rng: Rng,
+
+    /// The source of the current time for the program.
+    clock: Clock,
+
+    /// The execution counts collected while profiling, if enabled.
+    ///
+    /// This isn't reset by [`Runtime::clear`], so that counts accumulate over
+    /// consecutive runs.
+    #[cfg(feature = "profiling")]
+    profile: Option<Profile>,
 }
 
 impl Runtime {
+    /// Create a runtime state that makes the same random decisions every time
+    /// it runs a program, for the given seed.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+            ..Self::default()
+        }
+    }
+
+    /// Start counting how often the expressions of the program run, see
+    /// [`Profile`].
+    #[cfg(feature = "profiling")]
+    pub fn enable_profiling(&mut self) {
+        self.profile.get_or_insert_with(Profile::default);
+    }
+
+    /// The execution counts collected so far, if profiling is enabled.
+    #[cfg(feature = "profiling")]
+    #[must_use]
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_ref()
+    }
+
+    #[cfg(feature = "profiling")]
+    pub fn profile_mut(&mut self) -> Option<&mut Profile> {
+        self.profile.as_mut()
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.variables.is_empty()
@@ -185,6 +232,20 @@ impl Runtime {
         self.variables.get_mut(ident)
     }
 
+    pub fn rng_mut(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    #[must_use]
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Replace the source of the current time, e.g. to freeze it in tests.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
     pub(crate) fn insert_variable(&mut self, ident: Ident, value: Value) {
         self.variables.insert(ident, value);
     }
@@ -203,3 +264,45 @@ impl Runtime {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(details: Details) -> LocalEnv {
+        let mut bindings = HashMap::new();
+        bindings.insert(Ident::new("foo"), details);
+
+        LocalEnv { bindings }
+    }
+
+    #[test]
+    fn merge_reassigned_variable() {
+        let before = local(Details {
+            type_def: TypeDef::bytes(),
+            value: Some("bar".into()),
+        });
+        let branch = local(Details {
+            type_def: TypeDef::integer(),
+            value: Some(1.into()),
+        });
+
+        let merged = branch.merge(before);
+        let details = merged.variable(&Ident::new("foo")).unwrap();
+
+        assert_eq!(details.type_def, TypeDef::integer().or_bytes());
+        assert_eq!(details.value, None);
+    }
+
+    #[test]
+    fn merge_unchanged_variable() {
+        let details = Details {
+            type_def: TypeDef::bytes(),
+            value: Some("bar".into()),
+        };
+
+        let merged = local(details.clone()).merge(local(details.clone()));
+
+        assert_eq!(merged.variable(&Ident::new("foo")), Some(&details));
+    }
+}
@@ -41,6 +41,12 @@ pub struct TypeDef {
     /// custom function designed to be infallible).
     fallible: bool,
 
+    /// True, if an expression can abort the program.
+    ///
+    /// This is the case for the [`Abort`][crate::expression::Abort] expression, and any block or
+    /// if-statement containing it.
+    abortable: bool,
+
     /// The [`Kind`][value::Kind]s this definition represents.
     kind: Kind,
 }
@@ -73,9 +79,14 @@ impl TypeDef {
     #[must_use]
     pub fn at_path(&self, path: &LookupBuf) -> TypeDef {
         let fallible = self.fallible;
+        let abortable = self.abortable;
         let kind = self.kind.at_path(path);
 
-        Self { fallible, kind }
+        Self {
+            fallible,
+            abortable,
+            kind,
+        }
     }
 
     #[inline]
@@ -99,6 +110,20 @@ impl TypeDef {
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn abortable(mut self) -> Self {
+        self.abortable = true;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_abortability(mut self, abortable: bool) -> Self {
+        self.abortable = abortable;
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn any() -> Self {
@@ -244,6 +269,7 @@ impl TypeDef {
     #[must_use]
     pub fn restrict_array(self) -> Self {
         let fallible = self.fallible;
+        let abortable = self.abortable;
         let collection = match self.kind.into_array() {
             Some(array) => array,
             None => Collection::any(),
@@ -251,6 +277,7 @@ impl TypeDef {
 
         Self {
             fallible,
+            abortable,
             kind: Kind::array(collection),
         }
     }
@@ -277,6 +304,7 @@ impl TypeDef {
     #[must_use]
     pub fn restrict_object(self) -> Self {
         let fallible = self.fallible;
+        let abortable = self.abortable;
         let collection = match self.kind.into_object() {
             Some(object) => object,
             None => Collection::any(),
@@ -284,6 +312,7 @@ impl TypeDef {
 
         Self {
             fallible,
+            abortable,
             kind: Kind::object(collection),
         }
     }
@@ -326,6 +355,11 @@ impl TypeDef {
         !self.is_fallible()
     }
 
+    #[must_use]
+    pub fn is_abortable(&self) -> bool {
+        self.abortable
+    }
+
     /// Set the type definition to be fallible if its kind is not contained
     /// within the provided kind.
     pub fn fallible_unless(mut self, kind: impl Into<Kind>) -> Self {
@@ -340,6 +374,7 @@ impl TypeDef {
     #[must_use]
     pub fn union(mut self, other: Self) -> Self {
         self.fallible |= other.fallible;
+        self.abortable |= other.abortable;
         self.kind = self.kind.union(other.kind);
         self
     }
@@ -347,6 +382,7 @@ impl TypeDef {
     // deprecated
     pub fn merge(&mut self, other: Self, strategy: merge::Strategy) {
         self.fallible |= other.fallible;
+        self.abortable |= other.abortable;
         self.kind.merge(other.kind, strategy);
     }
 
@@ -359,6 +395,7 @@ impl TypeDef {
             kind.insert(path, other.kind);
             Self {
                 fallible: self.fallible || other.fallible,
+                abortable: self.abortable || other.abortable,
                 kind,
             }
         }
@@ -381,6 +418,7 @@ impl From<Kind> for TypeDef {
     fn from(kind: Kind) -> Self {
         Self {
             fallible: false,
+            abortable: false,
             kind,
         }
     }
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
 use bytes::{BufMut, Bytes, BytesMut};
+use chrono::{DateTime, Duration, Utc};
 use value::Value;
 
 use super::Error;
@@ -51,6 +52,26 @@ pub trait VrlValueArithmetic: Sized {
 
     fn try_merge(self, rhs: Self) -> Result<Self, Error>;
 
+    /// Similar to [`std::ops::BitAnd`], but fallible (e.g. `TryBitAnd`).
+    fn try_bit_and(self, rhs: Self) -> Result<Self, Error>;
+
+    /// Similar to [`std::ops::BitOr`], but fallible (e.g. `TryBitOr`).
+    fn try_bit_or(self, rhs: Self) -> Result<Self, Error>;
+
+    /// Similar to [`std::ops::BitXor`], but fallible (e.g. `TryBitXor`).
+    fn try_bit_xor(self, rhs: Self) -> Result<Self, Error>;
+
+    /// Similar to [`std::ops::Shl`], but fallible (e.g. `TryShl`).
+    ///
+    /// The shift amount must be between 0 and 63.
+    fn try_shl(self, rhs: Self) -> Result<Self, Error>;
+
+    /// Similar to [`std::ops::Shr`], but fallible (e.g. `TryShr`).
+    ///
+    /// This is an arithmetic shift, the sign of the lhs is preserved. The
+    /// shift amount must be between 0 and 63.
+    fn try_shr(self, rhs: Self) -> Result<Self, Error>;
+
     /// Similar to [`std::cmp::Eq`], but does a lossless comparison for integers
     /// and floats.
     fn eq_lossy(&self, rhs: &Self) -> bool;
@@ -124,6 +145,9 @@ impl VrlValueArithmetic for Value {
                 value.freeze().into()
             }
             (Value::Null, rhs @ Value::Bytes(_)) => rhs,
+            (Value::Timestamp(lhs), rhs @ (Value::Integer(_) | Value::Float(_))) => {
+                Value::Timestamp(add_seconds(lhs, rhs.try_into_f64()?))
+            }
             (lhs, rhs) => return Err(Error::Add(lhs.kind(), rhs.kind())),
         };
 
@@ -140,6 +164,13 @@ impl VrlValueArithmetic for Value {
             }
             Value::Integer(lhv) => (lhv - rhs.try_into_i64().map_err(|_| err())?).into(),
             Value::Float(lhv) => (lhv - rhs.try_into_f64().map_err(|_| err())?).into(),
+            Value::Timestamp(lhv) => match rhs {
+                Value::Timestamp(rhv) => Value::from_f64_or_zero(seconds_between(rhv, lhv)),
+                Value::Integer(_) | Value::Float(_) => {
+                    Value::Timestamp(add_seconds(lhv, -rhs.try_into_f64()?))
+                }
+                _ => return Err(err()),
+            },
             _ => return Err(err()),
         };
 
@@ -282,6 +313,54 @@ impl VrlValueArithmetic for Value {
         Ok(value)
     }
 
+    /// Similar to [`std::ops::BitAnd`], but fallible (e.g. `TryBitAnd`).
+    fn try_bit_and(self, rhs: Self) -> Result<Self, Error> {
+        match (self, rhs) {
+            (Value::Integer(lhv), Value::Integer(rhv)) => Ok((lhv & rhv).into()),
+            (lhs, rhs) => Err(Error::BitAnd(lhs.kind(), rhs.kind())),
+        }
+    }
+
+    /// Similar to [`std::ops::BitOr`], but fallible (e.g. `TryBitOr`).
+    fn try_bit_or(self, rhs: Self) -> Result<Self, Error> {
+        match (self, rhs) {
+            (Value::Integer(lhv), Value::Integer(rhv)) => Ok((lhv | rhv).into()),
+            (lhs, rhs) => Err(Error::BitOr(lhs.kind(), rhs.kind())),
+        }
+    }
+
+    /// Similar to [`std::ops::BitXor`], but fallible (e.g. `TryBitXor`).
+    fn try_bit_xor(self, rhs: Self) -> Result<Self, Error> {
+        match (self, rhs) {
+            (Value::Integer(lhv), Value::Integer(rhv)) => Ok((lhv ^ rhv).into()),
+            (lhs, rhs) => Err(Error::BitXor(lhs.kind(), rhs.kind())),
+        }
+    }
+
+    /// Similar to [`std::ops::Shl`], but fallible (e.g. `TryShl`).
+    fn try_shl(self, rhs: Self) -> Result<Self, Error> {
+        match (self, rhs) {
+            (Value::Integer(lhv), Value::Integer(rhv)) => u32::try_from(rhv)
+                .ok()
+                .and_then(|rhv| lhv.checked_shl(rhv))
+                .map(Into::into)
+                .ok_or(Error::ShiftOutOfRange(rhv)),
+            (lhs, rhs) => Err(Error::Shift(lhs.kind(), rhs.kind())),
+        }
+    }
+
+    /// Similar to [`std::ops::Shr`], but fallible (e.g. `TryShr`).
+    fn try_shr(self, rhs: Self) -> Result<Self, Error> {
+        match (self, rhs) {
+            (Value::Integer(lhv), Value::Integer(rhv)) => u32::try_from(rhv)
+                .ok()
+                .and_then(|rhv| lhv.checked_shr(rhv))
+                .map(Into::into)
+                .ok_or(Error::ShiftOutOfRange(rhv)),
+            (lhs, rhs) => Err(Error::Shift(lhs.kind(), rhs.kind())),
+        }
+    }
+
     /// Similar to [`std::cmp::Eq`], but does a lossless comparison for integers
     /// and floats.
     fn eq_lossy(&self, rhs: &Self) -> bool {
@@ -302,3 +381,36 @@ impl VrlValueArithmetic for Value {
         }
     }
 }
+
+/// Shifts a timestamp by a (possibly negative) number of seconds.
+///
+/// Timestamps shifted beyond the supported range are clamped to its bounds.
+fn add_seconds(timestamp: DateTime<Utc>, seconds: f64) -> DateTime<Utc> {
+    // `Duration::seconds` panics well before this, at about 292 million years.
+    const MAX_SECONDS: f64 = 1e15;
+
+    let whole = seconds.trunc();
+    let shifted = (whole.abs() < MAX_SECONDS)
+        .then(|| {
+            let nanos = ((seconds - whole) * 1e9).round() as i64;
+            Duration::seconds(whole as i64) + Duration::nanoseconds(nanos)
+        })
+        .and_then(|duration| timestamp.checked_add_signed(duration));
+
+    match shifted {
+        Some(timestamp) => timestamp,
+        None if seconds < 0.0 => DateTime::<Utc>::MIN_UTC,
+        None => DateTime::<Utc>::MAX_UTC,
+    }
+}
+
+/// Returns the number of seconds from `start` to `end`.
+fn seconds_between(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    let duration = end - start;
+
+    // Nanosecond precision only fits about 292 years.
+    duration.num_nanoseconds().map_or_else(
+        || duration.num_milliseconds() as f64 / 1e3,
+        |nanos| nanos as f64 / 1e9,
+    )
+}
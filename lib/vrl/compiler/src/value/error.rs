@@ -55,13 +55,28 @@ pub enum Error {
 
     #[error("can't merge type {1} into {0}")]
     Merge(Kind, Kind),
+
+    #[error("can't apply a bitwise AND to types {0} and {1}")]
+    BitAnd(Kind, Kind),
+
+    #[error("can't apply a bitwise OR to types {0} and {1}")]
+    BitOr(Kind, Kind),
+
+    #[error("can't apply a bitwise XOR to types {0} and {1}")]
+    BitXor(Kind, Kind),
+
+    #[error("can't shift type {0} by {1}")]
+    Shift(Kind, Kind),
+
+    #[error("can't shift by {0} bits, must be between 0 and 63")]
+    ShiftOutOfRange(i64),
 }
 
 impl DiagnosticMessage for Error {
     fn code(&self) -> usize {
         use Error::{
-            Add, And, Coerce, Div, DivideByZero, Expected, Ge, Gt, Le, Lt, Merge, Mul, NanFloat,
-            Or, Rem, Sub,
+            Add, And, BitAnd, BitOr, BitXor, Coerce, Div, DivideByZero, Expected, Ge, Gt, Le, Lt,
+            Merge, Mul, NanFloat, Or, Rem, Shift, ShiftOutOfRange, Sub,
         };
 
         match self {
@@ -81,6 +96,11 @@ impl DiagnosticMessage for Error {
             Lt(..) => 313,
             Le(..) => 314,
             Merge(..) => 315,
+            BitAnd(..) => 316,
+            BitOr(..) => 317,
+            BitXor(..) => 318,
+            Shift(..) => 319,
+            ShiftOutOfRange(..) => 320,
         }
     }
 }
@@ -91,6 +111,7 @@ impl From<Error> for ExpressionError {
             message: err.message(),
             labels: vec![],
             notes: vec![],
+            code: err.code(),
         }
     }
 }
@@ -14,6 +14,8 @@ pub enum ExpressionError {
         message: String,
         labels: Vec<Label>,
         notes: Vec<Note>,
+        /// The code of the error, or `0` if the error doesn't have one.
+        code: usize,
     },
 }
 
@@ -31,7 +33,13 @@ impl std::error::Error for ExpressionError {
 
 impl DiagnosticMessage for ExpressionError {
     fn code(&self) -> usize {
-        0
+        use ExpressionError::{Abort, Error};
+
+        match self {
+            #[cfg(feature = "expr-abort")]
+            Abort { .. } => 0,
+            Error { code, .. } => *code,
+        }
     }
 
     fn message(&self) -> String {
@@ -73,6 +81,7 @@ impl From<String> for ExpressionError {
             message,
             labels: vec![],
             notes: vec![],
+            code: 0,
         }
     }
 }
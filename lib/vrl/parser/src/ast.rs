@@ -233,13 +233,18 @@ pub enum Expr {
     Variable(Node<Ident>),
     Unary(Node<Unary>),
     Abort(Node<Abort>),
+    Match(Node<Match>),
+    LoopControl(Node<LoopControl>),
+    Try(Node<Try>),
+    Return(Node<Return>),
+    Drop(Node<DropEvent>),
 }
 
 impl fmt::Debug for Expr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Op, Query, Unary,
-            Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Op, Query, Return, Try, Unary, Variable,
         };
 
         let value = match self {
@@ -253,6 +258,11 @@ impl fmt::Debug for Expr {
             Variable(v) => format!("{:?}", v),
             Unary(v) => format!("{:?}", v),
             Abort(v) => format!("{:?}", v),
+            Match(v) => format!("{:?}", v),
+            LoopControl(v) => format!("{:?}", v),
+            Try(v) => format!("{:?}", v),
+            Return(v) => format!("{:?}", v),
+            Drop(v) => format!("{:?}", v),
         };
 
         write!(f, "Expr({})", value)
@@ -262,8 +272,8 @@ impl fmt::Debug for Expr {
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Expr::{
-            Abort, Assignment, Container, FunctionCall, IfStatement, Literal, Op, Query, Unary,
-            Variable,
+            Abort, Assignment, Container, Drop, FunctionCall, IfStatement, Literal, LoopControl,
+            Match, Op, Query, Return, Try, Unary, Variable,
         };
 
         match self {
@@ -277,6 +287,11 @@ impl fmt::Display for Expr {
             Variable(v) => v.fmt(f),
             Unary(v) => v.fmt(f),
             Abort(v) => v.fmt(f),
+            Match(v) => v.fmt(f),
+            LoopControl(v) => v.fmt(f),
+            Try(v) => v.fmt(f),
+            Return(v) => v.fmt(f),
+            Drop(v) => v.fmt(f),
         }
     }
 }
@@ -285,7 +300,7 @@ impl fmt::Display for Expr {
 // ident
 // -----------------------------------------------------------------------------
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Ident(pub(crate) String);
 
 impl Ident {
@@ -344,12 +359,15 @@ pub enum Literal {
     Boolean(bool),
     Regex(String),
     Timestamp(String),
+    Duration(String),
     Null,
 }
 
 impl fmt::Display for Literal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Literal::{Boolean, Float, Integer, Null, RawString, Regex, String, Timestamp};
+        use Literal::{
+            Boolean, Duration, Float, Integer, Null, RawString, Regex, String, Timestamp,
+        };
 
         match self {
             String(v) => write!(f, r#""{}""#, v),
@@ -359,6 +377,7 @@ impl fmt::Display for Literal {
             Boolean(v) => v.fmt(f),
             Regex(v) => write!(f, "r'{}'", v),
             Timestamp(v) => write!(f, "t'{}'", v),
+            Duration(v) => v.fmt(f),
             Null => f.write_str("null"),
         }
     }
@@ -370,6 +389,36 @@ impl fmt::Debug for Literal {
     }
 }
 
+/// Returns the number of seconds a duration literal (such as `5m` or `1_000ms`)
+/// represents, or `None` if it isn't a valid duration literal.
+#[must_use]
+pub fn duration_seconds(literal: &str) -> Option<f64> {
+    let unit_start = literal.find(|ch: char| !ch.is_ascii_digit() && ch != '_')?;
+    let (amount, unit) = literal.split_at(unit_start);
+
+    if !amount.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    let amount: i64 = amount.replace('_', "").parse().ok()?;
+
+    #[allow(clippy::cast_precision_loss)] // durations beyond 2^53 units are approximated
+    let amount = amount as f64;
+
+    let seconds = match unit {
+        "ns" => amount / 1e9,
+        "us" => amount / 1e6,
+        "ms" => amount / 1e3,
+        "s" => amount,
+        "m" => amount * 60.0,
+        "h" => amount * 3_600.0,
+        "d" => amount * 86_400.0,
+        _ => return None,
+    };
+
+    Some(seconds)
+}
+
 // -----------------------------------------------------------------------------
 // container
 // -----------------------------------------------------------------------------
@@ -663,6 +712,83 @@ impl fmt::Debug for Predicate {
     }
 }
 
+// -----------------------------------------------------------------------------
+// match expression
+// -----------------------------------------------------------------------------
+
+#[derive(Clone, PartialEq)]
+pub struct Match {
+    pub value: Box<Node<Expr>>,
+    pub arms: Vec<Node<MatchArm>>,
+}
+
+impl fmt::Debug for Match {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arms = self
+            .arms
+            .iter()
+            .map(|arm| format!("{:?}", arm))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "Match({:?}, [{}])", self.value, arms)
+    }
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "match {} {{", self.value)?;
+
+        for arm in &self.arms {
+            writeln!(f, "\t{},", arm)?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Node<MatchPattern>,
+    pub expr: Node<Expr>,
+}
+
+impl fmt::Debug for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MatchArm({:?} => {:?})", self.pattern, self.expr)
+    }
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.expr)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum MatchPattern {
+    Literal(Literal),
+    Wildcard,
+}
+
+impl fmt::Debug for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchPattern::Literal(v) => v.fmt(f),
+            MatchPattern::Wildcard => f.write_str("Wildcard"),
+        }
+    }
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchPattern::Literal(v) => v.fmt(f),
+            MatchPattern::Wildcard => f.write_str("_"),
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // operation
 // -----------------------------------------------------------------------------
@@ -699,6 +825,11 @@ pub enum Opcode {
     Le,
     Lt,
     Merge,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
 }
 
 impl fmt::Display for Opcode {
@@ -710,7 +841,10 @@ impl fmt::Display for Opcode {
 impl Opcode {
     #[must_use]
     pub fn as_str(self) -> &'static str {
-        use Opcode::{Add, And, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Sub};
+        use Opcode::{
+            Add, And, BitAnd, BitOr, BitXor, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Shl,
+            Shr, Sub,
+        };
 
         match self {
             Mul => "*",
@@ -731,6 +865,12 @@ impl Opcode {
             Gt => ">",
             Le => "<=",
             Lt => "<",
+
+            BitAnd => "&",
+            BitOr => "|",
+            BitXor => "^",
+            Shl => "<<",
+            Shr => ">>",
         }
     }
 }
@@ -739,7 +879,10 @@ impl FromStr for Opcode {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
-        use Opcode::{Add, And, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Sub};
+        use Opcode::{
+            Add, And, BitAnd, BitXor, Div, Eq, Err, Ge, Gt, Le, Lt, Merge, Mul, Ne, Or, Shl, Shr,
+            Sub,
+        };
 
         let op = match s {
             "*" => Mul,
@@ -761,6 +904,11 @@ impl FromStr for Opcode {
             "<" => Lt,
             "|" => Merge,
 
+            "&" => BitAnd,
+            "^" => BitXor,
+            "<<" => Shl,
+            ">>" => Shr,
+
             _ => return std::result::Result::Err(()),
         };
 
@@ -786,6 +934,11 @@ pub enum Assignment {
         op: AssignmentOp,
         expr: Box<Node<Expr>>,
     },
+    Destructure {
+        pattern: Node<AssignmentPattern>,
+        strict: bool,
+        expr: Box<Node<Expr>>,
+    },
     // TODO
     // Compound {
     //     target: Node<AssignmentTarget>,
@@ -825,24 +978,89 @@ impl fmt::Debug for AssignmentOp {
 
 impl fmt::Display for Assignment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Assignment::{Infallible, Single};
+        use Assignment::{Destructure, Infallible, Single};
 
         match self {
             Single { target, op, expr } => write!(f, "{} {} {}", target, op, expr),
             Infallible { ok, err, op, expr } => write!(f, "{}, {} {} {}", ok, err, op, expr),
+            Destructure {
+                pattern,
+                strict,
+                expr,
+            } => {
+                let strict = if *strict { "!" } else { "" };
+                write!(f, "{}{} = {}", pattern, strict, expr)
+            }
         }
     }
 }
 
 impl fmt::Debug for Assignment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use Assignment::{Infallible, Single};
+        use Assignment::{Destructure, Infallible, Single};
 
         match self {
             Single { target, op, expr } => write!(f, "{:?} {:?} {:?}", target, op, expr),
             Infallible { ok, err, op, expr } => {
                 write!(f, "Ok({:?}), Err({:?}) {:?} {:?}", ok, err, op, expr)
             }
+            Destructure {
+                pattern,
+                strict,
+                expr,
+            } => write!(
+                f,
+                "Destructure({:?}, strict: {}) {:?}",
+                pattern, strict, expr
+            ),
+        }
+    }
+}
+
+/// The pattern of a destructuring assignment.
+#[derive(Clone, PartialEq)]
+pub enum AssignmentPattern {
+    /// `{a, b}`, assigns the fields of an object to the variables of the same
+    /// name.
+    Object(Vec<Node<AssignmentTarget>>),
+
+    /// `[a, _, c]`, assigns the elements of an array to the variables at the
+    /// same position.
+    Array(Vec<Node<AssignmentTarget>>),
+}
+
+impl AssignmentPattern {
+    #[must_use]
+    pub fn targets(&self) -> &[Node<AssignmentTarget>] {
+        match self {
+            AssignmentPattern::Object(targets) | AssignmentPattern::Array(targets) => targets,
+        }
+    }
+}
+
+impl fmt::Display for AssignmentPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (open, close) = match self {
+            AssignmentPattern::Object(_) => ("{", "}"),
+            AssignmentPattern::Array(_) => ("[", "]"),
+        };
+
+        let targets = self
+            .targets()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "{}{}{}", open, targets, close)
+    }
+}
+
+impl fmt::Debug for AssignmentPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignmentPattern::Object(targets) => write!(f, "Object({:?})", targets),
+            AssignmentPattern::Array(targets) => write!(f, "Array({:?})", targets),
         }
     }
 }
@@ -956,7 +1174,7 @@ impl fmt::Display for QueryTarget {
             Internal(v) => v.fmt(f),
             External(prefix) => match prefix {
                 PathPrefix::Event => write!(f, "."),
-                PathPrefix::Metadata => write!(f, "&"),
+                PathPrefix::Metadata => write!(f, "%"),
             },
             FunctionCall(v) => v.fmt(f),
             Container(v) => v.fmt(f),
@@ -1203,3 +1421,93 @@ impl fmt::Debug for Abort {
         write!(f, "Abort({:?})", self.message)
     }
 }
+
+// -----------------------------------------------------------------------------
+// loop control
+// -----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LoopControl {
+    Break,
+    Continue,
+}
+
+impl fmt::Display for LoopControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoopControl::Break => f.write_str("break"),
+            LoopControl::Continue => f.write_str("continue"),
+        }
+    }
+}
+
+impl fmt::Debug for LoopControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoopControl::Break => f.write_str("Break"),
+            LoopControl::Continue => f.write_str("Continue"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// try
+// -----------------------------------------------------------------------------
+
+#[derive(Clone, PartialEq)]
+pub struct Try {
+    pub block: Node<Block>,
+    pub fallback: Node<Block>,
+}
+
+impl fmt::Debug for Try {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "try {:?} catch {:?}", self.block, self.fallback)
+    }
+}
+
+impl fmt::Display for Try {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "try {} catch {}", self.block, self.fallback)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// return
+// -----------------------------------------------------------------------------
+
+#[derive(Clone, PartialEq)]
+pub struct Return {
+    pub value: Box<Node<Expr>>,
+}
+
+impl fmt::Display for Return {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "return {}", self.value)
+    }
+}
+
+impl fmt::Debug for Return {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Return({:?})", self.value)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// drop
+// -----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DropEvent;
+
+impl fmt::Display for DropEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("drop")
+    }
+}
+
+impl fmt::Debug for DropEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Drop")
+    }
+}
@@ -3,6 +3,7 @@ use std::{fmt, iter::Peekable, str::CharIndices};
 use diagnostic::{DiagnosticMessage, Label, Span};
 use ordered_float::NotNan;
 
+use crate::ast::duration_seconds;
 use crate::template_string::{StringSegment, TemplateString};
 
 pub(crate) type Tok<'input> = Token<&'input str>;
@@ -78,6 +79,8 @@ impl DiagnosticMessage for Error {
         fn update_expected(expected: Vec<String>) -> Vec<String> {
             expected
                 .into_iter()
+                // The opening `{` or `[` of a pattern is already listed.
+                .filter(|expect| expect != "LPattern")
                 .map(|expect| match expect.as_str() {
                     "LQuery" => r#""path literal""#.to_owned(),
                     _ => expect,
@@ -116,6 +119,13 @@ impl DiagnosticMessage for Error {
                         r#""null""#,
                         r#""true""#,
                         r#""if""#,
+                        r#""match""#,
+                        r#""break""#,
+                        r#""continue""#,
+                        r#""try""#,
+                        r#""catch""#,
+                        r#""return""#,
+                        r#""drop""#,
                     ];
                     let is_any_ident = any_ident
                         .iter()
@@ -234,14 +244,18 @@ pub(crate) struct Lexer<'input> {
     ///   ~~~~~~~~~~  0..10
     ///    ~~~~       1..5
     rquery_indices: Vec<usize>,
+
+    /// The position of the last emitted `LPattern` token, to emit it only once
+    /// for the pattern that starts there.
+    pattern_start: Option<usize>,
 }
 
 impl<'input> Lexer<'input> {
     fn next_token(&mut self) -> Option<SpannedResult<'input, usize>> {
         use Token::{
             Ampersand, Arrow, Bang, Colon, Comma, Dot, Escape, InvalidToken, LBrace, LBracket,
-            LParen, LQuery, Newline, Percent, RBrace, RBracket, RParen, RQuery, SemiColon,
-            Underscore,
+            LParen, LPattern, LQuery, Newline, Percent, RBrace, RBracket, RParen, RQuery,
+            SemiColon, Underscore,
         };
 
         loop {
@@ -271,6 +285,15 @@ impl<'input> Lexer<'input> {
                 return Some(Ok((pos, RQuery, pos + 1)));
             }
 
+            // Check if we need to emit a `LPattern` token.
+            //
+            // Like `LQuery`, this token is a boundary marker, the iterator isn't
+            // advanced.
+            if self.pattern_start(start) {
+                self.pattern_start = Some(start);
+                return Some(Ok((start, LPattern, start + 1)));
+            }
+
             // Advance the internal iterator and emit the next token, or loop
             // again if we encounter a token we want to ignore (e.g. whitespace).
             if let Some((start, ch)) = self.bump() {
@@ -358,6 +381,7 @@ pub enum Token<S> {
     FloatLiteral(NotNan<f64>),
     RegexLiteral(S),
     TimestampLiteral(S),
+    DurationLiteral(S),
 
     // Reserved for future use.
     ReservedIdentifier(S),
@@ -371,6 +395,13 @@ pub enum Token<S> {
     False,
     True,
     Abort,
+    Match,
+    Break,
+    Continue,
+    Try,
+    Catch,
+    Return,
+    Drop,
 
     // tokens
     Colon,
@@ -427,16 +458,30 @@ pub enum Token<S> {
     /// above two groups of examples.
     LQuery,
     RQuery,
+
+    /// The LPattern token is an "instruction" token, similar to `LQuery`. It
+    /// marks the `{` or `[` that opens the pattern of a destructuring
+    /// assignment:
+    ///
+    /// ```text
+    /// {a, b} = .foo  => LPattern, LBrace, Ident, Comma, Ident, RBrace, Equals, ...
+    /// [a, _] = .foo  => LPattern, LBracket, Ident, Comma, Underscore, RBracket, Equals, ...
+    /// ```
+    ///
+    /// Without it, the parser can't tell the pattern `{a}` apart from a block
+    /// until it reaches the `=`.
+    LPattern,
 }
 
 impl<S> Token<S> {
     pub(crate) fn map<R>(self, f: impl Fn(S) -> R) -> Token<R> {
         use self::Token::{
-            Abort, Ampersand, Arrow, Bang, Colon, Comma, Dot, Else, Equals, Escape, False,
-            FloatLiteral, FunctionCall, Identifier, If, IntegerLiteral, InvalidToken, LBrace,
-            LBracket, LParen, LQuery, MergeEquals, Newline, Null, Operator, PathField, Percent,
-            Question, RBrace, RBracket, RParen, RQuery, RawStringLiteral, RegexLiteral,
-            ReservedIdentifier, SemiColon, StringLiteral, TimestampLiteral, True, Underscore,
+            Abort, Ampersand, Arrow, Bang, Break, Catch, Colon, Comma, Continue, Dot, Drop,
+            DurationLiteral, Else, Equals, Escape, False, FloatLiteral, FunctionCall, Identifier,
+            If, IntegerLiteral, InvalidToken, LBrace, LBracket, LParen, LPattern, LQuery, Match,
+            MergeEquals, Newline, Null, Operator, PathField, Percent, Question, RBrace, RBracket,
+            RParen, RQuery, RawStringLiteral, RegexLiteral, ReservedIdentifier, Return, SemiColon,
+            StringLiteral, TimestampLiteral, True, Try, Underscore,
         };
 
         match self {
@@ -454,6 +499,7 @@ impl<S> Token<S> {
             FloatLiteral(s) => FloatLiteral(s),
             RegexLiteral(s) => RegexLiteral(f(s)),
             TimestampLiteral(s) => TimestampLiteral(f(s)),
+            DurationLiteral(s) => DurationLiteral(f(s)),
 
             ReservedIdentifier(s) => ReservedIdentifier(f(s)),
 
@@ -465,6 +511,13 @@ impl<S> Token<S> {
             Null => Null,
             True => True,
             Abort => Abort,
+            Match => Match,
+            Break => Break,
+            Continue => Continue,
+            Try => Try,
+            Catch => Catch,
+            Return => Return,
+            Drop => Drop,
 
             // tokens
             Colon => Colon,
@@ -491,6 +544,7 @@ impl<S> Token<S> {
 
             LQuery => LQuery,
             RQuery => RQuery,
+            LPattern => LPattern,
         }
     }
 }
@@ -501,11 +555,12 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Token::{
-            Abort, Ampersand, Arrow, Bang, Colon, Comma, Dot, Else, Equals, Escape, False,
-            FloatLiteral, FunctionCall, Identifier, If, IntegerLiteral, InvalidToken, LBrace,
-            LBracket, LParen, LQuery, MergeEquals, Newline, Null, Operator, PathField, Percent,
-            Question, RBrace, RBracket, RParen, RQuery, RawStringLiteral, RegexLiteral,
-            ReservedIdentifier, SemiColon, StringLiteral, TimestampLiteral, True, Underscore,
+            Abort, Ampersand, Arrow, Bang, Break, Catch, Colon, Comma, Continue, Dot, Drop,
+            DurationLiteral, Else, Equals, Escape, False, FloatLiteral, FunctionCall, Identifier,
+            If, IntegerLiteral, InvalidToken, LBrace, LBracket, LParen, LPattern, LQuery, Match,
+            MergeEquals, Newline, Null, Operator, PathField, Percent, Question, RBrace, RBracket,
+            RParen, RQuery, RawStringLiteral, RegexLiteral, ReservedIdentifier, Return, SemiColon,
+            StringLiteral, TimestampLiteral, True, Try, Underscore,
         };
 
         let s = match *self {
@@ -519,6 +574,7 @@ where
             FloatLiteral(_) => "FloatLiteral",
             RegexLiteral(_) => "RegexLiteral",
             TimestampLiteral(_) => "TimestampLiteral",
+            DurationLiteral(_) => "DurationLiteral",
             ReservedIdentifier(_) => "ReservedIdentifier",
             InvalidToken(_) => "InvalidToken",
 
@@ -528,6 +584,13 @@ where
             Null => "Null",
             True => "True",
             Abort => "Abort",
+            Match => "Match",
+            Break => "Break",
+            Continue => "Continue",
+            Try => "Try",
+            Catch => "Catch",
+            Return => "Return",
+            Drop => "Drop",
 
             // tokens
             Colon => "Colon",
@@ -554,6 +617,7 @@ where
 
             LQuery => "LQuery",
             RQuery => "RQuery",
+            LPattern => "LPattern",
         };
 
         s.fmt(f)
@@ -564,7 +628,8 @@ impl<'input> Token<&'input str> {
     /// Returns either a literal, reserved, or generic identifier.
     fn ident(s: &'input str) -> Self {
         use Token::{
-            Abort, Else, False, Identifier, If, Null, PathField, ReservedIdentifier, True,
+            Abort, Break, Catch, Continue, Drop, Else, False, Identifier, If, Match, Null,
+            PathField, ReservedIdentifier, Return, True, Try,
         };
 
         match s {
@@ -574,14 +639,19 @@ impl<'input> Token<&'input str> {
             "false" => False,
             "null" => Null,
             "abort" => Abort,
+            "match" => Match,
+            "break" => Break,
+            "continue" => Continue,
+            "try" => Try,
+            "catch" => Catch,
+            "return" => Return,
+            "drop" => Drop,
 
             // reserved identifiers
-            "array" | "bool" | "boolean" | "break" | "continue" | "do" | "emit" | "float"
-            | "for" | "forall" | "foreach" | "all" | "each" | "any" | "try" | "undefined"
-            | "int" | "integer" | "iter" | "object" | "regex" | "return" | "string"
-            | "traverse" | "timestamp" | "duration" | "unless" | "walk" | "while" | "loop" => {
-                ReservedIdentifier(s)
-            }
+            "array" | "bool" | "boolean" | "do" | "emit" | "float" | "for" | "forall"
+            | "foreach" | "all" | "each" | "any" | "undefined" | "int" | "integer" | "iter"
+            | "object" | "regex" | "string" | "traverse" | "timestamp" | "duration" | "unless"
+            | "walk" | "while" | "loop" => ReservedIdentifier(s),
 
             _ if s.contains('@') => PathField(s),
 
@@ -732,6 +802,66 @@ impl<'input> Lexer<'input> {
         (start, token, self.next_index())
     }
 
+    /// Returns `true` if the `{` or `[` at the given position opens the pattern
+    /// of a destructuring assignment.
+    ///
+    /// A pattern is a comma-separated list of names, followed by `=`, or by
+    /// `! =` for a strict pattern. The names themselves are validated by the
+    /// parser.
+    fn pattern_start(&self, start: usize) -> bool {
+        // If we already opened a pattern for the current position, we don't
+        // want to open another one.
+        if self.pattern_start == Some(start) {
+            return false;
+        }
+
+        let mut chars = self.input[start..].chars().peekable();
+        let close = match chars.next() {
+            Some('{') => '}',
+            Some('[') => ']',
+            _ => return false,
+        };
+
+        let mut names = 0;
+        loop {
+            while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+
+            match chars.peek() {
+                Some(&ch) if is_ident_start(ch) => {
+                    while chars.next_if(|&ch| is_ident_continue(ch)).is_some() {}
+                    names += 1;
+                }
+                // A trailing comma.
+                Some(&ch) if ch == close && names > 0 => {
+                    chars.next();
+                    break;
+                }
+                _ => return false,
+            }
+
+            while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+
+            match chars.next() {
+                Some(',') => continue,
+                Some(ch) if ch == close => break,
+                _ => return false,
+            }
+        }
+
+        let is_inline_whitespace = |ch: &char| ch.is_whitespace() && *ch != '\n';
+
+        while chars.next_if(is_inline_whitespace).is_some() {}
+
+        // `!=` is a comparison, not a strict pattern.
+        if chars.next_if_eq(&'!').is_some() && chars.peek().copied().map_or(false, is_operator) {
+            return false;
+        }
+
+        while chars.next_if(is_inline_whitespace).is_some() {}
+
+        chars.next() == Some('=') && !matches!(chars.peek(), Some('=' | '>'))
+    }
+
     fn query_end(&mut self, start: usize) -> Option<usize> {
         match self.rquery_indices.last() {
             Some(end) if start > 0 && start.saturating_sub(1) == *end => self.rquery_indices.pop(),
@@ -1092,6 +1222,11 @@ impl<'input> Lexer<'input> {
             Some((_, ch)) if is_ident_continue(ch) && !negative => {
                 self.bump();
                 let (end, ident) = self.take_while(start, is_ident_continue);
+
+                if duration_seconds(ident).is_some() {
+                    return Ok((start, Token::DurationLiteral(ident), end));
+                }
+
                 Ok((start, Token::ident(ident), end))
             }
             Some((_, '.')) => {
@@ -1190,6 +1325,7 @@ impl<'input> Lexer<'input> {
             rquery_indices: vec![],
             query_start: None,
             coalesce_state: None,
+            pattern_start: None,
         }
     }
 
@@ -1282,7 +1418,7 @@ fn is_digit(ch: char) -> bool {
 pub(crate) fn is_operator(ch: char) -> bool {
     matches!(
         ch,
-        '!' | '&' | '*' | '+' | '-' | '/' | '<' | '=' | '>' | '?' | '|'
+        '!' | '&' | '*' | '+' | '-' | '/' | '<' | '=' | '>' | '?' | '^' | '|'
     )
 }
 
@@ -1328,10 +1464,10 @@ mod test {
 
     use super::*;
     use crate::lex::Token::{
-        Arrow, Bang, Colon, Comma, Dot, Else, Equals, FloatLiteral, FunctionCall, Identifier, If,
-        IntegerLiteral, LBrace, LBracket, LParen, LQuery, Newline, Operator, PathField, Percent,
-        RBrace, RBracket, RParen, RQuery, RawStringLiteral, RegexLiteral, StringLiteral,
-        TimestampLiteral, True,
+        Arrow, Bang, Colon, Comma, Dot, DurationLiteral, Else, Equals, FloatLiteral, FunctionCall,
+        Identifier, If, IntegerLiteral, LBrace, LBracket, LParen, LPattern, LQuery, Newline,
+        Operator, PathField, Percent, RBrace, RBracket, RParen, RQuery, RawStringLiteral,
+        RegexLiteral, StringLiteral, TimestampLiteral, True, Underscore,
     };
 
     fn lexer(input: &str) -> impl Iterator<Item = SpannedResult<'_, usize>> + '_ {
@@ -1623,6 +1759,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn duration_literals() {
+        test(
+            data(r#"5m 1_000ms 30s 1d 5m30s 5x"#),
+            vec![
+                (r#"~~                        "#, DurationLiteral("5m")),
+                (r#"   ~~~~~~~                "#, DurationLiteral("1_000ms")),
+                (r#"           ~~~            "#, DurationLiteral("30s")),
+                (r#"               ~~         "#, DurationLiteral("1d")),
+                (r#"                  ~~~~~   "#, Identifier("5m30s")),
+                (r#"                        ~~"#, Identifier("5x")),
+            ],
+        );
+    }
+
     #[test]
     fn identifiers() {
         test(
@@ -2238,4 +2389,75 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn object_pattern() {
+        test(
+            data("{a, b} = .foo"),
+            vec![
+                ("~            ", LPattern),
+                ("~            ", LBrace),
+                (" ~           ", Identifier("a")),
+                ("  ~          ", Comma),
+                ("    ~        ", Identifier("b")),
+                ("     ~       ", RBrace),
+                ("       ~     ", Equals),
+                ("         ~   ", LQuery),
+                ("         ~   ", Dot),
+                ("          ~~~", Identifier("foo")),
+                ("            ~", RQuery),
+            ],
+        );
+    }
+
+    #[test]
+    fn strict_array_pattern() {
+        test(
+            data("[a, _]! = []"),
+            vec![
+                ("~           ", LPattern),
+                ("~           ", LBracket),
+                (" ~          ", Identifier("a")),
+                ("  ~         ", Comma),
+                ("    ~       ", Underscore),
+                ("     ~      ", RBracket),
+                ("      ~     ", Bang),
+                ("        ~   ", Equals),
+                ("          ~ ", LBracket),
+                ("           ~", RBracket),
+            ],
+        );
+    }
+
+    #[test]
+    fn block_compared_to_value() {
+        test(
+            data("{a} != b"),
+            vec![
+                ("~       ", LBrace),
+                (" ~      ", Identifier("a")),
+                ("  ~     ", RBrace),
+                ("    ~~  ", Operator("!=")),
+                ("       ~", Identifier("b")),
+            ],
+        );
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        test(
+            data("a & b ^ c << 1 >> d"),
+            vec![
+                ("~                  ", Identifier("a")),
+                ("  ~                ", Ampersand),
+                ("    ~              ", Identifier("b")),
+                ("      ~            ", Operator("^")),
+                ("        ~          ", Identifier("c")),
+                ("          ~~       ", Operator("<<")),
+                ("             ~     ", IntegerLiteral(1)),
+                ("               ~~  ", Operator(">>")),
+                ("                  ~", Identifier("d")),
+            ],
+        );
+    }
 }
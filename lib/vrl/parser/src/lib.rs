@@ -71,3 +71,32 @@ pub fn parse_literal(input: impl AsRef<str>) -> Result<Literal, Error> {
             dropped_tokens: vec![],
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use diagnostic::DiagnosticMessage;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::ast::RootExpr;
+
+    // Keywords that used to be valid variable names.
+    #[test_case("match = 1" ; "match")]
+    #[test_case("catch = 1" ; "catch")]
+    #[test_case("drop = 1" ; "drop")]
+    fn keyword_as_variable_name(source: &str) {
+        let codes = match parse(source) {
+            Ok(program) => program
+                .0
+                .iter()
+                .filter_map(|expr| match expr.inner() {
+                    RootExpr::Error(error) => Some(error.code()),
+                    RootExpr::Expr(_) => None,
+                })
+                .collect::<Vec<_>>(),
+            Err(error) => vec![error.code()],
+        };
+
+        assert_eq!(codes, vec![203]);
+    }
+}
@@ -36,6 +36,7 @@ static RESERVED: &[&str] = &[
     "each",
     "any",
     "try",
+    "catch",
     "undefined",
     "int",
     "integer",
@@ -215,6 +216,10 @@ fn opcode() -> impl Strategy<Value = Opcode> {
         Just(Opcode::Gt),
         Just(Opcode::Le),
         Just(Opcode::Lt),
+        Just(Opcode::BitAnd),
+        Just(Opcode::BitXor),
+        Just(Opcode::Shl),
+        Just(Opcode::Shr),
     ]
 }
 
@@ -12,6 +12,7 @@ fn assert(condition: Value, message: Option<Value>, format: Option<String>) -> R
                     message: message.clone(),
                     labels: vec![],
                     notes: vec![Note::UserErrorMessage(message)],
+                    code: 0,
                 })
             } else {
                 let message = match format {
@@ -4,20 +4,31 @@ use vrl::{diagnostic::Note, prelude::*};
 
 fn assert_eq(left: Value, right: Value, message: Option<Value>) -> Resolved {
     if left == right {
-        Ok(true.into())
-    } else if let Some(message) = message {
-        let message = message.try_bytes_utf8_lossy()?.into_owned();
-        Err(ExpressionError::Error {
-            message: message.clone(),
-            labels: vec![],
-            notes: vec![Note::UserErrorMessage(message)],
-        })
-    } else {
-        Err(ExpressionError::from(format!(
-            "assertion failed: {} == {}",
-            left, right
-        )))
+        return Ok(true.into());
     }
+
+    // Render both values on their own line, so that the difference between
+    // them is easier to spot than in the single-line error message.
+    let mut notes = vec![
+        Note::Basic(format!(" left: {}", left)),
+        Note::Basic(format!("right: {}", right)),
+    ];
+
+    let message = match message {
+        Some(message) => {
+            let message = message.try_bytes_utf8_lossy()?.into_owned();
+            notes.push(Note::UserErrorMessage(message.clone()));
+            message
+        }
+        None => format!("assertion failed: {} == {}", left, right),
+    };
+
+    Err(ExpressionError::Error {
+        message,
+        labels: vec![],
+        notes,
+        code: 0,
+    })
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -135,4 +146,31 @@ mod tests {
             tdef: TypeDef::boolean().fallible(),
         }
     ];
+
+    #[test]
+    fn notes_render_both_values() {
+        let error = assert_eq(value!({ "foo": 1 }), value!({ "foo": 2 }), None).unwrap_err();
+
+        assert_eq!(
+            error.notes(),
+            vec![
+                Note::Basic(r#" left: { "foo": 1 }"#.to_owned()),
+                Note::Basic(r#"right: { "foo": 2 }"#.to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn notes_keep_custom_message() {
+        let error = assert_eq(value!(1), value!(2), Some(value!("failure!"))).unwrap_err();
+
+        assert_eq!(
+            error.notes(),
+            vec![
+                Note::Basic(" left: 1".to_owned()),
+                Note::Basic("right: 2".to_owned()),
+                Note::UserErrorMessage("failure!".to_owned()),
+            ]
+        );
+    }
 }
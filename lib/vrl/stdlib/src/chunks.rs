@@ -3,21 +3,35 @@ use vrl::prelude::*;
 use vrl::state::TypeState;
 
 fn chunks(value: Value, chunk_size: Value) -> Resolved {
-    let bytes = value.try_bytes()?;
     let chunk_size = chunk_size.try_integer()?;
 
     if chunk_size < 1 {
-        return Err(r#""chunk_size" must be at least 1 byte"#.into());
+        return Err(r#""chunk_size" must be at least 1"#.into());
     }
 
-    if let Ok(chunk_size) = usize::try_from(chunk_size) {
-        Ok(bytes.chunks(chunk_size).collect::<Vec<_>>().into())
-    } else {
-        Err(format!(
-            r#""chunk_size" is too large: must be at most {} bytes"#,
-            usize::MAX
-        )
-        .into())
+    let chunk_size = match usize::try_from(chunk_size) {
+        Ok(chunk_size) => chunk_size,
+        Err(_) => {
+            return Err(format!(
+                r#""chunk_size" is too large: must be at most {}"#,
+                usize::MAX
+            )
+            .into())
+        }
+    };
+
+    match value {
+        Value::Bytes(bytes) => Ok(bytes.chunks(chunk_size).collect::<Vec<_>>().into()),
+        Value::Array(array) => Ok(array
+            .chunks(chunk_size)
+            .map(|chunk| Value::from(chunk.to_vec()))
+            .collect::<Vec<_>>()
+            .into()),
+        value => Err(value::Error::Expected {
+            got: value.kind(),
+            expected: Kind::bytes() | Kind::array(Collection::any()),
+        }
+        .into()),
     }
 }
 
@@ -33,7 +47,7 @@ impl Function for Chunks {
         &[
             Parameter {
                 keyword: "value",
-                kind: kind::BYTES,
+                kind: kind::BYTES | kind::ARRAY,
                 required: true,
             },
             Parameter {
@@ -56,6 +70,11 @@ impl Function for Chunks {
                 source: r#"chunks("ab你好", 4)"#,
                 result: Ok(r#"["ab�","�好"]"#),
             },
+            Example {
+                title: "chunks of an array",
+                source: r#"chunks([1, 2, 3, 4, 5], 2)"#,
+                result: Ok("[[1, 2], [3, 4], [5]]"),
+            },
         ]
     }
 
@@ -76,7 +95,7 @@ impl Function for Chunks {
                     return Err(vrl::function::Error::InvalidArgument {
                         keyword: "chunk_size",
                         value: literal,
-                        error: r#""chunk_size" must be at least 1 byte"#,
+                        error: r#""chunk_size" must be at least 1"#,
                     }
                     .into());
                 }
@@ -110,10 +129,19 @@ impl FunctionExpression for ChunksFn {
         chunks(value, chunk_size)
     }
 
-    fn type_def(&self, _state: &TypeState) -> TypeDef {
+    fn type_def(&self, state: &TypeState) -> TypeDef {
         let not_literal = self.chunk_size.as_value().is_none();
 
-        TypeDef::array(Collection::from_unknown(Kind::bytes())).with_fallibility(not_literal)
+        let chunk = match self.value.type_def(state) {
+            v if v.is_bytes() => Kind::bytes(),
+            v if v.is_array() => Kind::array(Collection::from_unknown(
+                v.as_array()
+                    .map_or_else(Kind::any, Collection::reduced_kind),
+            )),
+            _ => Kind::bytes().or_array(Collection::any()),
+        };
+
+        TypeDef::array(Collection::from_unknown(chunk)).with_fallibility(not_literal)
     }
 }
 
@@ -140,5 +168,21 @@ mod tests {
             want: Ok(value!([b"ab\xe4\xbd", b"\xa0\xe5\xa5\xbd"])),
             tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
         }
+
+        array {
+            args: func_args![value: value!([1, 2, 3, 4, 5]),
+                             chunk_size: 2,
+            ],
+            want: Ok(value!([[1, 2], [3, 4], [5]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::from_unknown(Kind::integer())))),
+        }
+
+        array_larger_than_chunk_size {
+            args: func_args![value: value!([1, "two"]),
+                             chunk_size: 3,
+            ],
+            want: Ok(value!([[1, "two"]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::from_unknown(Kind::integer().or_bytes())))),
+        }
     ];
 }
@@ -0,0 +1,130 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Coalesce;
+
+impl Function for Coalesce {
+    fn identifier(&self) -> &'static str {
+        "coalesce"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "values",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "default",
+                kind: kind::ANY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "first non-null value",
+                source: r#"coalesce([null, "foo", "bar"])"#,
+                result: Ok("foo"),
+            },
+            Example {
+                title: "missing fields",
+                source: r#"coalesce([.host, .hostname], "localhost")"#,
+                result: Ok("localhost"),
+            },
+            Example {
+                title: "no default",
+                source: r#"coalesce([null, .missing])"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let values = arguments.required_array("values")?;
+        let default = arguments.optional("default");
+
+        Ok(CoalesceFn { values, default }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CoalesceFn {
+    values: Vec<expression::Expr>,
+    default: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for CoalesceFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        // Candidates after the first non-null one are never resolved.
+        for expr in &self.values {
+            let value = expr.resolve(ctx)?;
+
+            if !value.is_null() {
+                return Ok(value);
+            }
+        }
+
+        self.default
+            .as_ref()
+            .map_or(Ok(Value::Null), |expr| expr.resolve(ctx))
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let fallback = self
+            .default
+            .as_ref()
+            .map_or_else(TypeDef::null, |expr| expr.type_def(state));
+
+        // A candidate only becomes the result if it isn't null.
+        self.values.iter().rev().fold(fallback, |type_def, expr| {
+            let mut candidate = expr.type_def(state);
+            candidate.kind_mut().remove_null();
+            candidate.kind_mut().remove_undefined();
+
+            candidate.union(type_def)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        coalesce => Coalesce;
+
+        first_non_null {
+            args: func_args![values: value!([null, "foo", 1])],
+            want: Ok("foo"),
+            tdef: TypeDef::bytes().or_integer().or_null().infallible(),
+        }
+
+        all_null {
+            args: func_args![values: value!([null, null])],
+            want: Ok(value!(null)),
+            tdef: TypeDef::null().infallible(),
+        }
+
+        default {
+            args: func_args![values: value!([null]), default: value!(2.5)],
+            want: Ok(value!(2.5)),
+            tdef: TypeDef::float().infallible(),
+        }
+
+        empty {
+            args: func_args![values: value!([]), default: true],
+            want: Ok(value!(true)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+    ];
+}
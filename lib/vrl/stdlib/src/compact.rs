@@ -237,10 +237,14 @@ impl FunctionExpression for CompactFn {
     }
 
     fn type_def(&self, state: &state::TypeState) -> TypeDef {
-        if self.value.type_def(state).is_array() {
+        let value = self.value.type_def(state);
+
+        if value.is_array() {
             TypeDef::array(Collection::any())
-        } else {
+        } else if value.is_object() {
             TypeDef::object(Collection::any())
+        } else {
+            TypeDef::array(Collection::any()).or_object(Collection::any())
         }
     }
 }
@@ -485,5 +489,23 @@ mod test {
             want: Ok(Value::Object(BTreeMap::from([(String::from("key2"), Value::from(1))]))),
             tdef: TypeDef::object(Collection::any()),
         }
+
+        keep_empty_arrays {
+            args: func_args![
+                value: value!({"key1": null, "key2": [], "key3": {"key4": ""}}),
+                array: false
+            ],
+            want: Ok(value!({"key2": []})),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        not_recursive {
+            args: func_args![
+                value: value!({"key1": null, "key2": {"key3": null}}),
+                recursive: false
+            ],
+            want: Ok(value!({"key2": {"key3": null}})),
+            tdef: TypeDef::object(Collection::any()),
+        }
     ];
 }
@@ -6,7 +6,7 @@ use vrl::prelude::*;
 
 use crate::util::Base64Charset;
 
-fn decode_base64(charset: Option<Value>, value: Value) -> Resolved {
+fn decode_base64(charset: Option<Value>, strict: Option<Value>, value: Value) -> Resolved {
     let charset = charset
         .map(Value::try_bytes)
         .transpose()?
@@ -17,8 +17,18 @@ fn decode_base64(charset: Option<Value>, value: Value) -> Resolved {
         Base64Charset::Standard => base64::STANDARD,
         Base64Charset::UrlSafe => base64::URL_SAFE,
     };
+    let strict = strict
+        .map(VrlValueConvert::try_boolean)
+        .transpose()?
+        .unwrap_or(false);
     let value = value.try_bytes()?;
 
+    // The decoder accepts input with or without padding, so it's up to us to reject unpadded
+    // input in strict mode.
+    if strict && value.len() % 4 != 0 {
+        return Err("unable to decode value to base64: padding is required".into());
+    }
+
     match base64::decode_config(value, config) {
         Ok(s) => Ok(Value::from(Bytes::from(s))),
         Err(_) => Err("unable to decode value to base64".into()),
@@ -45,6 +55,11 @@ impl Function for DecodeBase64 {
                 kind: kind::BYTES,
                 required: false,
             },
+            Parameter {
+                keyword: "strict",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
         ]
     }
 
@@ -56,16 +71,31 @@ impl Function for DecodeBase64 {
     ) -> Compiled {
         let value = arguments.required("value");
         let charset = arguments.optional("charset");
+        let strict = arguments.optional("strict");
 
-        Ok(DecodeBase64Fn { value, charset }.as_expr())
+        Ok(DecodeBase64Fn {
+            value,
+            charset,
+            strict,
+        }
+        .as_expr())
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "demo string",
-            source: r#"decode_base64!("c29tZSBzdHJpbmcgdmFsdWU=")"#,
-            result: Ok(r#"some string value"#),
-        }]
+        &[
+            Example {
+                title: "demo string",
+                source: r#"decode_base64!("c29tZSBzdHJpbmcgdmFsdWU=")"#,
+                result: Ok(r#"some string value"#),
+            },
+            Example {
+                title: "strict padding",
+                source: r#"decode_base64!("c29tZSBzdHJpbmcgdmFsdWU", strict: true)"#,
+                result: Err(
+                    r#"function call error for "decode_base64" at (0:55): unable to decode value to base64: padding is required"#,
+                ),
+            },
+        ]
     }
 }
 
@@ -73,14 +103,16 @@ impl Function for DecodeBase64 {
 struct DecodeBase64Fn {
     value: Box<dyn Expression>,
     charset: Option<Box<dyn Expression>>,
+    strict: Option<Box<dyn Expression>>,
 }
 
 impl FunctionExpression for DecodeBase64Fn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
         let charset = self.charset.as_ref().map(|c| c.resolve(ctx)).transpose()?;
+        let strict = self.strict.as_ref().map(|s| s.resolve(ctx)).transpose()?;
 
-        decode_base64(charset, value)
+        decode_base64(charset, strict, value)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -126,5 +158,29 @@ mod test {
             want: Ok(value!("")),
             tdef: TypeDef::bytes().fallible(),
         }
+
+        unpadded {
+            args: func_args![value: value!("c29tZSBzdHJpbmcgdmFsdWU")],
+            want: Ok(value!("some string value")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        strict_padded {
+            args: func_args![value: value!("c29tZSBzdHJpbmcgdmFsdWU="), strict: true],
+            want: Ok(value!("some string value")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        strict_unpadded {
+            args: func_args![value: value!("c29tZSBzdHJpbmcgdmFsdWU"), strict: true],
+            want: Err("unable to decode value to base64: padding is required"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        strict_urlsafe_charset {
+            args: func_args![value: value!("c29tZSs9c3RyaW5nL3ZhbHVl"), charset: value!("url_safe"), strict: true],
+            want: Ok(value!("some+=string/value")),
+            tdef: TypeDef::bytes().fallible(),
+        }
     ];
 }
@@ -0,0 +1,141 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn decode_utf8(value: Value, lossy: bool) -> Resolved {
+    let bytes = value.try_bytes()?;
+
+    if lossy {
+        return Ok(String::from_utf8_lossy(&bytes).into_owned().into());
+    }
+
+    match std::str::from_utf8(&bytes) {
+        Ok(_) => Ok(bytes.into()),
+        Err(err) => Err(format!("unable to decode value as UTF-8: {}", err).into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeUtf8;
+
+impl Function for DecodeUtf8 {
+    fn identifier(&self) -> &'static str {
+        "decode_utf8"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "lossy",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "valid",
+                source: r#"decode_utf8!("foo")"#,
+                result: Ok("foo"),
+            },
+            Example {
+                title: "invalid",
+                source: r#"decode_utf8!(decode_base64!("/w=="))"#,
+                result: Err(
+                    r#"function call error for "decode_utf8" at (0:36): unable to decode value as UTF-8: invalid utf-8 sequence of 1 bytes from index 0"#,
+                ),
+            },
+            Example {
+                title: "lossy",
+                source: r#"decode_utf8(decode_base64!("Zm//bw=="), lossy: true)"#,
+                result: Ok("fo\u{FFFD}o"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let lossy = arguments.optional("lossy").unwrap_or(expr!(false));
+
+        Ok(DecodeUtf8Fn { value, lossy }.as_expr())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeUtf8Fn {
+    value: Box<dyn Expression>,
+    lossy: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DecodeUtf8Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let lossy = self.lossy.resolve(ctx)?.try_boolean()?;
+
+        decode_utf8(value, lossy)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Decoding can only fail if invalid sequences aren't replaced, which is
+        // known in advance if `lossy` is a literal.
+        let lossy = matches!(self.lossy.as_value(), Some(Value::Boolean(true)));
+
+        TypeDef::bytes().with_fallibility(!lossy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        decode_utf8 => DecodeUtf8;
+
+        valid {
+            args: func_args![value: "föö"],
+            want: Ok("föö"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        invalid {
+            args: func_args![value: value!(b"f\xffo")],
+            want: Err("unable to decode value as UTF-8: invalid utf-8 sequence of 1 bytes from index 1"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        truncated_sequence {
+            args: func_args![value: value!(b"f\xc3")],
+            want: Err("unable to decode value as UTF-8: incomplete utf-8 byte sequence from index 1"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        lossy {
+            args: func_args![value: value!(b"f\xffo"), lossy: true],
+            want: Ok("f\u{FFFD}o"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        lossy_valid {
+            args: func_args![value: "föö", lossy: true],
+            want: Ok("föö"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        not_lossy {
+            args: func_args![value: "föö", lossy: false],
+            want: Ok("föö"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
@@ -63,6 +63,11 @@ impl Function for EncodeLogfmt {
                 source: r#"encode_logfmt!({"msg": "This is a message", "lvl": "info", "log_id": 12345}, ["lvl", "msg"])"#,
                 result: Ok(r#"s'lvl=info msg="This is a message" log_id=12345'"#),
             },
+            Example {
+                title: "encode object with delimiters in values",
+                source: r#"encode_logfmt({"query": "id=1", "lvl": "info"})"#,
+                result: Ok(r#"s'lvl=info query="id=1"'"#),
+            },
         ]
     }
 }
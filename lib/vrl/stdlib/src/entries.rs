@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+fn entries(value: Value) -> Resolved {
+    let object = value.try_object()?;
+
+    Ok(object
+        .into_iter()
+        .map(|(key, value)| Value::from(vec![Value::from(key), value]))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Entries;
+
+impl Function for Entries {
+    fn identifier(&self) -> &'static str {
+        "entries"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::OBJECT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "entries",
+            source: r#"entries({"a": 1, "b": true})"#,
+            result: Ok(r#"[["a", 1], ["b", true]]"#),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(EntriesFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EntriesFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for EntriesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        entries(value)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        let value = self
+            .value
+            .type_def(state)
+            .as_object()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        let entry = Kind::array(BTreeMap::from([
+            (Index::from(0), Kind::bytes()),
+            (Index::from(1), value),
+        ]));
+
+        TypeDef::array(Collection::from_unknown(entry)).infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_common::btreemap;
+
+    use super::*;
+
+    fn entries_of(value: Kind) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(btreemap! {
+            Index::from(0) => Kind::bytes(),
+            Index::from(1) => value,
+        })))
+    }
+
+    test_function![
+        entries => Entries;
+
+        object {
+            args: func_args![value: value!({"a": 1, "b": "two"})],
+            want: Ok(value!([["a", 1], ["b", "two"]])),
+            tdef: entries_of(Kind::integer().or_bytes()),
+        }
+
+        nested {
+            args: func_args![value: value!({"a": {"b": [1]}})],
+            want: Ok(value!([["a", {"b": [1]}]])),
+            tdef: entries_of(Kind::object(btreemap! {
+                "b" => Kind::array(btreemap! { Index::from(0) => Kind::integer() }),
+            })),
+        }
+
+        empty {
+            args: func_args![value: value!({})],
+            want: Ok(value!([])),
+            tdef: entries_of(Kind::never()),
+        }
+    ];
+}
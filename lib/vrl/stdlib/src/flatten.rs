@@ -1,22 +1,33 @@
-use std::collections::btree_map;
+use std::collections::BTreeMap;
 
 use ::value::Value;
 use vrl::prelude::*;
 
 static DEFAULT_SEPARATOR: &str = ".";
 
-fn flatten(value: Value, separator: Value) -> Resolved {
+fn flatten(
+    value: Value,
+    separator: Value,
+    max_depth: Option<Value>,
+    index_arrays: bool,
+) -> Resolved {
     let separator = separator.try_bytes_utf8_lossy()?;
+    let max_depth = max_depth.map(validate_depth).transpose()?;
 
     match value {
         Value::Array(arr) => Ok(Value::Array(
-            ArrayFlatten::new(arr.iter()).cloned().collect(),
-        )),
-        Value::Object(map) => Ok(Value::Object(
-            MapFlatten::new(map.iter(), &separator)
-                .map(|(k, v)| (k, v.clone()))
-                .collect(),
+            ArrayFlatten::new(arr.iter(), max_depth).cloned().collect(),
         )),
+        Value::Object(map) => {
+            let mut flattened = BTreeMap::new();
+            let options = MapFlatten {
+                separator: &separator,
+                index_arrays,
+            };
+            options.flatten_object(&mut flattened, None, map, max_depth);
+
+            Ok(Value::Object(flattened))
+        }
         value => Err(value::Error::Expected {
             got: value.kind(),
             expected: Kind::array(Collection::any()) | Kind::object(Collection::any()),
@@ -25,6 +36,16 @@ fn flatten(value: Value, separator: Value) -> Resolved {
     }
 }
 
+fn validate_depth(value: Value) -> std::result::Result<usize, ExpressionError> {
+    let depth = value.try_integer()?;
+
+    if depth > 0 {
+        Ok(depth as usize)
+    } else {
+        Err(format!("max_depth must be greater than 0, got {}", depth).into())
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct Flatten;
 
@@ -45,6 +66,16 @@ impl Function for Flatten {
                 kind: kind::BYTES,
                 required: false,
             },
+            Parameter {
+                keyword: "max_depth",
+                kind: kind::INTEGER,
+                required: false,
+            },
+            Parameter {
+                keyword: "index_arrays",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
         ]
     }
 
@@ -65,6 +96,16 @@ impl Function for Flatten {
                 source: r#"flatten([[true]])"#,
                 result: Ok(r#"[true]"#),
             },
+            Example {
+                title: "max depth",
+                source: r#"flatten({ "foo": { "bar": { "baz": true }}}, max_depth: 1)"#,
+                result: Ok(r#"{ "foo.bar": { "baz": true } }"#),
+            },
+            Example {
+                title: "index arrays",
+                source: r#"flatten({ "foo": [{ "bar": true }, 2] }, index_arrays: true)"#,
+                result: Ok(r#"{ "foo.0.bar": true, "foo.1": 2 }"#),
+            },
         ]
     }
 
@@ -78,7 +119,27 @@ impl Function for Flatten {
             .optional("separator")
             .unwrap_or_else(|| expr!(DEFAULT_SEPARATOR));
         let value = arguments.required("value");
-        Ok(FlattenFn { value, separator }.as_expr())
+        let max_depth = arguments.optional("max_depth");
+        let index_arrays = arguments.optional("index_arrays");
+
+        if let Some(literal) = max_depth.as_ref().and_then(|expr| expr.as_value()) {
+            if !matches!(literal, Value::Integer(depth) if depth > 0) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "max_depth",
+                    value: literal,
+                    error: "max_depth must be greater than 0",
+                }
+                .into());
+            }
+        }
+
+        Ok(FlattenFn {
+            value,
+            separator,
+            max_depth,
+            index_arrays,
+        }
+        .as_expr())
     }
 }
 
@@ -86,14 +147,25 @@ impl Function for Flatten {
 struct FlattenFn {
     value: Box<dyn Expression>,
     separator: Box<dyn Expression>,
+    max_depth: Option<Box<dyn Expression>>,
+    index_arrays: Option<Box<dyn Expression>>,
 }
 
 impl FunctionExpression for FlattenFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
         let separator = self.separator.resolve(ctx)?;
-
-        flatten(value, separator)
+        let max_depth = self
+            .max_depth
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+        let index_arrays = match &self.index_arrays {
+            Some(expr) => expr.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+
+        flatten(value, separator, max_depth, index_arrays)
     }
 
     fn type_def(&self, state: &state::TypeState) -> TypeDef {
@@ -107,70 +179,67 @@ impl FunctionExpression for FlattenFn {
     }
 }
 
-/// An iterator to walk over maps allowing us to flatten nested maps to a single level.
+/// Flattens nested maps to a single level, joining the keys of each level with
+/// the separator.
 struct MapFlatten<'a> {
-    values: btree_map::Iter<'a, String, Value>,
     separator: &'a str,
-    inner: Option<Box<MapFlatten<'a>>>,
-    parent: Option<String>,
+
+    /// Whether arrays nested in the map are flattened into keys containing the
+    /// index of each element, or kept as array values.
+    index_arrays: bool,
 }
 
 impl<'a> MapFlatten<'a> {
-    fn new(values: btree_map::Iter<'a, String, Value>, separator: &'a str) -> Self {
-        Self {
-            values,
-            separator,
-            inner: None,
-            parent: None,
+    fn flatten_object(
+        &self,
+        flattened: &mut BTreeMap<String, Value>,
+        parent: Option<&str>,
+        map: BTreeMap<String, Value>,
+        max_depth: Option<usize>,
+    ) {
+        for (key, value) in map {
+            let key = self.new_key(parent, &key);
+            self.flatten_value(flattened, key, value, max_depth);
         }
     }
 
-    fn new_from_parent(
-        parent: String,
-        values: btree_map::Iter<'a, String, Value>,
-        separator: &'a str,
-    ) -> Self {
-        Self {
-            values,
-            separator,
-            inner: None,
-            parent: Some(parent),
+    fn flatten_value(
+        &self,
+        flattened: &mut BTreeMap<String, Value>,
+        key: String,
+        value: Value,
+        max_depth: Option<usize>,
+    ) {
+        // Once the maximum depth is reached, nested values are kept as they are.
+        let remaining_depth = match max_depth {
+            Some(0) => {
+                flattened.insert(key, value);
+                return;
+            }
+            depth => depth.map(|depth| depth - 1),
+        };
+
+        match value {
+            Value::Object(map) => {
+                self.flatten_object(flattened, Some(&key), map, remaining_depth);
+            }
+            Value::Array(array) if self.index_arrays => {
+                for (index, value) in array.into_iter().enumerate() {
+                    let key = self.new_key(Some(&key), &index.to_string());
+                    self.flatten_value(flattened, key, value, remaining_depth);
+                }
+            }
+            value => {
+                flattened.insert(key, value);
+            }
         }
     }
 
     /// Returns the key with the parent prepended.
-    fn new_key(&self, key: &str) -> String {
-        match self.parent {
+    fn new_key(&self, parent: Option<&str>, key: &str) -> String {
+        match parent {
             None => key.to_string(),
-            Some(ref parent) => format!("{}{}{}", parent, self.separator, key),
-        }
-    }
-}
-
-impl<'a> std::iter::Iterator for MapFlatten<'a> {
-    type Item = (String, &'a Value);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(ref mut inner) = self.inner {
-            let next = inner.next();
-            match next {
-                Some(_) => return next,
-                None => self.inner = None,
-            }
-        }
-
-        let next = self.values.next();
-        match next {
-            Some((key, Value::Object(value))) => {
-                self.inner = Some(Box::new(MapFlatten::new_from_parent(
-                    self.new_key(key),
-                    value.iter(),
-                    self.separator,
-                )));
-                self.next()
-            }
-            Some((key, value)) => Some((self.new_key(key), value)),
-            None => None,
+            Some(parent) => format!("{}{}{}", parent, self.separator, key),
         }
     }
 }
@@ -180,13 +249,17 @@ impl<'a> std::iter::Iterator for MapFlatten<'a> {
 struct ArrayFlatten<'a> {
     values: std::slice::Iter<'a, Value>,
     inner: Option<Box<ArrayFlatten<'a>>>,
+
+    /// The number of nested levels left to flatten, if limited.
+    max_depth: Option<usize>,
 }
 
 impl<'a> ArrayFlatten<'a> {
-    fn new(values: std::slice::Iter<'a, Value>) -> Self {
+    fn new(values: std::slice::Iter<'a, Value>, max_depth: Option<usize>) -> Self {
         ArrayFlatten {
             values,
             inner: None,
+            max_depth,
         }
     }
 }
@@ -210,9 +283,12 @@ impl<'a> std::iter::Iterator for ArrayFlatten<'a> {
         // Then iterate over our values.
         let next = self.values.next();
         match next {
-            Some(Value::Array(next)) => {
+            Some(Value::Array(next)) if self.max_depth != Some(0) => {
                 // Create a new iterator for this child list.
-                self.inner = Some(Box::new(ArrayFlatten::new(next.iter())));
+                self.inner = Some(Box::new(ArrayFlatten::new(
+                    next.iter(),
+                    self.max_depth.map(|depth| depth - 1),
+                )));
                 self.next()
             }
             _ => next,
@@ -359,5 +435,72 @@ mod test {
             })),
             tdef: TypeDef::object(Collection::any()),
         }
+
+        map_max_depth {
+            args: func_args![value: value!({
+                parent: {
+                    child1: { grandchild1: { leaf: 1 } },
+                    child2: 2,
+                },
+                key: "val",
+            }), max_depth: 2],
+            want: Ok(value!({
+                "parent.child1.grandchild1": { leaf: 1 },
+                "parent.child2": 2,
+                key: "val",
+            })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        array_max_depth {
+            args: func_args![value: value!([42, [43, [44, [45]]]]), max_depth: 1],
+            want: Ok(value!([42, 43, [44, [45]]])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        invalid_max_depth {
+            args: func_args![value: value!({ parent: { child: 1 } }), max_depth: 0],
+            want: Err("invalid argument"),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        index_arrays {
+            args: func_args![value: value!({
+                parent: {
+                    child1: [1, [2, 3]],
+                    child2: [{ grandchild1: 1 }, {}],
+                    child3: [],
+                },
+                key: "val",
+            }), index_arrays: true],
+            want: Ok(value!({
+                "parent.child1.0": 1,
+                "parent.child1.1.0": 2,
+                "parent.child1.1.1": 3,
+                "parent.child2.0.grandchild1": 1,
+                key: "val",
+            })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        index_arrays_max_depth {
+            args: func_args![value: value!({
+                parent: [[1, 2], { child: 3 }],
+            }), separator: "_", max_depth: 2, index_arrays: true],
+            want: Ok(value!({
+                "parent_0_0": 1,
+                "parent_0_1": 2,
+                "parent_1_child": 3,
+            })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        index_arrays_depth_limit {
+            args: func_args![value: value!({
+                parent: [[1, 2]],
+            }), max_depth: 1, index_arrays: true],
+            want: Ok(value!({ "parent.0": [1, 2] })),
+            tdef: TypeDef::object(Collection::any()),
+        }
     ];
 }
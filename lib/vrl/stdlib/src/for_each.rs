@@ -6,11 +6,19 @@ where
     T: Fn(&mut Context) -> Resolved,
 {
     for item in value.into_iter(false) {
-        match item {
-            IterItem::KeyValue(key, value) => runner.run_key_value(ctx, key, value)?,
-            IterItem::IndexValue(index, value) => runner.run_index_value(ctx, index, value)?,
+        let result = match item {
+            IterItem::KeyValue(key, value) => runner.run_key_value(ctx, key, value),
+            IterItem::IndexValue(index, value) => runner.run_index_value(ctx, index, value),
             IterItem::Value(_) => continue,
         };
+
+        // A `break` or `continue` stops the iteration with an error, which
+        // isn't a failure of the loop.
+        match ctx.take_control_flow() {
+            Some(ControlFlow::Break) => break,
+            Some(ControlFlow::Continue) => continue,
+            None => result?,
+        };
     }
 
     Ok(Value::Null)
@@ -82,6 +90,7 @@ impl Function for ForEach {
                 },
             }],
             is_iterator: true,
+            supports_loop_control: true,
         })
     }
 }
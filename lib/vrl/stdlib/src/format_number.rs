@@ -8,9 +8,11 @@ fn format_number(
     grouping_separator: Option<Value>,
     decimal_separator: Option<Value>,
 ) -> Resolved {
-    let value: Decimal = match value {
-        Value::Integer(v) => v.into(),
-        Value::Float(v) => Decimal::from_f64(*v).expect("not NaN"),
+    let value = match value {
+        Value::Integer(v) => v.to_string(),
+        // Floats outside of the range of a decimal are formatted as is, which
+        // doesn't use scientific notation either.
+        Value::Float(v) => Decimal::from_f64(*v).map_or_else(|| v.to_string(), |v| v.to_string()),
         value => {
             return Err(value::Error::Expected {
                 got: value.kind(),
@@ -31,9 +33,13 @@ fn format_number(
         Some(expr) => expr.try_bytes()?,
         None => ".".into(),
     };
+    // Keep the sign out of the way of the grouping separators.
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(value) => ("-", value),
+        None => ("", value.as_str()),
+    };
     // Split integral and fractional part of float.
     let mut parts = value
-        .to_string()
         .split('.')
         .map(ToOwned::to_owned)
         .collect::<Vec<String>>();
@@ -63,22 +69,19 @@ fn format_number(
         let sep = String::from_utf8_lossy(sep);
         let start = parts[0].len() % 3;
 
-        let positions: Vec<usize> = parts[0]
-            .chars()
-            .skip(start)
-            .enumerate()
-            .map(|(i, _)| i)
-            .filter(|i| i % 3 == 0)
+        let positions: Vec<usize> = (start..parts[0].len())
+            .step_by(3)
+            .filter(|pos| *pos > 0)
             .collect();
 
         for (i, pos) in positions.iter().enumerate() {
-            parts[0].insert_str(pos + (i * sep.len()) + start, &sep);
+            parts[0].insert_str(pos + (i * sep.len()), &sep);
         }
     }
     // Join results, using configured decimal separator.
-    Ok(parts
-        .join(&String::from_utf8_lossy(&decimal_separator[..]))
-        .into())
+    let number = parts.join(&String::from_utf8_lossy(&decimal_separator[..]));
+
+    Ok(format!("{}{}", sign, number).into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -251,5 +254,42 @@ mod tests {
             want: Ok(value!("12345.00")),
             tdef: TypeDef::bytes().infallible(),
         }
+
+        negative {
+            args: func_args![value: -1234.5,
+                             scale: 2,
+                             decimal_separator: ".",
+                             grouping_separator: ","],
+            want: Ok(value!("-1,234.50")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        negative_single_group {
+            args: func_args![value: -123,
+                             grouping_separator: ","],
+            want: Ok(value!("-123")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        full_groups {
+            args: func_args![value: -123_456,
+                             grouping_separator: ","],
+            want: Ok(value!("-123,456")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        large_magnitude {
+            args: func_args![value: 1e30,
+                             grouping_separator: ","],
+            want: Ok(value!("1,000,000,000,000,000,000,000,000,000,000")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        small_magnitude {
+            args: func_args![value: 0.000_001,
+                             scale: 6],
+            want: Ok(value!("0.000001")),
+            tdef: TypeDef::bytes().infallible(),
+        }
     ];
 }
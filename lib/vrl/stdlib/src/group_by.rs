@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use lookup_lib::{LookupBuf, SegmentBuf};
+use vrl::prelude::*;
+
+/// What to do with elements that don't have a value at the grouping path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Missing {
+    /// Leave the element out of the result.
+    Skip,
+
+    /// Group the element under the `"null"` key.
+    Null,
+}
+
+fn missing_variants() -> Vec<Value> {
+    vec![value!("skip"), value!("null")]
+}
+
+fn group_by(value: Value, path: Value, missing: Missing) -> Resolved {
+    let array = value.try_array()?;
+    let path = match path {
+        Value::Array(path) => {
+            let mut lookup = LookupBuf::root();
+
+            for segment in path {
+                let segment = match segment {
+                    Value::Bytes(field) => {
+                        SegmentBuf::Field(String::from_utf8_lossy(&field).into_owned().into())
+                    }
+                    Value::Integer(index) => SegmentBuf::Index(index as isize),
+                    value => {
+                        return Err(format!(
+                            r#"path segment must be either string or integer, not {}"#,
+                            value.kind()
+                        )
+                        .into())
+                    }
+                };
+
+                lookup.push_back(segment)
+            }
+
+            lookup
+        }
+        value => {
+            return Err(value::Error::Expected {
+                got: value.kind(),
+                expected: Kind::array(Collection::any()),
+            }
+            .into())
+        }
+    };
+
+    let mut groups: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+
+    for element in array {
+        let key = match element.get_by_path(&path) {
+            Some(Value::Bytes(key)) => String::from_utf8_lossy(key).into_owned(),
+            None | Some(Value::Null) => match missing {
+                Missing::Skip => continue,
+                Missing::Null => "null".to_owned(),
+            },
+            Some(value) => {
+                return Err(format!("group key must be a string, not {}", value.kind()).into())
+            }
+        };
+
+        groups.entry(key).or_default().push(element);
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|(key, group)| (key, Value::from(group)))
+        .collect::<BTreeMap<_, _>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GroupBy;
+
+impl Function for GroupBy {
+    fn identifier(&self) -> &'static str {
+        "group_by"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "path",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "missing",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "group by field",
+                source: r#"group_by!([{"level": "info"}, {"level": "error"}, {"level": "info"}], ["level"])"#,
+                result: Ok(
+                    r#"{"error": [{"level": "error"}], "info": [{"level": "info"}, {"level": "info"}]}"#,
+                ),
+            },
+            Example {
+                title: "skip missing keys",
+                source: r#"group_by!([{"level": "info"}, {"message": "foo"}], ["level"])"#,
+                result: Ok(r#"{"info": [{"level": "info"}]}"#),
+            },
+            Example {
+                title: "group missing keys under null",
+                source: r#"group_by!([{"level": "info"}, {"message": "foo"}], ["level"], missing: "null")"#,
+                result: Ok(r#"{"info": [{"level": "info"}], "null": [{"message": "foo"}]}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let path = arguments.required("path");
+        let missing = match arguments
+            .optional_enum("missing", &missing_variants())?
+            .map(Value::try_bytes)
+            .transpose()
+            .map_err(|e| Box::new(e) as Box<dyn DiagnosticMessage>)?
+        {
+            Some(missing) if missing.as_ref() == b"null" => Missing::Null,
+            _ => Missing::Skip,
+        };
+
+        Ok(GroupByFn {
+            value,
+            path,
+            missing,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GroupByFn {
+    value: Box<dyn Expression>,
+    path: Box<dyn Expression>,
+    missing: Missing,
+}
+
+impl FunctionExpression for GroupByFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let path = self.path.resolve(ctx)?;
+
+        group_by(value, path, self.missing)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Fallible, as the value at the path isn't guaranteed to be a string.
+        TypeDef::object(Collection::from_unknown(Kind::array(Collection::any()))).fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tdef() -> TypeDef {
+        TypeDef::object(Collection::from_unknown(Kind::array(Collection::any()))).fallible()
+    }
+
+    test_function![
+        group_by => GroupBy;
+
+        field {
+            args: func_args![
+                value: value!([{"level": "info", "id": 1}, {"level": "error", "id": 2}, {"level": "info", "id": 3}]),
+                path: value!(["level"]),
+            ],
+            want: Ok(value!({
+                "error": [{"level": "error", "id": 2}],
+                "info": [{"level": "info", "id": 1}, {"level": "info", "id": 3}],
+            })),
+            tdef: tdef(),
+        }
+
+        nested_path {
+            args: func_args![
+                value: value!([{"host": {"name": "a"}}, {"host": {"name": "b"}}]),
+                path: value!(["host", "name"]),
+            ],
+            want: Ok(value!({
+                "a": [{"host": {"name": "a"}}],
+                "b": [{"host": {"name": "b"}}],
+            })),
+            tdef: tdef(),
+        }
+
+        empty {
+            args: func_args![value: value!([]), path: value!(["level"])],
+            want: Ok(value!({})),
+            tdef: tdef(),
+        }
+
+        skip_missing {
+            args: func_args![
+                value: value!([{"level": "info"}, {"message": "foo"}, {"level": null}, 1]),
+                path: value!(["level"]),
+            ],
+            want: Ok(value!({"info": [{"level": "info"}]})),
+            tdef: tdef(),
+        }
+
+        null_missing {
+            args: func_args![
+                value: value!([{"level": "info"}, {"message": "foo"}, {"level": null}]),
+                path: value!(["level"]),
+                missing: "null",
+            ],
+            want: Ok(value!({
+                "info": [{"level": "info"}],
+                "null": [{"message": "foo"}, {"level": null}],
+            })),
+            tdef: tdef(),
+        }
+
+        non_string_key {
+            args: func_args![
+                value: value!([{"level": 1}]),
+                path: value!(["level"]),
+            ],
+            want: Err("group key must be a string, not integer"),
+            tdef: tdef(),
+        }
+
+        invalid_missing {
+            args: func_args![
+                value: value!([]),
+                path: value!(["level"]),
+                missing: "drop",
+            ],
+            want: Err(r#"invalid enum variant""#),
+            tdef: tdef(),
+        }
+    ];
+}
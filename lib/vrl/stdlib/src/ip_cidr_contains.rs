@@ -2,16 +2,15 @@ use ::value::Value;
 use cidr_utils::cidr::IpCidr;
 use vrl::prelude::*;
 
-fn ip_cidr_contains(value: Value, cidr: Value) -> Resolved {
+fn parse_cidr(cidr: &str) -> Result<IpCidr, String> {
+    IpCidr::from_str(cidr).map_err(|err| format!("unable to parse CIDR: {}", err))
+}
+
+fn ip_cidr_contains(value: Value, cidr: &IpCidr) -> Resolved {
     let value = value
         .try_bytes_utf8_lossy()?
         .parse()
         .map_err(|err| format!("unable to parse IP address: {}", err))?;
-    let cidr = {
-        let cidr = cidr.try_bytes_utf8_lossy()?;
-
-        IpCidr::from_str(cidr).map_err(|err| format!("unable to parse CIDR: {}", err))?
-    };
     Ok(cidr.contains(value).into())
 }
 
@@ -50,13 +49,6 @@ impl Function for IpCidrContains {
                 source: r#"ip_cidr_contains!("192.168.0.0/24", "192.168.10.32")"#,
                 result: Ok("false"),
             },
-            Example {
-                title: "invalid cidr",
-                source: r#"ip_cidr_contains!("INVALID", "192.168.10.32")"#,
-                result: Err(
-                    r#"function call error for "ip_cidr_contains" at (0:45): unable to parse CIDR: The CIDR string is incorrect."#,
-                ),
-            },
             Example {
                 title: "invalid address",
                 source: r#"ip_cidr_contains!("192.168.0.0/24", "INVALID")"#,
@@ -76,22 +68,55 @@ impl Function for IpCidrContains {
         let cidr = arguments.required("cidr");
         let value = arguments.required("value");
 
+        // A literal CIDR is parsed once, and rejected if it's invalid.
+        let cidr = match cidr.as_value() {
+            Some(literal) => {
+                let parsed = literal.as_str().map(|cidr| parse_cidr(&cidr));
+
+                match parsed {
+                    Some(Ok(cidr)) => Cidr::Static(cidr),
+                    _ => {
+                        return Err(vrl::function::Error::InvalidArgument {
+                            keyword: "cidr",
+                            value: literal,
+                            error: "unable to parse CIDR",
+                        }
+                        .into())
+                    }
+                }
+            }
+            None => Cidr::Dynamic(cidr),
+        };
+
         Ok(IpCidrContainsFn { cidr, value }.as_expr())
     }
 }
 
+#[derive(Debug, Clone)]
+enum Cidr {
+    Static(IpCidr),
+    Dynamic(Box<dyn Expression>),
+}
+
 #[derive(Debug, Clone)]
 struct IpCidrContainsFn {
-    cidr: Box<dyn Expression>,
+    cidr: Cidr,
     value: Box<dyn Expression>,
 }
 
 impl FunctionExpression for IpCidrContainsFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        let cidr = self.cidr.resolve(ctx)?;
 
-        ip_cidr_contains(value, cidr)
+        match &self.cidr {
+            Cidr::Static(cidr) => ip_cidr_contains(value, cidr),
+            Cidr::Dynamic(cidr) => {
+                let cidr = cidr.resolve(ctx)?;
+                let cidr = parse_cidr(&cidr.try_bytes_utf8_lossy()?)?;
+
+                ip_cidr_contains(value, &cidr)
+            }
+        }
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -137,5 +162,13 @@ mod tests {
             want: Ok(value!(false)),
             tdef: TypeDef::boolean().fallible(),
         }
+
+        invalid_ip {
+            args: func_args![value: "INVALID",
+                             cidr: "192.168.0.0/16",
+            ],
+            want: Err("unable to parse IP address: invalid IP address syntax"),
+            tdef: TypeDef::boolean().fallible(),
+        }
     ];
 }
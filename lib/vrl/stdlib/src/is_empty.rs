@@ -131,5 +131,11 @@ mod tests {
             want: Ok(value!(false)),
             tdef: TypeDef::boolean().infallible(),
         }
+
+        integer {
+            args: func_args![value: 1],
+            want: Err("expected string, array or object, got integer"),
+            tdef: TypeDef::boolean().infallible(),
+        }
     ];
 }
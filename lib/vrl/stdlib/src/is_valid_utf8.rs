@@ -0,0 +1,101 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn is_valid_utf8(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+
+    Ok(std::str::from_utf8(&bytes).is_ok().into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsValidUtf8;
+
+impl Function for IsValidUtf8 {
+    fn identifier(&self) -> &'static str {
+        "is_valid_utf8"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "valid",
+                source: r#"is_valid_utf8("foo")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "invalid",
+                source: r#"is_valid_utf8(decode_base64!("/w=="))"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(IsValidUtf8Fn { value }.as_expr())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct IsValidUtf8Fn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for IsValidUtf8Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        is_valid_utf8(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        is_valid_utf8 => IsValidUtf8;
+
+        valid {
+            args: func_args![value: "föö"],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        empty {
+            args: func_args![value: ""],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        invalid {
+            args: func_args![value: value!(b"f\xffo")],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        truncated_sequence {
+            args: func_args![value: value!(b"f\xc3")],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+    ];
+}
@@ -42,6 +42,8 @@ mod boolean;
 mod ceil;
 #[cfg(feature = "chunks")]
 mod chunks;
+#[cfg(feature = "coalesce")]
+mod coalesce;
 #[cfg(feature = "compact")]
 mod compact;
 #[cfg(feature = "contains")]
@@ -50,6 +52,8 @@ mod contains;
 mod decode_base64;
 #[cfg(feature = "decode_percent")]
 mod decode_percent;
+#[cfg(feature = "decode_utf8")]
+mod decode_utf8;
 #[cfg(feature = "decrypt")]
 mod decrypt;
 #[cfg(feature = "del")]
@@ -70,6 +74,8 @@ mod encode_percent;
 mod encrypt;
 #[cfg(feature = "ends_with")]
 mod ends_with;
+#[cfg(feature = "entries")]
+mod entries;
 #[cfg(feature = "exists")]
 mod exists;
 #[cfg(feature = "filter")]
@@ -96,6 +102,8 @@ mod get;
 mod get_env_var;
 #[cfg(feature = "get_hostname")]
 mod get_hostname;
+#[cfg(feature = "group_by")]
+mod group_by;
 #[cfg(feature = "includes")]
 mod includes;
 #[cfg(feature = "integer")]
@@ -144,6 +152,8 @@ mod is_regex;
 mod is_string;
 #[cfg(feature = "is_timestamp")]
 mod is_timestamp;
+#[cfg(feature = "is_valid_utf8")]
+mod is_valid_utf8;
 #[cfg(feature = "join")]
 mod join;
 #[cfg(feature = "keys")]
@@ -180,6 +190,8 @@ mod mod_func;
 mod now;
 #[cfg(feature = "object")]
 mod object;
+#[cfg(feature = "object_from_entries")]
+mod object_from_entries;
 #[cfg(feature = "only_fields")]
 mod only_fields;
 #[cfg(feature = "parse_apache_log")]
@@ -252,12 +264,16 @@ mod replace;
 mod reverse_dns;
 #[cfg(feature = "round")]
 mod round;
+#[cfg(feature = "sample")]
+mod sample;
 #[cfg(feature = "set")]
 mod set;
 #[cfg(feature = "sha1")]
 mod sha1;
 #[cfg(feature = "sha2")]
 mod sha2;
+#[cfg(feature = "sha256")]
+mod sha256;
 #[cfg(feature = "sha3")]
 mod sha3;
 #[cfg(feature = "slice")]
@@ -284,6 +300,8 @@ mod tally_value;
 mod timestamp;
 #[cfg(feature = "to_bool")]
 mod to_bool;
+#[cfg(feature = "to_case")]
+mod to_case;
 #[cfg(feature = "to_float")]
 mod to_float;
 #[cfg(feature = "to_int")]
@@ -292,6 +310,8 @@ mod to_int;
 mod to_regex;
 #[cfg(feature = "to_string")]
 mod to_string;
+#[cfg(feature = "to_string_lossy")]
+mod to_string_lossy;
 #[cfg(feature = "to_syslog_facility")]
 mod to_syslog_facility;
 #[cfg(feature = "to_syslog_level")]
@@ -310,12 +330,18 @@ mod type_def;
 mod unique;
 #[cfg(feature = "unnest")]
 mod unnest;
+#[cfg(feature = "unzip")]
+mod unzip;
 #[cfg(feature = "upcase")]
 mod upcase;
 #[cfg(feature = "uuid_v4")]
 mod uuid_v4;
 #[cfg(feature = "values")]
 mod values;
+#[cfg(feature = "windows")]
+mod windows;
+#[cfg(feature = "zip")]
+mod zip;
 
 // -----------------------------------------------------------------------------
 
@@ -331,6 +357,8 @@ pub use boolean::Boolean;
 pub use ceil::Ceil;
 #[cfg(feature = "chunks")]
 pub use chunks::Chunks;
+#[cfg(feature = "coalesce")]
+pub use coalesce::Coalesce;
 #[cfg(feature = "compact")]
 pub use compact::Compact;
 #[cfg(feature = "contains")]
@@ -339,6 +367,8 @@ pub use contains::Contains;
 pub use decode_base64::DecodeBase64;
 #[cfg(feature = "decode_percent")]
 pub use decode_percent::DecodePercent;
+#[cfg(feature = "decode_utf8")]
+pub use decode_utf8::DecodeUtf8;
 #[cfg(feature = "decrypt")]
 pub use decrypt::Decrypt;
 #[cfg(feature = "del")]
@@ -359,6 +389,8 @@ pub use encode_percent::EncodePercent;
 pub use encrypt::Encrypt;
 #[cfg(feature = "ends_with")]
 pub use ends_with::EndsWith;
+#[cfg(feature = "entries")]
+pub use entries::Entries;
 #[cfg(feature = "exists")]
 pub use exists::Exists;
 #[cfg(feature = "filter")]
@@ -385,6 +417,8 @@ pub use get::Get;
 pub use get_env_var::GetEnvVar;
 #[cfg(feature = "get_hostname")]
 pub use get_hostname::GetHostname;
+#[cfg(feature = "group_by")]
+pub use group_by::GroupBy;
 #[cfg(feature = "includes")]
 pub use includes::Includes;
 #[cfg(feature = "integer")]
@@ -433,6 +467,8 @@ pub use is_regex::IsRegex;
 pub use is_string::IsString;
 #[cfg(feature = "is_timestamp")]
 pub use is_timestamp::IsTimestamp;
+#[cfg(feature = "is_valid_utf8")]
+pub use is_valid_utf8::IsValidUtf8;
 #[cfg(feature = "join")]
 pub use join::Join;
 #[cfg(feature = "keys")]
@@ -459,6 +495,8 @@ pub use mod_func::Mod;
 pub use now::Now;
 #[cfg(feature = "object")]
 pub use object::Object;
+#[cfg(feature = "object_from_entries")]
+pub use object_from_entries::ObjectFromEntries;
 #[cfg(feature = "only_fields")]
 pub use only_fields::OnlyFields;
 #[cfg(feature = "parse_apache_log")]
@@ -533,10 +571,14 @@ pub use replace::Replace;
 pub use reverse_dns::ReverseDns;
 #[cfg(feature = "round")]
 pub use round::Round;
+#[cfg(feature = "sample")]
+pub use sample::Sample;
 #[cfg(feature = "set")]
 pub use set::Set;
 #[cfg(feature = "sha2")]
 pub use sha2::Sha2;
+#[cfg(feature = "sha256")]
+pub use sha256::Sha256;
 #[cfg(feature = "sha3")]
 pub use sha3::Sha3;
 #[cfg(feature = "slice")]
@@ -563,6 +605,8 @@ pub use tally_value::TallyValue;
 pub use timestamp::Timestamp;
 #[cfg(feature = "to_bool")]
 pub use to_bool::ToBool;
+#[cfg(feature = "to_case")]
+pub use to_case::ToCase;
 #[cfg(feature = "to_float")]
 pub use to_float::ToFloat;
 #[cfg(feature = "to_int")]
@@ -571,6 +615,8 @@ pub use to_int::ToInt;
 pub use to_regex::ToRegex;
 #[cfg(feature = "to_string")]
 pub use to_string::ToString;
+#[cfg(feature = "to_string_lossy")]
+pub use to_string_lossy::ToStringLossy;
 #[cfg(feature = "to_syslog_facility")]
 pub use to_syslog_facility::ToSyslogFacility;
 #[cfg(feature = "to_syslog_level")]
@@ -589,12 +635,18 @@ pub use type_def::TypeDef;
 pub use unique::Unique;
 #[cfg(feature = "unnest")]
 pub use unnest::Unnest;
+#[cfg(feature = "unzip")]
+pub use unzip::Unzip;
 #[cfg(feature = "upcase")]
 pub use upcase::Upcase;
 #[cfg(feature = "uuid_v4")]
 pub use uuid_v4::UuidV4;
 #[cfg(feature = "values")]
 pub use values::Values;
+#[cfg(feature = "windows")]
+pub use windows::Windows;
+#[cfg(feature = "zip")]
+pub use zip::Zip;
 
 #[cfg(feature = "array")]
 pub use crate::array::Array;
@@ -620,6 +672,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Ceil),
         #[cfg(feature = "chunks")]
         Box::new(Chunks),
+        #[cfg(feature = "coalesce")]
+        Box::new(Coalesce),
         #[cfg(feature = "compact")]
         Box::new(Compact),
         #[cfg(feature = "contains")]
@@ -628,6 +682,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(DecodeBase64),
         #[cfg(feature = "decode_percent")]
         Box::new(DecodePercent),
+        #[cfg(feature = "decode_utf8")]
+        Box::new(DecodeUtf8),
         #[cfg(feature = "decrypt")]
         Box::new(Decrypt),
         #[cfg(feature = "del")]
@@ -648,6 +704,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Encrypt),
         #[cfg(feature = "ends_with")]
         Box::new(EndsWith),
+        #[cfg(feature = "entries")]
+        Box::new(Entries),
         #[cfg(feature = "exists")]
         Box::new(Exists),
         #[cfg(feature = "filter")]
@@ -674,6 +732,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(GetEnvVar),
         #[cfg(feature = "get_hostname")]
         Box::new(GetHostname),
+        #[cfg(feature = "group_by")]
+        Box::new(GroupBy),
         #[cfg(feature = "includes")]
         Box::new(Includes),
         #[cfg(feature = "integer")]
@@ -722,6 +782,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(IsString),
         #[cfg(feature = "is_timestamp")]
         Box::new(IsTimestamp),
+        #[cfg(feature = "is_valid_utf8")]
+        Box::new(IsValidUtf8),
         #[cfg(feature = "join")]
         Box::new(Join),
         #[cfg(feature = "keys")]
@@ -756,6 +818,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         //Box::new(OnlyFields),
         #[cfg(feature = "object")]
         Box::new(Object),
+        #[cfg(feature = "object_from_entries")]
+        Box::new(ObjectFromEntries),
         #[cfg(feature = "parse_apache_log")]
         Box::new(ParseApacheLog),
         #[cfg(feature = "parse_aws_alb_log")]
@@ -826,12 +890,16 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ReverseDns),
         #[cfg(feature = "round")]
         Box::new(Round),
+        #[cfg(feature = "sample")]
+        Box::new(Sample),
         #[cfg(feature = "set")]
         Box::new(Set),
         #[cfg(feature = "sha1")]
         Box::new(Sha1),
         #[cfg(feature = "sha2")]
         Box::new(Sha2),
+        #[cfg(feature = "sha256")]
+        Box::new(Sha256),
         #[cfg(feature = "sha3")]
         Box::new(Sha3),
         #[cfg(feature = "slice")]
@@ -858,6 +926,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Timestamp),
         #[cfg(feature = "to_bool")]
         Box::new(ToBool),
+        #[cfg(feature = "to_case")]
+        Box::new(ToCase),
         #[cfg(feature = "to_float")]
         Box::new(ToFloat),
         #[cfg(feature = "to_int")]
@@ -866,6 +936,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ToRegex),
         #[cfg(feature = "to_string")]
         Box::new(ToString),
+        #[cfg(feature = "to_string_lossy")]
+        Box::new(ToStringLossy),
         #[cfg(feature = "to_syslog_facility")]
         Box::new(ToSyslogFacility),
         #[cfg(feature = "to_syslog_level")]
@@ -884,11 +956,17 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Unique),
         #[cfg(feature = "unnest")]
         Box::new(Unnest),
+        #[cfg(feature = "unzip")]
+        Box::new(Unzip),
         #[cfg(feature = "upcase")]
         Box::new(Upcase),
         #[cfg(feature = "uuid_v4")]
         Box::new(UuidV4),
         #[cfg(feature = "values")]
         Box::new(Values),
+        #[cfg(feature = "windows")]
+        Box::new(Windows),
+        #[cfg(feature = "zip")]
+        Box::new(Zip),
     ]
 }
@@ -100,6 +100,7 @@ impl Function for MapKeys {
                 },
             }],
             is_iterator: true,
+            supports_loop_control: false,
         })
     }
 }
@@ -99,6 +99,7 @@ impl Function for MapValues {
                 },
             }],
             is_iterator: true,
+            supports_loop_control: false,
         })
     }
 }
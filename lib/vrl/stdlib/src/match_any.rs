@@ -7,6 +7,18 @@ fn match_any(value: Value, pattern: &RegexSet) -> Resolved {
     Ok(pattern.is_match(&bytes).into())
 }
 
+fn match_any_dynamic(value: Value, patterns: Value) -> Resolved {
+    let string = value.try_bytes_utf8_lossy()?;
+
+    for pattern in patterns.try_array()? {
+        if pattern.try_regex()?.is_match(&string) {
+            return Ok(true.into());
+        }
+    }
+
+    Ok(false.into())
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct MatchAny;
 
@@ -52,43 +64,59 @@ impl Function for MatchAny {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
-        let patterns = arguments.required_array("patterns")?;
-
-        let mut re_strings = Vec::with_capacity(patterns.len());
-        for expr in patterns {
-            let value = expr
-                .as_value()
-                .ok_or(vrl::function::Error::ExpectedStaticExpression {
-                    keyword: "patterns",
-                    expr,
-                })?;
-
-            let re = value
-                .try_regex()
-                .map_err(|e| Box::new(e) as Box<dyn DiagnosticMessage>)?;
-            re_strings.push(re.to_string());
-        }
-
-        let regex_set = RegexSet::new(re_strings).expect("regex were already valid");
-
-        Ok(MatchAnyFn { value, regex_set }.as_expr())
+        let patterns = arguments.required("patterns");
+
+        // Literal patterns are compiled into a single set once, other patterns
+        // are only known when the program runs.
+        let patterns = match patterns.as_value() {
+            Some(value) => {
+                let values = value
+                    .try_array()
+                    .map_err(|e| Box::new(e) as Box<dyn DiagnosticMessage>)?;
+
+                let mut re_strings = Vec::with_capacity(values.len());
+                for value in values {
+                    let re = value
+                        .try_regex()
+                        .map_err(|e| Box::new(e) as Box<dyn DiagnosticMessage>)?;
+                    re_strings.push(re.to_string());
+                }
+
+                Patterns::Static(RegexSet::new(re_strings).expect("regex were already valid"))
+            }
+            None => Patterns::Dynamic(patterns),
+        };
+
+        Ok(MatchAnyFn { value, patterns }.as_expr())
     }
 }
 
+#[derive(Clone, Debug)]
+enum Patterns {
+    Static(RegexSet),
+    Dynamic(Box<dyn Expression>),
+}
+
 #[derive(Clone, Debug)]
 struct MatchAnyFn {
     value: Box<dyn Expression>,
-    regex_set: RegexSet,
+    patterns: Patterns,
 }
 
 impl FunctionExpression for MatchAnyFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        match_any(value, &self.regex_set)
+
+        match &self.patterns {
+            Patterns::Static(regex_set) => match_any(value, regex_set),
+            Patterns::Dynamic(patterns) => match_any_dynamic(value, patterns.resolve(ctx)?),
+        }
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::boolean().infallible()
+        // Patterns that aren't known at compile time can turn out not to be
+        // regular expressions.
+        TypeDef::boolean().with_fallibility(matches!(self.patterns, Patterns::Dynamic(_)))
     }
 }
 
@@ -2,9 +2,11 @@ use ::value::Value;
 use md5::Digest;
 use vrl::prelude::*;
 
-fn md5(value: Value) -> Resolved {
+use crate::util;
+
+fn md5(value: Value, encoding: &Bytes) -> Resolved {
     let value = value.try_bytes()?;
-    Ok(hex::encode(md5::Md5::digest(&value)).into())
+    Ok(util::encode_digest(&md5::Md5::digest(&value), encoding).into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -16,19 +18,33 @@ impl Function for Md5 {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::ANY,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "encoding",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "md5",
-            source: r#"md5("foobar")"#,
-            result: Ok("3858f62230ac3c915f300c664312c63f"),
-        }]
+        &[
+            Example {
+                title: "md5",
+                source: r#"md5("foobar")"#,
+                result: Ok("3858f62230ac3c915f300c664312c63f"),
+            },
+            Example {
+                title: "base64 encoding",
+                source: r#"md5("foobar", encoding: "base64")"#,
+                result: Ok("OFj2IjCsPJFfMAxmQxLGPw=="),
+            },
+        ]
     }
 
     fn compile(
@@ -38,20 +54,26 @@ impl Function for Md5 {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let encoding = arguments
+            .optional_enum("encoding", &util::digest_encodings())?
+            .unwrap_or_else(|| value!("hex"))
+            .try_bytes()
+            .expect("encoding not bytes");
 
-        Ok(Md5Fn { value }.as_expr())
+        Ok(Md5Fn { value, encoding }.as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
 struct Md5Fn {
     value: Box<dyn Expression>,
+    encoding: Bytes,
 }
 
 impl FunctionExpression for Md5Fn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        md5(value)
+        md5(value, &self.encoding)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -71,5 +93,17 @@ mod tests {
             want: Ok(value!("acbd18db4cc2f85cedef654fccc4a4d8")),
             tdef: TypeDef::bytes().infallible(),
         }
+
+        hex_encoding {
+            args: func_args![value: "foo", encoding: "hex"],
+            want: Ok(value!("acbd18db4cc2f85cedef654fccc4a4d8")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        base64_encoding {
+            args: func_args![value: "foo", encoding: "base64"],
+            want: Ok(value!("rL0Y20zC+Fzt72VPzMSk2A==")),
+            tdef: TypeDef::bytes().infallible(),
+        }
     ];
 }
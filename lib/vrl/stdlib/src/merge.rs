@@ -72,12 +72,93 @@ impl FunctionExpression for MergeFn {
     }
 
     fn type_def(&self, state: &state::TypeState) -> TypeDef {
-        // TODO: this has a known bug when deep is true
-        // see: https://github.com/vectordotdev/vector/issues/13597
-        self.to
-            .type_def(state)
-            .restrict_object()
-            .merge_overwrite(self.from.type_def(state).restrict_object())
+        let to = self.to.type_def(state).restrict_object();
+        let from = self.from.type_def(state).restrict_object();
+
+        let to_object = to
+            .kind()
+            .as_object()
+            .cloned()
+            .unwrap_or_else(Collection::any);
+        let from_object = from
+            .kind()
+            .as_object()
+            .cloned()
+            .unwrap_or_else(Collection::any);
+        let deep = Kind::object(merge_collections(to_object, from_object));
+
+        let shallow = to.merge_overwrite(from);
+
+        match self.deep.as_value() {
+            Some(Value::Boolean(false)) => shallow,
+            Some(Value::Boolean(true)) => shallow.with_kind(deep),
+            // Not known at compile-time, so either merge can happen.
+            _ => {
+                let kind = shallow.kind().union(deep);
+                shallow.with_kind(kind)
+            }
+        }
+    }
+}
+
+/// Returns the type of deep merging an object of type `from` into an object of
+/// type `to`, mirroring `merge_maps`.
+fn merge_collections(mut to: Collection<Field>, from: Collection<Field>) -> Collection<Field> {
+    for (field, from_kind) in from.known() {
+        let to_kind = to
+            .known()
+            .get(field)
+            .cloned()
+            .unwrap_or_else(|| to.unknown_kind());
+
+        to.known_mut()
+            .insert(field.clone(), merge_kinds(to_kind, from_kind.clone()));
+    }
+
+    // Any other field can be merged with one of the unknown fields of `from`.
+    let from_unknown = from.unknown_kind();
+    for (field, kind) in to.known_mut() {
+        if !from.known().contains_key(field) {
+            *kind = merge_kinds(kind.clone(), from_unknown.clone());
+        }
+    }
+
+    // An infinite unknown type can't be recursed into.
+    let unknown = if to.is_unknown_exact() && from.is_unknown_exact() {
+        merge_kinds(to.unknown_kind(), from_unknown)
+    } else {
+        to.unknown_kind().union(from_unknown)
+    };
+    to.set_unknown(unknown);
+
+    to
+}
+
+/// Returns the type of a field after deep merging a field of type `from` into a
+/// field of type `to`.
+fn merge_kinds(to: Kind, from: Kind) -> Kind {
+    // If the field doesn't exist in `from`, the field in `to` is kept as-is.
+    let mut kind = if from.contains_undefined() {
+        to.clone()
+    } else {
+        Kind::never()
+    };
+    let from = from.without_undefined();
+
+    match (to.as_object(), from.as_object()) {
+        (Some(to_object), Some(from_object)) => {
+            let merged = merge_collections(to_object.clone(), from_object.clone());
+            kind = kind.union(Kind::object(merged));
+
+            // Only objects on both sides are merged, any other value in `to` is
+            // overwritten by the object in `from`.
+            let mut from = from.clone();
+            if to.is_object() {
+                from.remove_object();
+            }
+            kind.union(from)
+        }
+        _ => kind.union(from),
     }
 }
 
@@ -186,10 +267,25 @@ mod tests {
                 Field::from("key1") => Kind::bytes(),
                 Field::from("key2") => Kind::bytes(),
                 Field::from("child") => TypeDef::object(btreemap! {
+                    Field::from("grandchild1") => Kind::bytes(),
                     Field::from("grandchild2") => Kind::boolean(),
                 }),
             }),
+        }
 
+        deep_overwrites_non_object {
+            args: func_args![
+                to: value!({ child: "val1", other: { grandchild1: 1 } }),
+                from: value!({ child: { grandchild1: true }, other: 2 }),
+                deep: true,
+            ],
+            want: Ok(value!({ child: { grandchild1: true }, other: 2 })),
+            tdef: TypeDef::object(btreemap! {
+                Field::from("child") => TypeDef::object(btreemap! {
+                    Field::from("grandchild1") => Kind::boolean(),
+                }),
+                Field::from("other") => Kind::integer(),
+            }),
         }
     ];
 }
@@ -1,4 +1,3 @@
-use chrono::Utc;
 use vrl::prelude::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -31,11 +30,38 @@ impl Function for Now {
 struct NowFn;
 
 impl FunctionExpression for NowFn {
-    fn resolve(&self, _: &mut Context) -> Resolved {
-        Ok(Utc::now().into())
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        Ok(ctx.now().into())
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
         TypeDef::timestamp()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ::value::Value;
+    use chrono::{TimeZone as _, Utc};
+    use vector_common::TimeZone;
+    use vrl::Clock;
+
+    use super::*;
+
+    #[test]
+    fn frozen_clock() {
+        let time = Utc.ymd(2021, 1, 1).and_hms(0, 0, 0);
+
+        let mut state = vrl::state::Runtime::default();
+        state.set_clock(Clock::frozen(time));
+
+        let mut object = Value::Object(BTreeMap::new());
+        let tz = TimeZone::default();
+        let mut ctx = Context::new(&mut object, &mut state, &tz);
+
+        assert_eq!(NowFn.resolve(&mut ctx).unwrap(), Value::from(time));
+        assert_eq!(NowFn.resolve(&mut ctx).unwrap(), Value::from(time));
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+fn object_from_entries(value: Value) -> Resolved {
+    let entries = value.try_array()?;
+    let mut object = BTreeMap::new();
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        let mut entry = match entry {
+            Value::Array(entry) if entry.len() == 2 => entry.into_iter(),
+            _ => return Err(format!("entry at index {} is not a pair", index).into()),
+        };
+
+        let key = match entry.next() {
+            Some(Value::Bytes(key)) => String::from_utf8_lossy(&key).into_owned(),
+            _ => return Err(format!("key at index {} is not a string", index).into()),
+        };
+
+        object.extend(entry.next().map(|value| (key, value)));
+    }
+
+    Ok(object.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ObjectFromEntries;
+
+impl Function for ObjectFromEntries {
+    fn identifier(&self) -> &'static str {
+        "object_from_entries"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "object from entries",
+                source: r#"object_from_entries!([["a", 1], ["b", true]])"#,
+                result: Ok(r#"{"a": 1, "b": true}"#),
+            },
+            Example {
+                title: "key not a string",
+                source: r#"object_from_entries!([["a", 1], [2, true]])"#,
+                result: Err(
+                    r#"function call error for "object_from_entries" at (0:43): key at index 1 is not a string"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ObjectFromEntriesFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ObjectFromEntriesFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ObjectFromEntriesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        object_from_entries(value)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        // The kind of the values is the kind of the second element of every
+        // entry, the keys aren't known.
+        let value = self
+            .value
+            .type_def(state)
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind)
+            .as_array()
+            .map_or_else(Kind::any, |entry| {
+                entry
+                    .known()
+                    .get(&Index::from(1))
+                    .cloned()
+                    .unwrap_or_else(|| entry.reduced_kind())
+            });
+
+        TypeDef::object(Collection::from_unknown(value)).fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object_of(value: Kind) -> TypeDef {
+        TypeDef::object(Collection::from_unknown(value)).fallible()
+    }
+
+    test_function![
+        object_from_entries => ObjectFromEntries;
+
+        entries {
+            args: func_args![value: value!([["a", 1], ["b", 2]])],
+            want: Ok(value!({"a": 1, "b": 2})),
+            tdef: object_of(Kind::integer()),
+        }
+
+        duplicate_keys {
+            args: func_args![value: value!([["a", 1], ["a", 2]])],
+            want: Ok(value!({"a": 2})),
+            tdef: object_of(Kind::integer()),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!({})),
+            tdef: object_of(Kind::any()),
+        }
+
+        key_not_a_string {
+            args: func_args![value: value!([["a", 1], [2, true]])],
+            want: Err("key at index 1 is not a string"),
+            tdef: object_of(Kind::integer().or_boolean()),
+        }
+
+        not_a_pair {
+            args: func_args![value: value!([["a", 1], "b"])],
+            want: Err("entry at index 1 is not a pair"),
+            tdef: object_of(Kind::integer()),
+        }
+    ];
+}
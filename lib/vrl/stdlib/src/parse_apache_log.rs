@@ -55,7 +55,7 @@ impl Function for ParseApacheLog {
             Parameter {
                 keyword: "format",
                 kind: kind::BYTES,
-                required: true,
+                required: false,
             },
             Parameter {
                 keyword: "timestamp_format",
@@ -73,7 +73,8 @@ impl Function for ParseApacheLog {
     ) -> Compiled {
         let value = arguments.required("value");
         let format = arguments
-            .required_enum("format", &variants())?
+            .optional_enum("format", &variants())?
+            .unwrap_or_else(|| value!("common"))
             .try_bytes()
             .expect("format not bytes");
 
@@ -197,6 +198,24 @@ mod tests {
     test_function![
         parse_common_log => ParseApacheLog;
 
+        default_format {
+            args: func_args![value: r#"127.0.0.1 bob frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326"#],
+            want: Ok(btreemap! {
+                "host" => "127.0.0.1",
+                "identity" => "bob",
+                "user" => "frank",
+                "timestamp" => Value::Timestamp(DateTime::parse_from_rfc3339("2000-10-10T20:55:36Z").unwrap().into()),
+                "message" => "GET /apache_pb.gif HTTP/1.0",
+                "method" => "GET",
+                "path" => "/apache_pb.gif",
+                "protocol" => "HTTP/1.0",
+                "status" => 200,
+                "size" => 2326,
+            }),
+            tdef: TypeDef::object(kind_common()).fallible(),
+            tz: vector_common::TimeZone::default(),
+        }
+
         common_line_valid {
             args: func_args![value: r#"127.0.0.1 bob frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326"#,
                              format: "common"
@@ -182,6 +182,9 @@ fn parse_value(input: &str) -> IResult<&str, String, VerboseError<&str>> {
             alt((
                 value('=', char('=')),
                 value('\\', char('\\')),
+                // Pipes don't need to be escaped in extension values, but some
+                // producers escape them anyway.
+                value('|', char('|')),
                 value('\n', one_of("nr")),
             )),
         ),
@@ -407,6 +410,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_escape_extension_pipe() {
+        assert_eq!(
+            Ok(vec![
+                ("dst".to_string(), "2.1.2.2".into()),
+                ("msg".to_string(), "a | b | c".into()),
+                ("spt".to_string(),"1232".into()),
+                ("cefVersion".to_string(), "1".into()),
+                ("deviceVendor".to_string(), "Security".into()),
+                ("deviceProduct".to_string(), "threatmanager".into()),
+                ("deviceVersion".to_string(), "1.0".into()),
+                ("deviceEventClassId".to_string(), "100".into()),
+                ("name".to_string(), "worm successfully stopped".into()),
+                ("severity".to_string(), "10".into()),
+            ]),
+            parse(r#"CEF:1|Security|threatmanager|1.0|100|worm successfully stopped|10|dst=2.1.2.2 msg=a \| b | c spt=1232"#)
+                .map(Iterator::collect)
+        );
+    }
+
     #[test]
     fn test_extension_newline() {
         assert_eq!(
@@ -17,15 +17,27 @@ fn parse_duration(bytes: Value, unit: Value) -> Resolved {
             .get(string.as_ref())
             .ok_or(format!("unknown unit format: '{}'", string))?
     };
-    let captures = RE
-        .captures(&value)
-        .ok_or(format!("unable to parse duration: '{}'", value))?;
-    let value = Decimal::from_str(&captures["value"])
-        .map_err(|error| format!("unable to parse number: {}", error))?;
-    let unit = UNITS
-        .get(&captures["unit"])
-        .ok_or(format!("unknown duration unit: '{}'", &captures["unit"]))?;
-    let number = value * unit / conversion_factor;
+
+    if !RE.is_match(&value) {
+        return Err(format!("unable to parse duration: '{}'", value).into());
+    }
+
+    // A compound duration, such as "1h30m", is the sum of its components.
+    let mut duration = Decimal::ZERO;
+    for captures in COMPONENT_RE.captures_iter(&value) {
+        let number = Decimal::from_str(&captures["value"])
+            .map_err(|error| format!("unable to parse number: {}", error))?;
+        let unit = UNITS
+            .get(&captures["unit"])
+            .ok_or(format!("unknown duration unit: '{}'", &captures["unit"]))?;
+
+        duration = number
+            .checked_mul(*unit)
+            .and_then(|component| duration.checked_add(component))
+            .ok_or(format!("duration out of range: '{}'", value))?;
+    }
+
+    let number = duration / conversion_factor;
     let number = number
         .to_f64()
         .ok_or(format!("unable to format duration: '{}'", number))?;
@@ -36,10 +48,20 @@ static RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(
         r"(?ix)                        # i: case-insensitive, x: ignore whitespace + comments
             \A
+            [0-9]*\.?[0-9]+\s?[µa-z]{1,2} # first component
+            (?:\s?[0-9]*\.?[0-9]+\s?[µa-z]{1,2})* # any further components, optionally space separated
+            \z",
+    )
+    .unwrap()
+});
+
+static COMPONENT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?ix)                        # i: case-insensitive, x: ignore whitespace + comments
             (?P<value>[0-9]*\.?[0-9]+) # value: integer or float
             \s?                        # optional space between value and unit
             (?P<unit>[µa-z]{1,2})      # unit: one or two letters
-            \z",
+        ",
     )
     .unwrap()
 });
@@ -71,11 +93,18 @@ impl Function for ParseDuration {
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "milliseconds",
-            source: r#"parse_duration!("1005ms", unit: "s")"#,
-            result: Ok("1.005"),
-        }]
+        &[
+            Example {
+                title: "milliseconds",
+                source: r#"parse_duration!("1005ms", unit: "s")"#,
+                result: Ok("1.005"),
+            },
+            Example {
+                title: "compound duration",
+                source: r#"parse_duration!("1h30m", unit: "s")"#,
+                result: Ok("5400.0"),
+            },
+        ]
     }
 
     fn compile(
@@ -181,6 +210,41 @@ mod tests {
             tdef: TypeDef::float().fallible(),
         }
 
+        compound_h_m {
+            args: func_args![value: "1h30m",
+                             unit: "s"],
+            want: Ok(5400.0),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        compound_spaced {
+            args: func_args![value: "1m 30s 500ms",
+                             unit: "s"],
+            want: Ok(90.5),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        fractional_h_m {
+            args: func_args![value: "1.5h",
+                             unit: "m"],
+            want: Ok(90.0),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        error_compound_missing_unit {
+            args: func_args![value: "1h30",
+                             unit: "s"],
+            want: Err("unable to parse duration: '1h30'"),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        error_compound_unit {
+            args: func_args![value: "1h30x",
+                             unit: "s"],
+            want: Err("unknown duration unit: 'x'"),
+            tdef: TypeDef::float().fallible(),
+        }
+
         error_invalid {
             args: func_args![value: "foo",
                              unit: "ms"],
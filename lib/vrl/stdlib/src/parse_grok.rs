@@ -3,11 +3,15 @@ use std::{collections::BTreeMap, fmt, sync::Arc};
 use ::value::Value;
 use vrl::state::TypeState;
 use vrl::{
-    diagnostic::{Label, Span},
+    diagnostic::{Label, Note, Severity, Span},
     prelude::*,
 };
 
-fn parse_grok(value: Value, pattern: Arc<grok::Pattern>) -> Resolved {
+fn compile_pattern(pattern: &str) -> Result<grok::Pattern, grok::Error> {
+    grok::Grok::with_default_patterns().compile(pattern, true)
+}
+
+fn parse_grok(value: Value, pattern: &grok::Pattern) -> Resolved {
     let bytes = value.try_bytes_utf8_lossy()?;
     match pattern.match_against(&bytes) {
         Some(matches) => {
@@ -26,12 +30,14 @@ fn parse_grok(value: Value, pattern: Arc<grok::Pattern>) -> Resolved {
 #[derive(Debug)]
 pub(crate) enum Error {
     InvalidGrokPattern(grok::Error),
+    DynamicPattern(Span),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::InvalidGrokPattern(err) => err.fmt(f),
+            Error::DynamicPattern(_) => f.write_str("dynamic grok pattern"),
         }
     }
 }
@@ -40,7 +46,10 @@ impl std::error::Error for Error {}
 
 impl DiagnosticMessage for Error {
     fn code(&self) -> usize {
-        109
+        match self {
+            Error::InvalidGrokPattern(_) => 109,
+            Error::DynamicPattern(_) => 705,
+        }
     }
 
     fn labels(&self) -> Vec<Label> {
@@ -51,8 +60,40 @@ impl DiagnosticMessage for Error {
                     Span::default(),
                 )]
             }
+            Error::DynamicPattern(span) => vec![Label::primary(
+                "this pattern is compiled each time the function is called",
+                *span,
+            )],
+        }
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        match self {
+            Error::InvalidGrokPattern(_) => vec![],
+            Error::DynamicPattern(_) => vec![
+                Note::Hint("use a literal pattern to compile it once, with the program".to_owned()),
+                Note::SeeFunctionDocs("parse_grok"),
+            ],
         }
     }
+
+    fn severity(&self) -> Severity {
+        match self {
+            Error::InvalidGrokPattern(_) => Severity::Error,
+            Error::DynamicPattern(_) => Severity::Warning,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Pattern {
+    /// A literal pattern, compiled once when the program is compiled.
+    ///
+    /// Wrapping pattern in an Arc, as cloning the pattern could otherwise be expensive.
+    Compiled(Arc<grok::Pattern>),
+
+    /// A pattern only known at runtime, compiled each time the function is called.
+    Dynamic(Box<dyn Expression>),
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -100,23 +141,28 @@ impl Function for ParseGrok {
     fn compile(
         &self,
         _state: &state::TypeState,
-        _ctx: &mut FunctionCompileContext,
+        ctx: &mut FunctionCompileContext,
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
 
-        let pattern = arguments
-            .required_literal("pattern")?
-            .to_value()
-            .try_bytes_utf8_lossy()
-            .expect("grok pattern not bytes")
-            .into_owned();
+        let pattern = match arguments.required_literal("pattern") {
+            Ok(literal) => {
+                let pattern = literal
+                    .to_value()
+                    .try_bytes_utf8_lossy()
+                    .expect("grok pattern not bytes")
+                    .into_owned();
 
-        let mut grok = grok::Grok::with_default_patterns();
-        let pattern =
-            Arc::new(grok.compile(&pattern, true).map_err(|e| {
-                Box::new(Error::InvalidGrokPattern(e)) as Box<dyn DiagnosticMessage>
-            })?);
+                Pattern::Compiled(Arc::new(compile_pattern(&pattern).map_err(|e| {
+                    Box::new(Error::InvalidGrokPattern(e)) as Box<dyn DiagnosticMessage>
+                })?))
+            }
+            Err(_) => {
+                ctx.push_warning(Error::DynamicPattern(ctx.span()));
+                Pattern::Dynamic(arguments.required("pattern"))
+            }
+        };
 
         Ok(ParseGrokFn { value, pattern }.as_expr())
     }
@@ -125,17 +171,23 @@ impl Function for ParseGrok {
 #[derive(Clone, Debug)]
 struct ParseGrokFn {
     value: Box<dyn Expression>,
-
-    // Wrapping pattern in an Arc, as cloning the pattern could otherwise be expensive.
-    pattern: Arc<grok::Pattern>,
+    pattern: Pattern,
 }
 
 impl FunctionExpression for ParseGrokFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        let pattern = self.pattern.clone();
 
-        parse_grok(value, pattern)
+        match &self.pattern {
+            Pattern::Compiled(pattern) => parse_grok(value, pattern),
+            Pattern::Dynamic(expr) => {
+                let pattern = expr.resolve(ctx)?.try_bytes_utf8_lossy()?.into_owned();
+                let pattern = compile_pattern(&pattern)
+                    .map_err(|e| format!("unable to compile grok pattern: {}", e))?;
+
+                parse_grok(value, &pattern)
+            }
+        }
     }
 
     fn type_def(&self, _: &TypeState) -> TypeDef {
@@ -145,7 +197,7 @@ impl FunctionExpression for ParseGrokFn {
 
 #[cfg(test)]
 mod test {
-    use vector_common::btreemap;
+    use vector_common::{btreemap, TimeZone};
 
     use super::*;
 
@@ -193,4 +245,33 @@ mod test {
             tdef: TypeDef::object(Collection::any()).fallible(),
         }
     ];
+
+    #[test]
+    fn dynamic_pattern() {
+        let mut state = vrl::state::Runtime::default();
+        let mut object: Value = Value::Object(BTreeMap::new());
+        let tz = TimeZone::default();
+        let mut ctx = Context::new(&mut object, &mut state, &tz);
+
+        let expr = ParseGrokFn {
+            value: expr!("2020-10-02T23:22:12.223222Z info Hello world"),
+            pattern: Pattern::Dynamic(expr!("%{TIMESTAMP_ISO8601:timestamp} %{LOGLEVEL:level}")),
+        };
+        assert_eq!(
+            expr.resolve(&mut ctx),
+            Ok(Value::from(btreemap! {
+                "timestamp" => "2020-10-02T23:22:12.223222Z",
+                "level" => "info",
+            }))
+        );
+
+        let expr = ParseGrokFn {
+            value: expr!("foo"),
+            pattern: Pattern::Dynamic(expr!("%{NOG}")),
+        };
+        assert_eq!(
+            expr.resolve(&mut ctx),
+            Err(r#"unable to compile grok pattern: The given pattern definition name "NOG" could not be found in the definition map"#.into())
+        );
+    }
 }
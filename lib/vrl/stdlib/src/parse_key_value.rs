@@ -23,17 +23,20 @@ pub(crate) fn parse_key_value(
     field_delimiter: Value,
     standalone_key: Value,
     whitespace: Whitespace,
+    escape_delimiters: Value,
 ) -> Resolved {
     let bytes = bytes.try_bytes_utf8_lossy()?;
     let key_value_delimiter = key_value_delimiter.try_bytes_utf8_lossy()?;
     let field_delimiter = field_delimiter.try_bytes_utf8_lossy()?;
     let standalone_key = standalone_key.try_boolean()?;
+    let escape_delimiters = escape_delimiters.try_boolean()?;
     let values = parse(
         &bytes,
         &key_value_delimiter,
         &field_delimiter,
         whitespace,
         standalone_key,
+        escape_delimiters,
     )?;
 
     // Construct Value::Object by grouping values with the same key into an array.
@@ -100,6 +103,11 @@ impl Function for ParseKeyValue {
                 kind: kind::BOOLEAN,
                 required: false,
             },
+            Parameter {
+                keyword: "escape_delimiters",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
         ]
     }
 
@@ -132,6 +140,11 @@ impl Function for ParseKeyValue {
                 source: r#"parse_key_value!(s'foo=bar foo=nor', whitespace: "strict")"#,
                 result: Ok(r#"{"foo": ["bar", "nor"]}"#),
             },
+            Example {
+                title: "escaped delimiters",
+                source: r#"parse_key_value!(s'path=/var/log/my\ app.log level=info', escape_delimiters: true)"#,
+                result: Ok(r#"{"path": "/var/log/my\ app.log", "level": "info"}"#),
+            },
         ]
     }
 
@@ -163,12 +176,17 @@ impl Function for ParseKeyValue {
             .optional("accept_standalone_key")
             .unwrap_or_else(|| expr!(true));
 
+        let escape_delimiters = arguments
+            .optional("escape_delimiters")
+            .unwrap_or_else(|| expr!(false));
+
         Ok(ParseKeyValueFn {
             value,
             key_value_delimiter,
             field_delimiter,
             whitespace,
             standalone_key,
+            escape_delimiters,
         }
         .as_expr())
     }
@@ -227,6 +245,7 @@ pub(crate) struct ParseKeyValueFn {
     pub(crate) field_delimiter: Box<dyn Expression>,
     pub(crate) whitespace: Whitespace,
     pub(crate) standalone_key: Box<dyn Expression>,
+    pub(crate) escape_delimiters: Box<dyn Expression>,
 }
 
 impl FunctionExpression for ParseKeyValueFn {
@@ -236,6 +255,7 @@ impl FunctionExpression for ParseKeyValueFn {
         let field_delimiter = self.field_delimiter.resolve(ctx)?;
         let standalone_key = self.standalone_key.resolve(ctx)?;
         let whitespace = self.whitespace;
+        let escape_delimiters = self.escape_delimiters.resolve(ctx)?;
 
         parse_key_value(
             bytes,
@@ -243,6 +263,7 @@ impl FunctionExpression for ParseKeyValueFn {
             field_delimiter,
             standalone_key,
             whitespace,
+            escape_delimiters,
         )
     }
 
@@ -257,6 +278,7 @@ fn parse<'a>(
     field_delimiter: &'a str,
     whitespace: Whitespace,
     standalone_key: bool,
+    escape_delimiters: bool,
 ) -> Result<Vec<(String, Value)>> {
     let (rest, result) = parse_line(
         input,
@@ -264,6 +286,7 @@ fn parse<'a>(
         field_delimiter,
         whitespace,
         standalone_key,
+        escape_delimiters,
     )
     .map_err(|e| match e {
         nom::Err::Error(e) | nom::Err::Failure(e) => {
@@ -287,6 +310,7 @@ fn parse_line<'a>(
     field_delimiter: &'a str,
     whitespace: Whitespace,
     standalone_key: bool,
+    escape_delimiters: bool,
 ) -> IResult<&'a str, Vec<(String, Value)>, VerboseError<&'a str>> {
     separated_list1(
         parse_field_delimiter(field_delimiter),
@@ -295,6 +319,7 @@ fn parse_line<'a>(
             field_delimiter,
             whitespace,
             standalone_key,
+            escape_delimiters,
         ),
     )(input)
 }
@@ -322,6 +347,7 @@ fn parse_key_value_<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     field_delimiter: &'a str,
     whitespace: Whitespace,
     standalone_key: bool,
+    escape_delimiters: bool,
 ) -> impl Fn(&'a str) -> IResult<&'a str, (String, Value), E> {
     move |input| {
         map(
@@ -329,22 +355,32 @@ fn parse_key_value_<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
                 Whitespace::Strict => tuple((
                     preceded(
                         space0,
-                        parse_key(key_value_delimiter, field_delimiter, standalone_key),
+                        parse_key(
+                            key_value_delimiter,
+                            field_delimiter,
+                            standalone_key,
+                            escape_delimiters,
+                        ),
                     ),
                     many_m_n(usize::from(!standalone_key), 1, tag(key_value_delimiter)),
-                    parse_value(field_delimiter),
+                    parse_value(field_delimiter, escape_delimiters),
                 ))(input),
                 Whitespace::Lenient => tuple((
                     preceded(
                         space0,
-                        parse_key(key_value_delimiter, field_delimiter, standalone_key),
+                        parse_key(
+                            key_value_delimiter,
+                            field_delimiter,
+                            standalone_key,
+                            escape_delimiters,
+                        ),
                     ),
                     many_m_n(
                         usize::from(!standalone_key),
                         1,
                         delimited(space0, tag(key_value_delimiter), space0),
                     ),
-                    parse_value(field_delimiter),
+                    parse_value(field_delimiter, escape_delimiters),
                 ))(input),
             },
             |(field, sep, value): (&str, Vec<&str>, Value)| {
@@ -401,10 +437,29 @@ fn parse_delimited<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
 
 /// An undelimited value is all the text until our `field_delimiter`, or if it is the last value in the line,
 /// just take the rest of the string.
+/// If `escape_delimiters` is `true`, a `field_delimiter` preceded by a `\` is part of the value. The escape is
+/// kept, as with delimited values.
 fn parse_undelimited<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     field_delimiter: &'a str,
+    escape_delimiters: bool,
 ) -> impl Fn(&'a str) -> IResult<&'a str, &'a str, E> {
-    move |input| map(alt((take_until(field_delimiter), rest)), str::trim)(input)
+    move |input| {
+        if !escape_delimiters {
+            return map(alt((take_until(field_delimiter), rest)), str::trim)(input);
+        }
+
+        let mut escaped = false;
+        let end = input
+            .char_indices()
+            .find(|&(index, c)| {
+                let found = !escaped && input[index..].starts_with(field_delimiter);
+                escaped = !escaped && c == '\\';
+                found
+            })
+            .map_or(input.len(), |(index, _)| index);
+
+        Ok((&input[end..], input[..end].trim()))
+    }
 }
 
 /// Parses the value.
@@ -416,12 +471,13 @@ fn parse_undelimited<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
 ///
 fn parse_value<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     field_delimiter: &'a str,
+    escape_delimiters: bool,
 ) -> impl Fn(&'a str) -> IResult<&'a str, Value, E> {
     move |input| {
         map(
             alt((
                 parse_delimited('"', field_delimiter),
-                parse_undelimited(field_delimiter),
+                parse_undelimited(field_delimiter, escape_delimiters),
             )),
             Into::into,
         )(input)
@@ -435,23 +491,25 @@ fn parse_key<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     key_value_delimiter: &'a str,
     field_delimiter: &'a str,
     standalone_key: bool,
+    escape_delimiters: bool,
 ) -> Box<dyn Fn(&'a str) -> IResult<&'a str, &'a str, E> + 'a> {
     if standalone_key {
         Box::new(move |input| {
             alt((
                 parse_delimited('"', key_value_delimiter),
                 parse_delimited('"', field_delimiter),
-                verify(parse_undelimited(key_value_delimiter), |s: &str| {
-                    !s.contains(field_delimiter)
-                }),
-                parse_undelimited(field_delimiter),
+                verify(
+                    parse_undelimited(key_value_delimiter, escape_delimiters),
+                    |s: &str| !s.contains(field_delimiter),
+                ),
+                parse_undelimited(field_delimiter, escape_delimiters),
             ))(input)
         })
     } else {
         Box::new(move |input| {
             alt((
                 parse_delimited('"', key_value_delimiter),
-                parse_undelimited(key_value_delimiter),
+                parse_undelimited(key_value_delimiter, escape_delimiters),
             ))(input)
         })
     }
@@ -472,12 +530,12 @@ mod test {
     fn test_quote_and_escape_char() {
         assert_eq!(
             Ok(vec![("key".to_string(), r#"a\a"#.into()),]),
-            parse(r#"key="a\a""#, "=", " ", Whitespace::Strict, true,)
+            parse(r#"key="a\a""#, "=", " ", Whitespace::Strict, true, false)
         );
 
         assert_eq!(
             Ok(vec![(r#"a\ a"#.to_string(), r#"val"#.into()),]),
-            parse(r#""a\ a"=val"#, "=", " ", Whitespace::Strict, true,)
+            parse(r#""a\ a"=val"#, "=", " ", Whitespace::Strict, true, false)
         );
     }
 
@@ -500,6 +558,7 @@ mod test {
                 " ",
                 Whitespace::Lenient,
                 false,
+                false,
             )
         );
     }
@@ -508,14 +567,16 @@ mod test {
     fn test_parse_key_value() {
         assert_eq!(
             Ok(("", ("ook".to_string(), "pook".into()))),
-            parse_key_value_::<VerboseError<&str>>("=", " ", Whitespace::Lenient, false)(
+            parse_key_value_::<VerboseError<&str>>("=", " ", Whitespace::Lenient, false, false)(
                 "ook=pook"
             )
         );
 
         assert_eq!(
             Ok(("", ("key".to_string(), "".into()))),
-            parse_key_value_::<VerboseError<&str>>("=", " ", Whitespace::Strict, false)("key=")
+            parse_key_value_::<VerboseError<&str>>("=", " ", Whitespace::Strict, false, false)(
+                "key="
+            )
         );
     }
 
@@ -526,7 +587,14 @@ mod test {
                 ("ook".to_string(), "pook".into()),
                 ("onk".to_string(), "ponk".into())
             ]),
-            parse("ook=pook onk=ponk", "=", " ", Whitespace::Lenient, false)
+            parse(
+                "ook=pook onk=ponk",
+                "=",
+                " ",
+                Whitespace::Lenient,
+                false,
+                false
+            )
         );
     }
 
@@ -537,7 +605,7 @@ mod test {
                 ("ook".to_string(), "".into()),
                 ("onk".to_string(), "ponk".into())
             ]),
-            parse("ook= onk=ponk", "=", " ", Whitespace::Strict, false)
+            parse("ook= onk=ponk", "=", " ", Whitespace::Strict, false, false)
         );
     }
 
@@ -548,7 +616,14 @@ mod test {
                 ("foo".to_string(), "bar".into()),
                 ("foobar".to_string(), value!(true))
             ]),
-            parse("foo:bar ,   foobar   ", ":", ",", Whitespace::Lenient, true)
+            parse(
+                "foo:bar ,   foobar   ",
+                ":",
+                ",",
+                Whitespace::Lenient,
+                true,
+                false
+            )
         );
     }
 
@@ -566,7 +641,8 @@ mod test {
                 "=",
                 " ",
                 Whitespace::Lenient,
-                true
+                true,
+                false,
             )
         );
     }
@@ -586,7 +662,8 @@ mod test {
                 "=",
                 " ",
                 Whitespace::Lenient,
-                true
+                true,
+                false,
             )
         );
     }
@@ -595,7 +672,7 @@ mod test {
     fn test_parse_single_standalone_key() {
         assert_eq!(
             Ok(vec![("foobar".to_string(), value!(true))]),
-            parse("foobar", ":", ",", Whitespace::Lenient, true)
+            parse("foobar", ":", ",", Whitespace::Lenient, true, false)
         );
     }
 
@@ -606,7 +683,14 @@ mod test {
                 ("foo".to_string(), "bar".into()),
                 ("foobar".to_string(), value!(true))
             ]),
-            parse("foo:bar ,   foobar   ", ":", ",", Whitespace::Strict, true)
+            parse(
+                "foo:bar ,   foobar   ",
+                ":",
+                ",",
+                Whitespace::Strict,
+                true,
+                false
+            )
         );
     }
 
@@ -615,37 +699,37 @@ mod test {
         // delimited
         assert_eq!(
             Ok(("", "noog")),
-            parse_key::<VerboseError<&str>>("=", " ", false)(r#""noog""#)
+            parse_key::<VerboseError<&str>>("=", " ", false, false)(r#""noog""#)
         );
 
         // undelimited
         assert_eq!(
             Ok(("", "noog")),
-            parse_key::<VerboseError<&str>>("=", " ", false)("noog")
+            parse_key::<VerboseError<&str>>("=", " ", false, false)("noog")
         );
 
         // delimited with escaped char (1)
         assert_eq!(
             Ok(("=baz", r#"foo \" bar"#)),
-            parse_key::<VerboseError<&str>>("=", " ", false)(r#""foo \" bar"=baz"#)
+            parse_key::<VerboseError<&str>>("=", " ", false, false)(r#""foo \" bar"=baz"#)
         );
 
         // delimited with escaped char (2)
         assert_eq!(
             Ok(("=baz", r#"foo \\ \" \ bar"#)),
-            parse_key::<VerboseError<&str>>("=", " ", false)(r#""foo \\ \" \ bar"=baz"#)
+            parse_key::<VerboseError<&str>>("=", " ", false, false)(r#""foo \\ \" \ bar"=baz"#)
         );
 
         // delimited with escaped char (3)
         assert_eq!(
             Ok(("=baz", r#"foo \ bar"#)),
-            parse_key::<VerboseError<&str>>("=", " ", false)(r#""foo \ bar"=baz"#)
+            parse_key::<VerboseError<&str>>("=", " ", false, false)(r#""foo \ bar"=baz"#)
         );
 
         // Standalone key
         assert_eq!(
             Ok((" bar=baz", "foo")),
-            parse_key::<VerboseError<&str>>("=", " ", true)(r#"foo bar=baz"#)
+            parse_key::<VerboseError<&str>>("=", " ", true, false)(r#"foo bar=baz"#)
         );
     }
 
@@ -654,25 +738,25 @@ mod test {
         // delimited
         assert_eq!(
             Ok(("", "noog".into())),
-            parse_value::<VerboseError<&str>>(" ")(r#""noog""#)
+            parse_value::<VerboseError<&str>>(" ", false)(r#""noog""#)
         );
 
         // undelimited
         assert_eq!(
             Ok(("", "noog".into())),
-            parse_value::<VerboseError<&str>>(" ")("noog")
+            parse_value::<VerboseError<&str>>(" ", false)("noog")
         );
 
         // empty delimited
         assert_eq!(
             Ok(("", "".into())),
-            parse_value::<VerboseError<&str>>(" ")(r#""""#)
+            parse_value::<VerboseError<&str>>(" ", false)(r#""""#)
         );
 
         // empty undelimited
         assert_eq!(
             Ok(("", "".into())),
-            parse_value::<VerboseError<&str>>(" ")("")
+            parse_value::<VerboseError<&str>>(" ", false)("")
         );
     }
 
@@ -689,11 +773,56 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_undelimited_with_escaped_delimiter() {
+        assert_eq!(
+            Ok((" nonk", r#"noog\ pook"#)),
+            parse_undelimited::<VerboseError<&str>>(" ", true)(r#"noog\ pook nonk"#)
+        );
+
+        assert_eq!(
+            Ok((r#" nonk"#, r#"noog\\"#)),
+            parse_undelimited::<VerboseError<&str>>(" ", true)(r#"noog\\ nonk"#)
+        );
+    }
+
+    #[test]
+    fn test_parse_escaped_delimiters() {
+        assert_eq!(
+            Ok(vec![
+                (
+                    r#"key\=with\=equals"#.to_string(),
+                    r#"value\ with\ spaces"#.into()
+                ),
+                ("other".to_string(), "value".into()),
+            ]),
+            parse(
+                r#"key\=with\=equals=value\ with\ spaces other=value"#,
+                "=",
+                " ",
+                Whitespace::Lenient,
+                false,
+                true,
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_backslash_before_delimiter_without_escaping() {
+        assert_eq!(
+            Ok(vec![
+                ("a".to_string(), r#"C:\"#.into()),
+                ("b".to_string(), "1".into()),
+            ]),
+            parse(r#"a=C:\ b=1"#, "=", " ", Whitespace::Lenient, false, false)
+        );
+    }
+
     #[test]
     fn test_parse_undelimited_with_quotes() {
         assert_eq!(
             Ok(("", r#""noog" nonk"#)),
-            parse_undelimited::<VerboseError<&str>>(":")(r#""noog" nonk"#)
+            parse_undelimited::<VerboseError<&str>>(":", false)(r#""noog" nonk"#)
         );
     }
 
@@ -884,5 +1013,24 @@ mod test {
             want: Ok(value!({"Cc": "bob"})),
             tdef: type_def(),
         }
+
+        backslash_before_delimiter {
+            args: func_args! [
+                value: r#"a=C:\ b=1"#,
+            ],
+            want: Ok(value!({a: r#"C:\"#,
+                             b: "1"})),
+            tdef: type_def(),
+        }
+
+        escaped_delimiters {
+            args: func_args! [
+                value: r#"path=/var/log/my\ app.log level=info"#,
+                escape_delimiters: true,
+            ],
+            want: Ok(value!({path: r#"/var/log/my\ app.log"#,
+                             level: "info"})),
+            tdef: type_def(),
+        }
     ];
 }
@@ -47,6 +47,7 @@ impl Function for ParseLogFmt {
         let field_delimiter = expr!(" ");
         let whitespace = Whitespace::Lenient;
         let standalone_key = expr!(true);
+        let escape_delimiters = expr!(false);
 
         Ok(ParseKeyValueFn {
             value,
@@ -54,6 +55,7 @@ impl Function for ParseLogFmt {
             field_delimiter,
             whitespace,
             standalone_key,
+            escape_delimiters,
         }
         .as_expr())
     }
@@ -46,7 +46,7 @@ impl Function for ParseNginxLog {
             Parameter {
                 keyword: "format",
                 kind: kind::BYTES,
-                required: true,
+                required: false,
             },
             Parameter {
                 keyword: "timestamp_format",
@@ -64,7 +64,8 @@ impl Function for ParseNginxLog {
     ) -> Compiled {
         let value = arguments.required("value");
         let format = arguments
-            .required_enum("format", &variants())?
+            .optional_enum("format", &variants())?
+            .unwrap_or_else(|| value!("combined"))
             .try_bytes()
             .expect("format not bytes");
 
@@ -199,6 +200,24 @@ mod tests {
     test_function![
         parse_combined_log => ParseNginxLog;
 
+        default_format {
+            args: func_args![
+                value: r#"172.17.0.1 - - [31/Mar/2021:12:04:07 +0000] "GET / HTTP/1.1" 200 612 "-" "curl/7.75.0" "-""#,
+            ],
+            want: Ok(btreemap! {
+                "client" => "172.17.0.1",
+                "timestamp" => Value::Timestamp(DateTime::parse_from_rfc3339("2021-03-31T12:04:07Z").unwrap().into()),
+                "request" => "GET / HTTP/1.1",
+                "method" => "GET",
+                "path" => "/",
+                "protocol" => "HTTP/1.1",
+                "status" => 200,
+                "size" => 612,
+                "agent" => "curl/7.75.0",
+            }),
+            tdef: TypeDef::object(kind_combined()).fallible(),
+        }
+
         combined_line_valid {
             args: func_args![
                 value: r#"172.17.0.1 - - [31/Mar/2021:12:04:07 +0000] "GET / HTTP/1.1" 200 612 "-" "curl/7.75.0" "-""#,
@@ -4,12 +4,17 @@ use vrl::prelude::*;
 
 use crate::util;
 
-fn parse_regex(value: Value, numeric_groups: bool, pattern: &Regex) -> Resolved {
+fn parse_regex(
+    value: Value,
+    numeric_groups: bool,
+    pattern: &Regex,
+    capture_names: &[(usize, String)],
+) -> Resolved {
     let bytes = value.try_bytes()?;
     let value = String::from_utf8_lossy(&bytes);
     let parsed = pattern
         .captures(&value)
-        .map(|capture| util::capture_regex_to_map(pattern, &capture, numeric_groups))
+        .map(|capture| util::capture_regex_to_map(capture_names, &capture, numeric_groups))
         .ok_or("could not find any pattern matches")?;
     Ok(parsed.into())
 }
@@ -50,6 +55,7 @@ impl Function for ParseRegex {
     ) -> Compiled {
         let value = arguments.required("value");
         let pattern = arguments.required_regex("pattern")?;
+        let capture_names = util::capture_names(&pattern);
         let numeric_groups = arguments
             .optional("numeric_groups")
             .unwrap_or_else(|| expr!(false));
@@ -57,6 +63,7 @@ impl Function for ParseRegex {
         Ok(ParseRegexFn {
             value,
             pattern,
+            capture_names,
             numeric_groups,
         }
         .as_expr())
@@ -91,6 +98,7 @@ impl Function for ParseRegex {
 pub(crate) struct ParseRegexFn {
     value: Box<dyn Expression>,
     pattern: Regex,
+    capture_names: Vec<(usize, String)>,
     numeric_groups: Box<dyn Expression>,
 }
 
@@ -100,7 +108,12 @@ impl FunctionExpression for ParseRegexFn {
         let numeric_groups = self.numeric_groups.resolve(ctx)?;
         let pattern = &self.pattern;
 
-        parse_regex(value, numeric_groups.try_boolean()?, pattern)
+        parse_regex(
+            value,
+            numeric_groups.try_boolean()?,
+            pattern,
+            &self.capture_names,
+        )
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -177,6 +190,21 @@ mod tests {
                 }).fallible(),
         }
 
+        unmatched_group {
+            args: func_args! [
+                value: "first group",
+                pattern: Regex::new(r#"(?P<unused>none)?(?P<number>.*?) group"#).unwrap()
+            ],
+            want: Ok(value!({"number": "first", "unused": null})),
+            tdef: TypeDef::object(btreemap! {
+                        Field::from("unused") => Kind::bytes(),
+                        Field::from("number") => Kind::bytes(),
+                        Field::from("0") => Kind::bytes() | Kind::null(),
+                        Field::from("1") => Kind::bytes() | Kind::null(),
+                        Field::from("2") => Kind::bytes() | Kind::null(),
+                }).fallible(),
+        }
+
         no_match {
             args: func_args! [
                 value: "I don't match",
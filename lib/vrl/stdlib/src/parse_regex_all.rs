@@ -4,12 +4,17 @@ use vrl::prelude::*;
 
 use crate::util;
 
-fn parse_regex_all(value: Value, numeric_groups: bool, pattern: &Regex) -> Resolved {
+fn parse_regex_all(
+    value: Value,
+    numeric_groups: bool,
+    pattern: &Regex,
+    capture_names: &[(usize, String)],
+) -> Resolved {
     let bytes = value.try_bytes()?;
     let value = String::from_utf8_lossy(&bytes);
     Ok(pattern
         .captures_iter(&value)
-        .map(|capture| util::capture_regex_to_map(pattern, &capture, numeric_groups).into())
+        .map(|capture| util::capture_regex_to_map(capture_names, &capture, numeric_groups).into())
         .collect::<Vec<Value>>()
         .into())
 }
@@ -50,6 +55,7 @@ impl Function for ParseRegexAll {
     ) -> Compiled {
         let value = arguments.required("value");
         let pattern = arguments.required_regex("pattern")?;
+        let capture_names = util::capture_names(&pattern);
         let numeric_groups = arguments
             .optional("numeric_groups")
             .unwrap_or_else(|| expr!(false));
@@ -57,6 +63,7 @@ impl Function for ParseRegexAll {
         Ok(ParseRegexAllFn {
             value,
             pattern,
+            capture_names,
             numeric_groups,
         }
         .as_expr())
@@ -96,6 +103,7 @@ impl Function for ParseRegexAll {
 pub(crate) struct ParseRegexAllFn {
     value: Box<dyn Expression>,
     pattern: Regex,
+    capture_names: Vec<(usize, String)>,
     numeric_groups: Box<dyn Expression>,
 }
 
@@ -105,7 +113,12 @@ impl FunctionExpression for ParseRegexAllFn {
         let numeric_groups = self.numeric_groups.resolve(ctx)?;
         let pattern = &self.pattern;
 
-        parse_regex_all(value, numeric_groups.try_boolean()?, pattern)
+        parse_regex_all(
+            value,
+            numeric_groups.try_boolean()?,
+            pattern,
+            &self.capture_names,
+        )
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -17,6 +17,7 @@ struct ParseOptions {
     attr_prefix: Option<Value>,
     text_key: Option<Value>,
     always_use_text_key: Option<Value>,
+    always_use_array: Option<Value>,
     parse_bool: Option<Value>,
     parse_null: Option<Value>,
     parse_number: Option<Value>,
@@ -44,6 +45,10 @@ fn parse_xml(value: Value, options: ParseOptions) -> Resolved {
         Some(value) => value.try_boolean()?,
         None => false,
     };
+    let always_use_array = match options.always_use_array {
+        Some(value) => value.try_boolean()?,
+        None => false,
+    };
     let parse_bool = match options.parse_bool {
         Some(value) => value.try_boolean()?,
         None => true,
@@ -61,6 +66,7 @@ fn parse_xml(value: Value, options: ParseOptions) -> Resolved {
         attr_prefix,
         text_key,
         always_use_text_key,
+        always_use_array,
         parse_bool,
         parse_null,
         parse_number,
@@ -81,6 +87,8 @@ struct ParseXmlConfig<'a> {
     text_key: Cow<'a, str>,
     /// Always use text default (instead of flattening). Default: false.
     always_use_text_key: bool,
+    /// Always wrap child elements in an array, even if they aren't repeated. Default: false.
+    always_use_array: bool,
     /// Parse "true" or "false" as booleans. Default: true.
     parse_bool: bool,
     /// Parse "null" as null. Default: true.
@@ -124,6 +132,7 @@ impl Function for ParseXml {
         let attr_prefix = arguments.optional("attr_prefix");
         let text_key = arguments.optional("text_key");
         let always_use_text_key = arguments.optional("always_use_text_key");
+        let always_use_array = arguments.optional("always_use_array");
         let parse_bool = arguments.optional("parse_bool");
         let parse_null = arguments.optional("parse_null");
         let parse_number = arguments.optional("parse_number");
@@ -135,6 +144,7 @@ impl Function for ParseXml {
             attr_prefix,
             text_key,
             always_use_text_key,
+            always_use_array,
             parse_bool,
             parse_null,
             parse_number,
@@ -174,6 +184,11 @@ impl Function for ParseXml {
                 kind: kind::BOOLEAN,
                 required: false,
             },
+            Parameter {
+                keyword: "always_use_array",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
             Parameter {
                 keyword: "parse_bool",
                 kind: kind::BOOLEAN,
@@ -202,6 +217,7 @@ struct ParseXmlFn {
     attr_prefix: Option<Box<dyn Expression>>,
     text_key: Option<Box<dyn Expression>>,
     always_use_text_key: Option<Box<dyn Expression>>,
+    always_use_array: Option<Box<dyn Expression>>,
     parse_bool: Option<Box<dyn Expression>>,
     parse_null: Option<Box<dyn Expression>>,
     parse_number: Option<Box<dyn Expression>>,
@@ -242,6 +258,12 @@ impl FunctionExpression for ParseXmlFn {
                 .map(|expr| expr.resolve(ctx))
                 .transpose()?,
 
+            always_use_array: self
+                .always_use_array
+                .as_ref()
+                .map(|expr| expr.resolve(ctx))
+                .transpose()?,
+
             parse_bool: self
                 .parse_bool
                 .as_ref()
@@ -281,6 +303,16 @@ fn inner_kind() -> Kind {
 
 /// Process an XML node, and return a VRL `Value`.
 fn process_node<'a>(node: Node, config: &ParseXmlConfig<'a>) -> Value {
+    // Helper to wrap the value of a child element in an array, if required.
+    // The root element is never wrapped.
+    let wrap = |parent: Node, value: Value| -> Value {
+        if config.always_use_array && parent.is_element() {
+            Value::Array(vec![value])
+        } else {
+            value
+        }
+    };
+
     // Helper to recurse over a `Node`s children, and build an object.
     let recurse = |node: Node| -> BTreeMap<String, Value> {
         let mut map = BTreeMap::new();
@@ -325,6 +357,9 @@ fn process_node<'a>(node: Node, config: &ParseXmlConfig<'a>) -> Value {
                         }
                     };
                 }
+                Entry::Vacant(entry) if n.is_element() => {
+                    entry.insert(wrap(node, value));
+                }
                 Entry::Vacant(entry) => {
                     entry.insert(value);
                 }
@@ -347,6 +382,8 @@ fn process_node<'a>(node: Node, config: &ParseXmlConfig<'a>) -> Value {
                 _ => match node.children().count() {
                     // For a single node, 'flatten' the object if necessary.
                     1 => {
+                        let parent = node;
+
                         // Expect a single element.
                         let node = node
                             .children()
@@ -360,7 +397,7 @@ fn process_node<'a>(node: Node, config: &ParseXmlConfig<'a>) -> Value {
 
                             map.insert(
                                 node.tag_name().name().to_string(),
-                                Value::Object(recurse(node)),
+                                wrap(parent, Value::Object(recurse(node))),
                             );
 
                             Value::Object(map)
@@ -476,6 +513,18 @@ mod tests {
             tdef: type_def(),
         }
 
+        always_use_array {
+            args: func_args![ value: r#"<a attr="value"><b>one</b><c>two</c><c>three</c></a>"#, always_use_array: true ],
+            want: Ok(value!({ "a": { "@attr": "value", "b": ["one"], "c": ["two", "three"] } })),
+            tdef: type_def(),
+        }
+
+        always_use_array_single_child {
+            args: func_args![ value: r#"<root><node attr="value"><message>foo</message></node></root>"#, always_use_array: true ],
+            want: Ok(value!({ "root": { "node": [{ "@attr": "value", "message": ["foo"] }] } })),
+            tdef: type_def(),
+        }
+
         header_and_comments {
             args: func_args![ value: indoc!{r#"
                 <?xml version="1.0" encoding="ISO-8859-1"?>
@@ -40,6 +40,11 @@ impl Function for Redact {
                 kind: kind::ARRAY,
                 required: true,
             },
+            Parameter {
+                keyword: "redactor",
+                kind: kind::BYTES | kind::OBJECT,
+                required: false,
+            },
         ]
     }
 
@@ -55,6 +60,11 @@ impl Function for Redact {
                 source: r#"redact({ "name": "John Doe", "ssn": "123-12-1234"}, filters: ["us_social_security_number"])"#,
                 result: Ok(r#"{ "name": "John Doe", "ssn": "[REDACTED]" }"#),
             },
+            Example {
+                title: "custom replacement",
+                source: r#"redact("my id is 123456", filters: [r'\d+'], redactor: { "type": "text", "replacement": "***" })"#,
+                result: Ok(r#"my id is ***"#),
+            },
         ]
     }
 
@@ -89,7 +99,20 @@ impl Function for Redact {
             })
             .collect::<std::result::Result<Vec<Filter>, _>>()?;
 
-        let redactor = Redactor::Full;
+        let redactor = arguments
+            .optional_value("redactor")?
+            .map(|value| {
+                value
+                    .clone()
+                    .try_into()
+                    .map_err(|error| vrl::function::Error::InvalidArgument {
+                        keyword: "redactor",
+                        value,
+                        error,
+                    })
+            })
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(RedactFn {
             value,
@@ -222,7 +245,7 @@ impl Filter {
                     .iter()
                     .fold(Cow::Borrowed(input), |input, pattern| match pattern {
                         Pattern::Regex(regex) => regex
-                            .replace_all(&input, redactor.pattern())
+                            .replace_all(&input, regex::NoExpand(redactor.pattern()))
                             .into_owned()
                             .into(),
                         Pattern::String(pattern) => {
@@ -231,24 +254,26 @@ impl Filter {
                     })
             }
             Filter::UsSocialSecurityNumber => {
-                US_SOCIAL_SECURITY_NUMBER.replace_all(input, redactor.pattern())
+                US_SOCIAL_SECURITY_NUMBER.replace_all(input, regex::NoExpand(redactor.pattern()))
             }
         }
     }
 }
 
 /// The recipe for redacting the matched filters.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum Redactor {
     Full,
+    Text(String),
 }
 
 impl Redactor {
     fn pattern(&self) -> &str {
-        use Redactor::Full;
+        use Redactor::{Full, Text};
 
         match self {
             Full => "[REDACTED]",
+            Text(replacement) => replacement,
         }
     }
 }
@@ -267,11 +292,46 @@ impl FromStr for Redactor {
 
         match s {
             "full" => Ok(Full),
+            "text" => Err("text redactor must have `replacement` specified"),
             _ => Err("unknown redactor"),
         }
     }
 }
 
+impl TryFrom<Value> for Redactor {
+    type Error = &'static str;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        match value {
+            Value::Object(object) => {
+                let r#type = match object
+                    .get("type")
+                    .ok_or("redactors specified as objects must have type parameter")?
+                {
+                    Value::Bytes(bytes) => Ok(bytes.clone()),
+                    _ => Err("type key in redactors must be a string"),
+                }?;
+
+                match r#type.as_ref() {
+                    b"full" => Ok(Redactor::Full),
+                    b"text" => match object
+                        .get("replacement")
+                        .ok_or("text redactor must have `replacement` specified")?
+                    {
+                        Value::Bytes(bytes) => {
+                            Ok(Redactor::Text(String::from_utf8_lossy(bytes).into_owned()))
+                        }
+                        _ => Err("`replacement` must be a string"),
+                    },
+                    _ => Err("unknown redactor"),
+                }
+            }
+            Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).parse(),
+            _ => Err("unknown literal for redactor, must be a redactor name or object"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use regex::Regex;
@@ -313,6 +373,46 @@ mod test {
              tdef: TypeDef::bytes().infallible(),
         }
 
+        text_redactor {
+             args: func_args![
+                 value: "hello 123456 world",
+                 filters: vec![Regex::new(r"\d+").unwrap()],
+                 redactor: value!({ "type": "text", "replacement": "***" }),
+             ],
+             want: Ok("hello *** world"),
+             tdef: TypeDef::bytes().infallible(),
+        }
+
+        text_redactor_is_not_expanded {
+             args: func_args![
+                 value: "hello 123456 world",
+                 filters: vec![Regex::new(r"(\d+)").unwrap()],
+                 redactor: value!({ "type": "text", "replacement": "$1" }),
+             ],
+             want: Ok("hello $1 world"),
+             tdef: TypeDef::bytes().infallible(),
+        }
+
+        full_redactor {
+             args: func_args![
+                 value: "hello 123456 world",
+                 filters: vec![Regex::new(r"\d+").unwrap()],
+                 redactor: "full",
+             ],
+             want: Ok("hello [REDACTED] world"),
+             tdef: TypeDef::bytes().infallible(),
+        }
+
+        missing_replacement {
+             args: func_args![
+                 value: "hello 123456 world",
+                 filters: vec![Regex::new(r"\d+").unwrap()],
+                 redactor: value!({ "type": "text" }),
+             ],
+             want: Err("invalid argument"),
+             tdef: TypeDef::bytes().infallible(),
+        }
+
         invalid_filter {
              args: func_args![
                  value: "hello 123456 world",
@@ -0,0 +1,147 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+const RATE_OUT_OF_RANGE_ERR: &str = "rate must be between 0.0 and 1.0";
+
+fn sample(rate: Value, ctx: &mut Context) -> Resolved {
+    let rate = rate.try_float()?;
+
+    // A rate outside of the valid range always or never samples, there's no
+    // need to fail the program for it.
+    Ok(Value::Boolean(ctx.rng_mut().next_f64() < rate))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sample;
+
+impl Function for Sample {
+    fn identifier(&self) -> &'static str {
+        "sample"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "rate",
+            kind: kind::FLOAT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "always sample",
+                source: r#"sample(1.0)"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "never sample",
+                source: r#"sample(0.0)"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let rate = arguments.required("rate");
+
+        if let Some(literal) = rate.as_value() {
+            if !matches!(literal, Value::Float(rate) if (0.0..=1.0).contains(&rate.into_inner())) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "rate",
+                    value: literal,
+                    error: RATE_OUT_OF_RANGE_ERR,
+                }
+                .into());
+            }
+        }
+
+        Ok(SampleFn { rate }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SampleFn {
+    rate: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SampleFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let rate = self.rate.resolve(ctx)?;
+
+        sample(rate, ctx)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use vector_common::TimeZone;
+
+    use super::*;
+
+    test_function![
+        sample => Sample;
+
+        always {
+            args: func_args![rate: 1.0],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        never {
+            args: func_args![rate: 0.0],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        rate_too_large {
+            args: func_args![rate: 1.5],
+            want: Err("invalid argument"),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        rate_negative {
+            args: func_args![rate: -0.1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::boolean().infallible(),
+        }
+    ];
+
+    #[test]
+    fn seeded_sequence() {
+        let mut state = vrl::state::Runtime::with_seed(0);
+        let mut object: Value = Value::Object(BTreeMap::new());
+        let tz = TimeZone::default();
+        let mut ctx = Context::new(&mut object, &mut state, &tz);
+        let expr = SampleFn { rate: expr!(0.5) };
+
+        let got = (0..8)
+            .map(|_| expr.resolve(&mut ctx).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            got,
+            vec![
+                value!(false),
+                value!(true),
+                value!(true),
+                value!(false),
+                value!(true),
+                value!(true),
+                value!(true),
+                value!(false),
+            ]
+        );
+    }
+}
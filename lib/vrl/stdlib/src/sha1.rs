@@ -2,9 +2,11 @@ use ::sha1::Digest;
 use ::value::Value;
 use vrl::prelude::*;
 
-fn sha1(value: Value) -> Resolved {
+use crate::util;
+
+fn sha1(value: Value, encoding: &Bytes) -> Resolved {
     let value = value.try_bytes()?;
-    Ok(hex::encode(sha1::Sha1::digest(&value)).into())
+    Ok(util::encode_digest(&sha1::Sha1::digest(&value), encoding).into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -16,19 +18,33 @@ impl Function for Sha1 {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::BYTES,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "encoding",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "sha1",
-            source: r#"sha1("foobar")"#,
-            result: Ok("8843d7f92416211de9ebb963ff4ce28125932878"),
-        }]
+        &[
+            Example {
+                title: "sha1",
+                source: r#"sha1("foobar")"#,
+                result: Ok("8843d7f92416211de9ebb963ff4ce28125932878"),
+            },
+            Example {
+                title: "base64 encoding",
+                source: r#"sha1("foobar", encoding: "base64")"#,
+                result: Ok("iEPX+SQWIR3p67lj/0zigSWTKHg="),
+            },
+        ]
     }
 
     fn compile(
@@ -38,20 +54,26 @@ impl Function for Sha1 {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let encoding = arguments
+            .optional_enum("encoding", &util::digest_encodings())?
+            .unwrap_or_else(|| value!("hex"))
+            .try_bytes()
+            .expect("encoding not bytes");
 
-        Ok(Sha1Fn { value }.as_expr())
+        Ok(Sha1Fn { value, encoding }.as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
 struct Sha1Fn {
     value: Box<dyn Expression>,
+    encoding: Bytes,
 }
 
 impl FunctionExpression for Sha1Fn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        sha1(value)
+        sha1(value, &self.encoding)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -71,5 +93,11 @@ mod tests {
              want: Ok("0beec7b5ea3f0fdbc95d0dd47f3c5bc275da8a33"),
              tdef: TypeDef::bytes().infallible(),
          }
+
+        base64_encoding {
+             args: func_args![value: "foo", encoding: "base64"],
+             want: Ok("C+7Hteo/D9vJXQ3UfzxbwnXaijM="),
+             tdef: TypeDef::bytes().infallible(),
+         }
     ];
 }
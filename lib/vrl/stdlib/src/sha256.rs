@@ -0,0 +1,103 @@
+use ::value::Value;
+use sha_2::{Digest, Sha256 as Sha256Hasher};
+use vrl::prelude::*;
+
+use crate::util;
+
+fn sha256(value: Value, encoding: &Bytes) -> Resolved {
+    let value = value.try_bytes()?;
+    Ok(util::encode_digest(&Sha256Hasher::digest(&value), encoding).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sha256;
+
+impl Function for Sha256 {
+    fn identifier(&self) -> &'static str {
+        "sha256"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "encoding",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "sha256",
+                source: r#"sha256("foobar")"#,
+                result: Ok("c3ab8ff13720e8ad9047dd39466b3c8974e592c2fa383d4a3960714caef0c4f2"),
+            },
+            Example {
+                title: "base64 encoding",
+                source: r#"sha256("foobar", encoding: "base64")"#,
+                result: Ok("w6uP8Tcg6K2QR905Rms8iXTlksL6OD1KOWBxTK7wxPI="),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let encoding = arguments
+            .optional_enum("encoding", &util::digest_encodings())?
+            .unwrap_or_else(|| value!("hex"))
+            .try_bytes()
+            .expect("encoding not bytes");
+
+        Ok(Sha256Fn { value, encoding }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Sha256Fn {
+    value: Box<dyn Expression>,
+    encoding: Bytes,
+}
+
+impl FunctionExpression for Sha256Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        sha256(value, &self.encoding)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        sha256 => Sha256;
+
+        hex_encoding {
+             args: func_args![value: "foo"],
+             want: Ok("2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"),
+             tdef: TypeDef::bytes().infallible(),
+         }
+
+        base64_encoding {
+             args: func_args![value: "foo", encoding: "base64"],
+             want: Ok("LCa0a2j/xo/5m0U8HTBBNBNCLXBkg7+g+YpeiGJm564="),
+             tdef: TypeDef::bytes().infallible(),
+         }
+    ];
+}
@@ -1,37 +1,50 @@
 use std::ops::Range;
 
 use ::value::Value;
+use unicode_segmentation::UnicodeSegmentation;
 use vrl::prelude::*;
 
-fn slice(start: i64, end: Option<i64>, value: Value) -> Resolved {
-    let range = |len: i64| -> Result<Range<usize>> {
-        let start = match start {
-            start if start < 0 => start + len,
-            start => start,
-        };
+/// Turn the `start` and `end` positions into a range over `len` elements.
+///
+/// Negative positions count from the end, positions outside of the value are
+/// clamped to its bounds, and an `end` before `start` results in an empty
+/// range.
+fn range(start: i64, end: Option<i64>, len: usize) -> Range<usize> {
+    let len = len as i64;
+    let position = |pos: i64| {
+        let pos = if pos < 0 { pos + len } else { pos };
+        pos.clamp(0, len) as usize
+    };
 
-        let end = match end {
-            Some(end) if end < 0 => end + len,
-            Some(end) => end,
-            None => len,
-        };
+    let start = position(start);
+    let end = end.map_or(len as usize, position).max(start);
+
+    start..end
+}
 
-        match () {
-            _ if start < 0 || start > len => {
-                Err(format!(r#""start" must be between "{}" and "{}""#, -len, len).into())
+fn slice(start: i64, end: Option<i64>, value: Value) -> Resolved {
+    match value {
+        Value::Bytes(bytes) => match std::str::from_utf8(&bytes) {
+            // Strings are sliced by grapheme cluster, so that a character made
+            // up of multiple code points is never split.
+            Ok(value) => {
+                let graphemes = value.grapheme_indices(true).collect::<Vec<_>>();
+                let range = range(start, end, graphemes.len());
+                let offset = |index: usize| graphemes.get(index).map_or(value.len(), |(i, _)| *i);
+
+                Ok(bytes.slice(offset(range.start)..offset(range.end)).into())
+            }
+            // Anything that isn't valid UTF-8 is sliced by byte, as it has no
+            // characters to keep together.
+            Err(_) => {
+                let range = range(start, end, bytes.len());
+                Ok(bytes.slice(range).into())
             }
-            _ if end < start => Err(r#""end" must be greater or equal to "start""#.into()),
-            _ if end > len => Ok(start as usize..len as usize),
-            _ => Ok(start as usize..end as usize),
+        },
+        Value::Array(mut v) => {
+            let range = range(start, end, v.len());
+            Ok(v.drain(range).collect::<Vec<_>>().into())
         }
-    };
-    match value {
-        Value::Bytes(v) => range(v.len() as i64)
-            .map(|range| v.slice(range))
-            .map(Value::from),
-        Value::Array(mut v) => range(v.len() as i64)
-            .map(|range| v.drain(range).collect::<Vec<_>>())
-            .map(Value::from),
         value => Err(value::Error::Expected {
             got: value.kind(),
             expected: Kind::bytes() | Kind::array(Collection::any()),
@@ -255,29 +268,82 @@ mod tests {
             tdef: TypeDef::array(Collection::from_unknown(Kind::integer().or_bytes().or_boolean())).fallible(),
         }
 
-        error_after_end {
+        bytes_after_end {
             args: func_args![value: "foo",
                              start: 4
             ],
-            want: Err(r#""start" must be between "-3" and "3""#),
+            want: Ok(""),
             tdef: TypeDef::bytes().fallible(),
         }
 
-        error_minus_before_start {
+        bytes_minus_before_start {
             args: func_args![value: "foo",
                              start: -4
             ],
-            want: Err(r#""start" must be between "-3" and "3""#),
+            want: Ok("foo"),
             tdef: TypeDef::bytes().fallible(),
         }
 
-        error_start_end {
+        bytes_end_before_start {
             args: func_args![value: "foo",
                              start: 2,
                              end: 1
             ],
-            want: Err(r#""end" must be greater or equal to "start""#),
+            want: Ok(""),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        bytes_negative_end {
+            args: func_args![value: "foobar",
+                             start: 1,
+                             end: -2
+            ],
+            want: Ok("oob"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        bytes_graphemes {
+            args: func_args![value: "ae\u{301}\u{1f44d}\u{1f3fd}b",
+                             start: 1,
+                             end: 3
+            ],
+            want: Ok("e\u{301}\u{1f44d}\u{1f3fd}"),
             tdef: TypeDef::bytes().fallible(),
         }
+
+        bytes_not_utf8 {
+            args: func_args![value: value!(b"\xfffo\xfeo"),
+                             start: 1,
+                             end: 4
+            ],
+            want: Ok(value!(b"fo\xfe")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        bytes_not_utf8_negative {
+            args: func_args![value: value!(b"foo\xff"),
+                             start: -2
+            ],
+            want: Ok(value!(b"o\xff")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        array_after_end {
+            args: func_args![value: vec![0, 1, 2],
+                             start: 1,
+                             end: 10
+            ],
+            want: Ok(vec![1, 2]),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())).fallible(),
+        }
+
+        array_minus_before_start {
+            args: func_args![value: vec![0, 1, 2],
+                             start: -10,
+                             end: -1
+            ],
+            want: Ok(vec![0, 1]),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())).fallible(),
+        }
     ];
 }
@@ -0,0 +1,252 @@
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Snake,
+    Camel,
+    Kebab,
+    Pascal,
+}
+
+impl Case {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        match bytes {
+            b"snake" => Self::Snake,
+            b"camel" => Self::Camel,
+            b"kebab" => Self::Kebab,
+            b"pascal" => Self::Pascal,
+            _ => unreachable!("enum invariant"),
+        }
+    }
+}
+
+fn cases() -> Vec<Value> {
+    vec![
+        value!("snake"),
+        value!("camel"),
+        value!("kebab"),
+        value!("pascal"),
+    ]
+}
+
+/// Split the string into words.
+///
+/// Any character that isn't alphanumeric separates words, as does a change
+/// from a lowercase letter or digit to an uppercase letter. A run of uppercase
+/// letters is kept together as an acronym, up to the last letter before a
+/// lowercase one, so `"HTTPServer"` splits into `"HTTP"` and `"Server"`.
+fn words(value: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for part in value.split(|c: char| !c.is_alphanumeric()) {
+        let chars = part.char_indices().collect::<Vec<_>>();
+        let mut start = 0;
+
+        for (i, window) in chars.windows(2).enumerate() {
+            let (_, prev) = window[0];
+            let (index, current) = window[1];
+            let next = chars.get(i + 2).map(|(_, c)| *c);
+
+            let boundary = current.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next.map_or(false, char::is_lowercase)));
+
+            if boundary {
+                words.push(&part[start..index]);
+                start = index;
+            }
+        }
+
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn to_case(value: Value, case: Case) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let words = words(&value);
+
+    let converted = match case {
+        Case::Snake => words
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        Case::Kebab => words
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("-"),
+        Case::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.to_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        Case::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+    };
+
+    Ok(converted.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToCase;
+
+impl Function for ToCase {
+    fn identifier(&self) -> &'static str {
+        "to_case"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "case",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "snake case",
+                source: r#"to_case("userID", "snake")"#,
+                result: Ok("user_id"),
+            },
+            Example {
+                title: "camel case",
+                source: r#"to_case("http_status_code", "camel")"#,
+                result: Ok("httpStatusCode"),
+            },
+            Example {
+                title: "kebab case",
+                source: r#"to_case("HTTPServerError", "kebab")"#,
+                result: Ok("http-server-error"),
+            },
+            Example {
+                title: "pascal case",
+                source: r#"to_case("request-id", "pascal")"#,
+                result: Ok("RequestId"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let case = arguments
+            .required_enum("case", &cases())?
+            .try_bytes()
+            .map(|case| Case::from_bytes(&case))
+            .expect("case not bytes");
+
+        Ok(ToCaseFn { value, case }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ToCaseFn {
+    value: Box<dyn Expression>,
+    case: Case,
+}
+
+impl FunctionExpression for ToCaseFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        to_case(value, self.case)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        to_case => ToCase;
+
+        snake {
+            args: func_args![value: "fooBar baz-QUX", case: "snake"],
+            want: Ok(value!("foo_bar_baz_qux")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        camel {
+            args: func_args![value: "foo_bar_baz", case: "camel"],
+            want: Ok(value!("fooBarBaz")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        kebab {
+            args: func_args![value: "FooBar", case: "kebab"],
+            want: Ok(value!("foo-bar")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        pascal {
+            args: func_args![value: "foo bar", case: "pascal"],
+            want: Ok(value!("FooBar")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        acronym {
+            args: func_args![value: "parseHTTPRequest", case: "snake"],
+            want: Ok(value!("parse_http_request")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        trailing_acronym {
+            args: func_args![value: "userID", case: "pascal"],
+            want: Ok(value!("UserId")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        digits {
+            args: func_args![value: "utf8Encoding2", case: "kebab"],
+            want: Ok(value!("utf8-encoding2")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty {
+            args: func_args![value: "", case: "camel"],
+            want: Ok(value!("")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+}
@@ -0,0 +1,132 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn to_string_lossy(value: Value) -> Value {
+    use chrono::SecondsFormat;
+    use Value::{Array, Boolean, Bytes, Float, Integer, Null, Object, Regex, Timestamp};
+
+    match value {
+        v @ Bytes(_) => v,
+        Integer(v) => v.to_string().into(),
+        Float(v) => v.to_string().into(),
+        Boolean(v) => v.to_string().into(),
+        Timestamp(v) => v.to_rfc3339_opts(SecondsFormat::AutoSi, true).into(),
+        Regex(v) => v.as_str().into(),
+        Null => "".into(),
+        v @ (Array(_) | Object(_)) => {
+            // With `vrl::Value` it should not be possible to get `Err`.
+            match serde_json::to_string(&v) {
+                Ok(value) => value.into(),
+                Err(error) => unreachable!("unable encode to json: {}", error),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToStringLossy;
+
+impl Function for ToStringLossy {
+    fn identifier(&self) -> &'static str {
+        "to_string_lossy"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "integer",
+                source: "to_string_lossy(5)",
+                result: Ok("s'5'"),
+            },
+            Example {
+                title: "null",
+                source: "to_string_lossy(null)",
+                result: Ok(""),
+            },
+            Example {
+                title: "regex",
+                source: "to_string_lossy(r'^foo')",
+                result: Ok("s'^foo'"),
+            },
+            Example {
+                title: "array",
+                source: r#"to_string_lossy([1, "two"])"#,
+                result: Ok(r#"s'[1,"two"]'"#),
+            },
+            Example {
+                title: "object",
+                source: r#"to_string_lossy({ "foo": true })"#,
+                result: Ok(r#"s'{"foo":true}'"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ToStringLossyFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ToStringLossyFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ToStringLossyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        Ok(to_string_lossy(value))
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        to_string_lossy => ToStringLossy;
+
+        integer {
+            args: func_args![value: 20],
+            want: Ok("20"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        null {
+            args: func_args![value: value!(null)],
+            want: Ok(""),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        array {
+            args: func_args![value: value!([1, "two", null])],
+            want: Ok(r#"[1,"two",null]"#),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        object {
+            args: func_args![value: value!({ "foo": { "bar": 1.5 } })],
+            want: Ok(r#"{"foo":{"bar":1.5}}"#),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+}
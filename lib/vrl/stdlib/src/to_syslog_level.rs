@@ -38,7 +38,7 @@ impl Function for ToSyslogLevel {
         &[
             Example {
                 title: "valid",
-                source: "to_syslog_level!(0)",
+                source: "to_syslog_level(0)",
                 result: Ok("emerg"),
             },
             Example {
@@ -76,7 +76,13 @@ impl FunctionExpression for ToSyslogLevelFn {
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::bytes().fallible()
+        // A literal severity is known to be valid or not at compile time.
+        let valid = self
+            .value
+            .as_value()
+            .map_or(false, |value| to_syslog_level(value).is_ok());
+
+        TypeDef::bytes().with_fallibility(!valid)
     }
 }
 
@@ -90,49 +96,49 @@ mod tests {
         emergency {
             args: func_args![value: value!(0)],
             want: Ok(value!("emerg")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         alert {
             args: func_args![value: value!(1)],
             want: Ok(value!("alert")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         critical {
             args: func_args![value: value!(2)],
             want: Ok(value!("crit")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         error {
             args: func_args![value: value!(3)],
             want: Ok(value!("err")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         warning {
             args: func_args![value: value!(4)],
             want: Ok(value!("warning")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         notice {
             args: func_args![value: value!(5)],
             want: Ok(value!("notice")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         informational {
             args: func_args![value: value!(6)],
             want: Ok(value!("info")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         debug {
             args: func_args![value: value!(7)],
             want: Ok(value!("debug")),
-            tdef: TypeDef::bytes().fallible(),
+            tdef: TypeDef::bytes().infallible(),
         }
 
         invalid_severity_next_int {
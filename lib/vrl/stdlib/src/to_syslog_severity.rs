@@ -4,14 +4,14 @@ use vrl::prelude::*;
 fn to_syslog_severity(level: Value) -> Resolved {
     let level = level.try_bytes_utf8_lossy()?;
     // Severity levels: https://en.wikipedia.org/wiki/Syslog#Severity_level
-    let severity = match &level[..] {
-        "emerg" | "panic" => 0,
+    let severity = match &level.to_lowercase()[..] {
+        "emerg" | "emergency" | "panic" => 0,
         "alert" => 1,
-        "crit" => 2,
+        "crit" | "critical" => 2,
         "err" | "error" => 3,
         "warning" | "warn" => 4,
         "notice" => 5,
-        "info" => 6,
+        "info" | "informational" => 6,
         "debug" => 7,
         _ => return Err(format!("syslog level {} not valid", level).into()),
     };
@@ -38,7 +38,7 @@ impl Function for ToSyslogSeverity {
         &[
             Example {
                 title: "valid",
-                source: "to_syslog_severity!(s'crit')",
+                source: "to_syslog_severity(s'crit')",
                 result: Ok("2"),
             },
             Example {
@@ -75,7 +75,13 @@ impl FunctionExpression for ToSyslogSeverityFn {
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::integer().fallible()
+        // A literal level is known to be valid or not at compile time.
+        let valid = self
+            .value
+            .as_value()
+            .map_or(false, |level| to_syslog_severity(level).is_ok());
+
+        TypeDef::integer().with_fallibility(!valid)
     }
 }
 
@@ -89,49 +95,49 @@ mod tests {
         emergency {
             args: func_args![value: value!("emerg")],
             want: Ok(value!(0)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         alert {
             args: func_args![value: value!("alert")],
             want: Ok(value!(1)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         critical {
             args: func_args![value: value!("crit")],
             want: Ok(value!(2)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         error {
             args: func_args![value: value!("err")],
             want: Ok(value!(3)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         warning {
             args: func_args![value: value!("warn")],
             want: Ok(value!(4)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         notice {
             args: func_args![value: value!("notice")],
             want: Ok(value!(5)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         informational {
             args: func_args![value: value!("info")],
             want: Ok(value!(6)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         debug {
             args: func_args![value: value!("debug")],
             want: Ok(value!(7)),
-            tdef: TypeDef::integer().fallible(),
+            tdef: TypeDef::integer().infallible(),
         }
 
         invalid_level_1 {
@@ -145,5 +151,23 @@ mod tests {
             want: Err("syslog level aww schucks not valid"),
             tdef: TypeDef::integer().fallible(),
         }
+
+        synonyms {
+            args: func_args![value: value!("critical")],
+            want: Ok(value!(2)),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        case_insensitive {
+            args: func_args![value: value!("WARN")],
+            want: Ok(value!(4)),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        invalid_level_case {
+            args: func_args![value: value!("Oopsie")],
+            want: Err("syslog level Oopsie not valid"),
+            tdef: TypeDef::integer().fallible(),
+        }
     ];
 }
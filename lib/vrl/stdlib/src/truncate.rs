@@ -1,26 +1,25 @@
 use ::value::Value;
+use unicode_segmentation::UnicodeSegmentation;
 use vrl::prelude::*;
 
 fn truncate(value: Value, limit: Value, ellipsis: Value) -> Resolved {
     let mut value = value.try_bytes_utf8_lossy()?.into_owned();
     let limit = limit.try_integer()?;
     let limit = if limit < 0 { 0 } else { limit as usize };
-    let ellipsis = ellipsis.try_boolean()?;
-    let pos = if let Some((pos, chr)) = value.char_indices().take(limit).last() {
-        // char_indices gives us the starting position of the character at limit,
-        // we want the end position.
-        pos + chr.len_utf8()
-    } else {
-        // We have an empty string
-        0
+    let ellipsis = match ellipsis {
+        Value::Boolean(true) => "...".to_owned(),
+        Value::Boolean(false) => String::new(),
+        ellipsis => ellipsis.try_bytes_utf8_lossy()?.into_owned(),
     };
-    if value.len() > pos {
-        value.truncate(pos);
 
-        if ellipsis {
-            value.push_str("...");
-        }
+    // The limit counts grapheme clusters, so that a character made up of
+    // multiple code points is never split. The string is only truncated if
+    // there is a grapheme beyond the limit.
+    if let Some((pos, _)) = value.grapheme_indices(true).nth(limit) {
+        value.truncate(pos);
+        value.push_str(&ellipsis);
     }
+
     Ok(value.into())
 }
 
@@ -46,7 +45,7 @@ impl Function for Truncate {
             },
             Parameter {
                 keyword: "ellipsis",
-                kind: kind::BOOLEAN,
+                kind: kind::BOOLEAN | kind::BYTES,
                 required: false,
             },
         ]
@@ -69,6 +68,11 @@ impl Function for Truncate {
                 source: r#"truncate("foo", 2, true)"#,
                 result: Ok("fo..."),
             },
+            Example {
+                title: "custom ellipsis",
+                source: r#"truncate("foobar", 3, "…")"#,
+                result: Ok("foo…"),
+            },
         ]
     }
 
@@ -178,5 +182,32 @@ mod tests {
             want: Ok("♔♕♖♗♘♙..."),
             tdef: TypeDef::bytes().infallible(),
         }
+
+        graphemes {
+            args: func_args![value: "cafe\u{301} au lait",
+                             limit: 4,
+                             ellipsis: true
+            ],
+            want: Ok("cafe\u{301}..."),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        custom_ellipsis {
+            args: func_args![value: "Supercalifragilisticexpialidocious",
+                             limit: 5,
+                             ellipsis: "…"
+            ],
+            want: Ok("Super…"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        custom_ellipsis_not_truncated {
+            args: func_args![value: "Super",
+                             limit: 5,
+                             ellipsis: "…"
+            ],
+            want: Ok("Super"),
+            tdef: TypeDef::bytes().infallible(),
+        }
     ];
 }
@@ -10,6 +10,10 @@ fn type_def(type_def: &VrlTypeDef) -> Value {
         tree.insert("fallible".to_owned(), true.into());
     }
 
+    if type_def.is_abortable() {
+        tree.insert("abortable".to_owned(), true.into());
+    }
+
     tree.into()
 }
 
@@ -55,13 +55,23 @@ impl FunctionExpression for UniqueFn {
         unique(value)
     }
 
-    fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::array(Collection::any())
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        // Removing duplicates shifts the remaining elements, so only the kind
+        // of the elements is kept, not their position.
+        let element = self
+            .value
+            .type_def(state)
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        TypeDef::array(Collection::from_unknown(element))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use vector_common::btreemap;
+
     use super::*;
 
     test_function![
@@ -72,7 +82,7 @@ mod tests {
                 value: value!(["bar", "foo", "baz", "foo"]),
             ],
             want: Ok(value!(["bar", "foo", "baz"])),
-            tdef: TypeDef::array(Collection::any()),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
         }
 
         mixed_values {
@@ -80,7 +90,28 @@ mod tests {
                 value: value!(["foo", [1,2,3], "123abc", 1, true, [1,2,3], "foo", true, 1]),
             ],
             want: Ok(value!(["foo", [1,2,3], "123abc", 1, true])),
-            tdef: TypeDef::array(Collection::any()),
+            tdef: TypeDef::array(Collection::from_unknown(
+                Kind::bytes()
+                    .or_integer()
+                    .or_boolean()
+                    .or_array(btreemap! {
+                        Index::from(0) => Kind::integer(),
+                        Index::from(1) => Kind::integer(),
+                        Index::from(2) => Kind::integer(),
+                    }),
+            )),
+        }
+
+        nested_objects {
+            args: func_args![
+                value: value!([{"a": {"b": 1}}, {"a": {"b": 2}}, {"a": {"b": 1}}]),
+            ],
+            want: Ok(value!([{"a": {"b": 1}}, {"a": {"b": 2}}])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::object(btreemap! {
+                "a" => Kind::object(btreemap! {
+                    "b" => Kind::integer(),
+                }),
+            }))),
         }
     ];
 }
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+fn unzip(value: Value) -> Resolved {
+    let pairs = value.try_array()?;
+
+    let mut firsts = Vec::with_capacity(pairs.len());
+    let mut seconds = Vec::with_capacity(pairs.len());
+
+    for (index, pair) in pairs.into_iter().enumerate() {
+        match pair {
+            Value::Array(pair) if pair.len() == 2 => {
+                let mut pair = pair.into_iter();
+                firsts.extend(pair.next());
+                seconds.extend(pair.next());
+            }
+            _ => return Err(format!("element at index {} is not a pair", index).into()),
+        }
+    }
+
+    Ok(Value::from(vec![Value::from(firsts), Value::from(seconds)]))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Unzip;
+
+impl Function for Unzip {
+    fn identifier(&self) -> &'static str {
+        "unzip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "unzip",
+                source: r#"unzip!([[1, "a"], [2, "b"]])"#,
+                result: Ok(r#"[[1, 2], ["a", "b"]]"#),
+            },
+            Example {
+                title: "not a pair",
+                source: r#"unzip!([[1, "a"], [2]])"#,
+                result: Err(
+                    r#"function call error for "unzip" at (0:23): element at index 1 is not a pair"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(UnzipFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UnzipFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for UnzipFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        unzip(value)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        // The kind of each side of the pairs is the union of the kind of every
+        // element of the pairs, the position of an element isn't tracked.
+        let element = self
+            .value
+            .type_def(state)
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind)
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        let side = Kind::array(Collection::from_unknown(element));
+
+        TypeDef::array(BTreeMap::from([
+            (Index::from(0), side.clone()),
+            (Index::from(1), side),
+        ]))
+        .fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_common::btreemap;
+
+    use super::*;
+
+    fn sides(element: Kind) -> TypeDef {
+        TypeDef::array(btreemap! {
+            Index::from(0) => Kind::array(Collection::from_unknown(element.clone())),
+            Index::from(1) => Kind::array(Collection::from_unknown(element)),
+        })
+        .fallible()
+    }
+
+    test_function![
+        unzip => Unzip;
+
+        pairs {
+            args: func_args![value: value!([[1, "a"], [2, "b"]])],
+            want: Ok(value!([[1, 2], ["a", "b"]])),
+            tdef: sides(Kind::integer().or_bytes()),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!([[], []])),
+            tdef: sides(Kind::any()),
+        }
+
+        not_an_array {
+            args: func_args![value: value!([[1, "a"], 2])],
+            want: Err("element at index 1 is not a pair"),
+            tdef: sides(Kind::integer().or_bytes()),
+        }
+
+        too_long {
+            args: func_args![value: value!([[1, 2, 3]])],
+            want: Err("element at index 0 is not a pair"),
+            tdef: sides(Kind::integer()),
+        }
+    ];
+}
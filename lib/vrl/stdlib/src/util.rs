@@ -11,25 +11,36 @@ where
     fun(num * multiplier as f64) / multiplier
 }
 
+/// The named capture groups of a regular expression, with their position.
+///
+/// This is computed once, when the function is compiled, so that each match
+/// only has to be indexed by position.
+#[cfg(any(feature = "parse_regex", feature = "parse_regex_all"))]
+pub(crate) fn capture_names(regex: &regex::Regex) -> Vec<(usize, String)> {
+    regex
+        .capture_names()
+        .enumerate()
+        .filter_map(|(index, name)| name.map(|name| (index, name.to_owned())))
+        .collect()
+}
+
 /// Takes a set of captures that have resulted from matching a regular expression
 /// against some text and fills a `BTreeMap` with the result.
 ///
 /// All captures are inserted with a key as the numeric index of that capture
 /// "0" is the overall match.
-/// Any named captures are also added to the Map with the key as the name.
+/// Any named captures are also added to the Map with the key as the name, as
+/// given by [`capture_names`].
 ///
 #[cfg(any(feature = "parse_regex", feature = "parse_regex_all"))]
 pub(crate) fn capture_regex_to_map(
-    regex: &regex::Regex,
+    capture_names: &[(usize, String)],
     capture: &regex::Captures,
     numeric_groups: bool,
 ) -> std::collections::BTreeMap<String, ::value::Value> {
-    let names = regex.capture_names().flatten().map(|name| {
-        (
-            name.to_owned(),
-            capture.name(name).map(|s| s.as_str()).into(),
-        )
-    });
+    let names = capture_names
+        .iter()
+        .map(|(index, name)| (name.clone(), capture.get(*index).map(|s| s.as_str()).into()));
 
     if numeric_groups {
         let indexed = capture
@@ -82,6 +93,22 @@ pub(crate) fn is_nullish(value: &::value::Value) -> bool {
     }
 }
 
+#[cfg(any(feature = "md5", feature = "sha1", feature = "sha256"))]
+pub(crate) fn digest_encodings() -> Vec<::value::Value> {
+    vec!["hex".into(), "base64".into()]
+}
+
+/// Encode a digest as either a lowercase `hex` or a standard, padded `base64`
+/// string.
+#[cfg(any(feature = "md5", feature = "sha1", feature = "sha256"))]
+pub(crate) fn encode_digest(digest: &[u8], encoding: &[u8]) -> String {
+    match encoding {
+        b"hex" => hex::encode(digest),
+        b"base64" => base64::encode(digest),
+        _ => unreachable!("enum invariant"),
+    }
+}
+
 #[cfg(any(feature = "decode_base64", feature = "encode_base64"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Base64Charset {
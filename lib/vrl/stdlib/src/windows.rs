@@ -0,0 +1,155 @@
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+fn windows(value: Value, window_size: Value) -> Resolved {
+    let array = value.try_array()?;
+    let window_size = window_size.try_integer()?;
+
+    if window_size < 1 {
+        return Err(r#""window_size" must be at least 1"#.into());
+    }
+
+    // A window can't be larger than the array, so any size that doesn't fit
+    // in a usize yields no windows at all.
+    let window_size = usize::try_from(window_size).unwrap_or(usize::MAX);
+
+    Ok(array
+        .windows(window_size)
+        .map(|window| Value::from(window.to_vec()))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Windows;
+
+impl Function for Windows {
+    fn identifier(&self) -> &'static str {
+        "windows"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "window_size",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "overlapping windows",
+                source: r#"windows([1, 2, 3, 4], 3)"#,
+                result: Ok("[[1, 2, 3], [2, 3, 4]]"),
+            },
+            Example {
+                title: "window larger than the array",
+                source: r#"windows([1, 2], 3)"#,
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let window_size = arguments.required("window_size");
+
+        if let Some(literal) = window_size.as_value() {
+            if matches!(literal.as_integer(), Some(integer) if integer < 1) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "window_size",
+                    value: literal,
+                    error: r#""window_size" must be at least 1"#,
+                }
+                .into());
+            }
+        }
+
+        Ok(WindowsFn { value, window_size }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WindowsFn {
+    value: Box<dyn Expression>,
+    window_size: Box<dyn Expression>,
+}
+
+impl FunctionExpression for WindowsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let window_size = self.window_size.resolve(ctx)?;
+
+        windows(value, window_size)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        let not_literal = self.window_size.as_value().is_none();
+
+        let element = self
+            .value
+            .type_def(state)
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        TypeDef::array(Collection::from_unknown(Kind::array(
+            Collection::from_unknown(element),
+        )))
+        .with_fallibility(not_literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        windows => Windows;
+
+        overlapping {
+            args: func_args![value: value!([1, 2, 3, 4]),
+                             window_size: 3,
+            ],
+            want: Ok(value!([[1, 2, 3], [2, 3, 4]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::from_unknown(Kind::integer())))),
+        }
+
+        single_element {
+            args: func_args![value: value!([1, "two"]),
+                             window_size: 1,
+            ],
+            want: Ok(value!([[1], ["two"]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::from_unknown(Kind::integer().or_bytes())))),
+        }
+
+        larger_than_array {
+            args: func_args![value: value!([1, 2]),
+                             window_size: 3,
+            ],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::from_unknown(Kind::integer())))),
+        }
+
+        zero_window_size {
+            args: func_args![value: value!([1, 2]),
+                             window_size: 0,
+            ],
+            want: Err("invalid argument"),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::from_unknown(Kind::integer())))),
+        }
+    ];
+}
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+fn zip(value: Value, other: Value, strict: Value) -> Resolved {
+    let value = value.try_array()?;
+    let other = other.try_array()?;
+
+    if strict.try_boolean()? && value.len() != other.len() {
+        return Err(format!(
+            "arrays have different lengths: {} and {}",
+            value.len(),
+            other.len()
+        )
+        .into());
+    }
+
+    Ok(value
+        .into_iter()
+        .zip(other)
+        .map(|(a, b)| Value::from(vec![a, b]))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Zip;
+
+impl Function for Zip {
+    fn identifier(&self) -> &'static str {
+        "zip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "strict",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "zip",
+                source: r#"zip([1, 2], ["a", "b"])"#,
+                result: Ok(r#"[[1, "a"], [2, "b"]]"#),
+            },
+            Example {
+                title: "truncated to the shorter array",
+                source: r#"zip([1, 2, 3], ["a", "b"])"#,
+                result: Ok(r#"[[1, "a"], [2, "b"]]"#),
+            },
+            Example {
+                title: "strict",
+                source: r#"zip!([1, 2, 3], ["a", "b"], strict: true)"#,
+                result: Err(
+                    r#"function call error for "zip" at (0:41): arrays have different lengths: 3 and 2"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+        let strict = arguments.optional("strict").unwrap_or_else(|| expr!(false));
+
+        Ok(ZipFn {
+            value,
+            other,
+            strict,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ZipFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+    strict: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ZipFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+        let strict = self.strict.resolve(ctx)?;
+
+        zip(value, other, strict)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        let element = |expr: &dyn Expression| {
+            expr.type_def(state)
+                .as_array()
+                .map_or_else(Kind::any, Collection::reduced_kind)
+        };
+
+        let pair = Kind::array(BTreeMap::from([
+            (Index::from(0), element(self.value.as_ref())),
+            (Index::from(1), element(self.other.as_ref())),
+        ]));
+
+        // Only a strict zip can fail, if the arrays have different lengths.
+        let lenient = matches!(self.strict.as_value(), Some(Value::Boolean(false)));
+
+        TypeDef::array(Collection::from_unknown(pair)).with_fallibility(!lenient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_common::btreemap;
+
+    use super::*;
+
+    fn pairs(a: Kind, b: Kind) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(btreemap! {
+            Index::from(0) => a,
+            Index::from(1) => b,
+        })))
+    }
+
+    test_function![
+        zip => Zip;
+
+        same_length {
+            args: func_args![value: value!([1, 2]), other: value!(["a", "b"])],
+            want: Ok(value!([[1, "a"], [2, "b"]])),
+            tdef: pairs(Kind::integer(), Kind::bytes()),
+        }
+
+        truncated {
+            args: func_args![value: value!([1, 2, 3]), other: value!(["a", "b"])],
+            want: Ok(value!([[1, "a"], [2, "b"]])),
+            tdef: pairs(Kind::integer(), Kind::bytes()),
+        }
+
+        strict_same_length {
+            args: func_args![value: value!([1]), other: value!([true]), strict: true],
+            want: Ok(value!([[1, true]])),
+            tdef: pairs(Kind::integer(), Kind::boolean()).fallible(),
+        }
+
+        strict_different_length {
+            args: func_args![value: value!([1, 2]), other: value!([true]), strict: true],
+            want: Err("arrays have different lengths: 2 and 1"),
+            tdef: pairs(Kind::integer(), Kind::boolean()).fallible(),
+        }
+
+        empty {
+            args: func_args![value: value!([]), other: value!([1])],
+            want: Ok(value!([])),
+            tdef: pairs(Kind::never(), Kind::integer()),
+        }
+    ];
+}
@@ -194,6 +194,7 @@ impl Test {
             result_approx: false,
             skip,
             read_only_paths: vec![],
+            ignored_warnings: vec![],
         }
     }
 }
@@ -18,6 +18,9 @@ pub struct Test {
 
     // paths set to read-only
     pub read_only_paths: Vec<(OwnedTargetPath, bool)>,
+
+    // codes of the warnings the test triggers on purpose
+    pub ignored_warnings: Vec<usize>,
 }
 
 enum CaptureMode {
@@ -44,6 +47,7 @@ impl Test {
         }
 
         let mut read_only_paths = vec![];
+        let mut ignored_warnings = vec![];
 
         let mut capture_mode = CaptureMode::None;
         for mut line in content.lines() {
@@ -90,6 +94,14 @@ impl Test {
                     read_only_paths
                         .push((OwnedTargetPath::metadata(parse_value_path(path_str)), true));
                     continue;
+                } else if line.starts_with("ignore_warning:") {
+                    let code = line
+                        .strip_prefix("ignore_warning:")
+                        .expect("ignore_warning")
+                        .trim()
+                        .trim_start_matches('E');
+                    ignored_warnings.push(code.parse().expect("warning code"));
+                    continue;
                 }
 
                 match capture_mode {
@@ -135,6 +147,7 @@ impl Test {
             result_approx,
             skip,
             read_only_paths,
+            ignored_warnings,
         }
     }
 
@@ -155,6 +168,7 @@ impl Test {
             result_approx: false,
             skip: false,
             read_only_paths: vec![],
+            ignored_warnings: vec![],
         }
     }
 }
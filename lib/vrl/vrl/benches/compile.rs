@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// An `if` statement with `depth - 1` chained `else if` branches.
+fn else_if_chain(depth: usize) -> String {
+    let branches: Vec<_> = (0..depth)
+        .map(|i| format!("if .level == {0} {{\n    .matched = {0}\n}}", i))
+        .collect();
+
+    branches.join(" else ")
+}
+
+/// `depth` `if` statements, each nested in the block of the previous one. The
+/// type of every block depends on the blocks nested inside it.
+fn nested_ifs(depth: usize) -> String {
+    let mut program = String::from(".matched = true");
+
+    for i in (0..depth).rev() {
+        program = format!(
+            "if .level > {0} {{\n.depth = {0}\n{1}\n}} else {{\n.depth = -1\n}}",
+            i, program
+        );
+    }
+
+    program
+}
+
+fn benchmark_vrl_compile(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vrl/compile");
+    let functions = vrl_stdlib::all();
+
+    for depth in [10, 50, 100] {
+        let sources = [
+            ("else_if_chain", else_if_chain(depth)),
+            ("nested_ifs", nested_ifs(depth)),
+        ];
+
+        for (name, source) in sources {
+            group.bench_with_input(BenchmarkId::new(name, depth), &source, |b, source| {
+                b.iter(|| black_box(vrl::compile(source, &functions).unwrap()))
+            });
+        }
+    }
+}
+
+criterion_group!(vrl_compile, benchmark_vrl_compile);
+criterion_main!(vrl_compile);
@@ -90,6 +90,69 @@ static SOURCES: &[Source] = &[
             .origin, .err = .hostname + "/" + .matches.name + "/" + .matches.num
         "#},
     },
+    Source {
+        name: "else_if_chain",
+        target: r#"{ "level": 20 }"#,
+        program: indoc! {r#"
+            if .level == 1 {
+                .matched = 1
+            } else if .level == 2 {
+                .matched = 2
+            } else if .level == 3 {
+                .matched = 3
+            } else if .level == 4 {
+                .matched = 4
+            } else if .level == 5 {
+                .matched = 5
+            } else if .level == 6 {
+                .matched = 6
+            } else if .level == 7 {
+                .matched = 7
+            } else if .level == 8 {
+                .matched = 8
+            } else if .level == 9 {
+                .matched = 9
+            } else if .level == 10 {
+                .matched = 10
+            } else if .level == 11 {
+                .matched = 11
+            } else if .level == 12 {
+                .matched = 12
+            } else if .level == 13 {
+                .matched = 13
+            } else if .level == 14 {
+                .matched = 14
+            } else if .level == 15 {
+                .matched = 15
+            } else if .level == 16 {
+                .matched = 16
+            } else if .level == 17 {
+                .matched = 17
+            } else if .level == 18 {
+                .matched = 18
+            } else if .level == 19 {
+                .matched = 19
+            } else if .level == 20 {
+                .matched = 20
+            }
+        "#},
+    },
+    Source {
+        name: "block_early_error",
+        target: r#"{ "message": "not json" }"#,
+        program: indoc! {r#"
+            .a = parse_json!(.message)
+            .b = parse_json!(.message)
+            .c = parse_json!(.message)
+            .d = parse_json!(.message)
+            .e = parse_json!(.message)
+            .f = parse_json!(.message)
+            .g = parse_json!(.message)
+            .h = parse_json!(.message)
+            .i = parse_json!(.message)
+            .j = parse_json!(.message)
+        "#},
+    },
 ];
 
 fn benchmark_vrl_runtimes(c: &mut Criterion) {
@@ -11,9 +11,11 @@ pub mod prelude;
 mod runtime;
 
 pub use compiler::{
-    function, state, value, CompilationResult, CompileConfig, Compiler, Context, Expression,
+    function, state, value, Clock, CompilationResult, CompileConfig, Compiler, Context, Expression,
     Function, Program, ProgramInfo, SecretTarget, Target, TargetValue, TargetValueRef, VrlRuntime,
 };
+#[cfg(feature = "profiling")]
+pub use compiler::{Branches, Profile};
 pub use diagnostic;
 pub use runtime::{Runtime, RuntimeResult, Terminate};
 pub use vector_common::TimeZone;
@@ -54,3 +56,55 @@ pub fn compile_with_state(
 
     Compiler::compile(fns, ast, state, config)
 }
+
+/// Compile a given source into the final [`Program`], together with the
+/// warnings raised while compiling it.
+///
+/// Warnings don't fail compilation, callers that don't surface them can
+/// ignore the returned list.
+pub fn compile_with_warnings(
+    source: &str,
+    fns: &[Box<dyn Function>],
+    state: &TypeState,
+    config: CompileConfig,
+) -> compiler::Result<(Program, diagnostic::DiagnosticList)> {
+    let CompilationResult {
+        program, warnings, ..
+    } = compile_with_state(source, fns, state, config)?;
+
+    Ok((program, warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compile_with_warnings_returns_warnings() {
+        let (_, warnings) = compile_with_warnings(
+            "if true { 1 } else { 2 }",
+            &[],
+            &TypeState::default(),
+            CompileConfig::default(),
+        )
+        .unwrap();
+
+        let codes = warnings
+            .iter()
+            .map(|warning| warning.code())
+            .collect::<Vec<_>>();
+        assert_eq!(codes, vec![703]);
+    }
+
+    #[test]
+    fn compile_with_warnings_uses_config() {
+        let mut config = CompileConfig::default();
+        config.set_read_only();
+
+        let errors = compile_with_warnings(".foo = 1", &[], &TypeState::default(), config)
+            .map(|_| ())
+            .unwrap_err();
+
+        assert!(errors.has_errors());
+    }
+}
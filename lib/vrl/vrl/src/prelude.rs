@@ -6,8 +6,8 @@ pub use compiler::{expression, state, value::kind};
 pub use compiler::{
     function::{closure, FunctionClosure},
     value::{Collection, Field, Index, IterItem, Kind},
-    Context, Expression, ExpressionError, Function, FunctionExpression, Resolved, Target, TypeDef,
-    TypeInfo,
+    Context, ControlFlow, Expression, ExpressionError, Function, FunctionExpression, Resolved,
+    Target, TypeDef, TypeInfo,
 };
 
 pub type Result<T> = std::result::Result<T, ExpressionError>;
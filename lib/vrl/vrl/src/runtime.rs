@@ -1,4 +1,8 @@
-use std::{error::Error, fmt};
+use std::{
+    error::Error,
+    fmt,
+    time::{Duration, Instant},
+};
 
 use compiler::ExpressionError;
 use lookup::OwnedTargetPath;
@@ -11,6 +15,7 @@ pub type RuntimeResult = Result<Value, Terminate>;
 #[derive(Debug, Default)]
 pub struct Runtime {
     state: state::Runtime,
+    timeout: Option<Duration>,
 }
 
 /// The error raised if the runtime is terminated.
@@ -25,6 +30,18 @@ pub enum Terminate {
 
     /// An unexpected program termination.
     Error(ExpressionError),
+
+    /// A `drop` expression discarded the event.
+    ///
+    /// Like an abort, this doesn't result in an `Ok(Value)` result, but it
+    /// isn't an error either, the event shouldn't be routed anywhere.
+    Drop,
+
+    /// The program ran past the timeout set on the runtime.
+    ///
+    /// Unlike an error, this can't be handled by the program itself, the
+    /// run is stopped at the first expression after the deadline.
+    Timeout,
 }
 
 impl fmt::Display for Terminate {
@@ -32,6 +49,8 @@ impl fmt::Display for Terminate {
         match self {
             Terminate::Abort(error) => error.fmt(f),
             Terminate::Error(error) => error.fmt(f),
+            Terminate::Drop => f.write_str("event dropped"),
+            Terminate::Timeout => f.write_str("program exceeded its timeout"),
         }
     }
 }
@@ -44,7 +63,29 @@ impl Error for Terminate {
 
 impl Runtime {
     pub fn new(state: state::Runtime) -> Self {
-        Self { state }
+        Self {
+            state,
+            timeout: None,
+        }
+    }
+
+    /// Bound the time each run of a program may take.
+    ///
+    /// A run that exceeds the timeout terminates with [`Terminate::Timeout`],
+    /// instead of holding up the events after it. The deadline is only checked
+    /// between expressions, so a single expensive function call can still
+    /// exceed it.
+    ///
+    /// Any program can time out, so callers setting a timeout should treat
+    /// the program as fallible, regardless of its [`ProgramInfo`].
+    ///
+    /// [`ProgramInfo`]: crate::ProgramInfo
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
     }
 
     pub fn is_empty(&self) -> bool {
@@ -55,6 +96,13 @@ impl Runtime {
         self.state.clear();
     }
 
+    /// The execution counts collected over all runs so far, if profiling is
+    /// enabled on the runtime state.
+    #[cfg(feature = "profiling")]
+    pub fn profile(&self) -> Option<&crate::Profile> {
+        self.state.profile()
+    }
+
     /// Given the provided [`Target`], resolve the provided [`Program`] to
     /// completion.
     pub fn resolve(
@@ -79,11 +127,22 @@ impl Runtime {
         };
 
         let mut ctx = Context::new(target, &mut self.state, timezone);
+        ctx.set_deadline(self.timeout.map(|timeout| Instant::now() + timeout));
 
-        program.resolve(&mut ctx).map_err(|err| match err {
+        let result = program.resolve(&mut ctx).map_err(|err| match err {
             #[cfg(feature = "expr-abort")]
             ExpressionError::Abort { .. } => Terminate::Abort(err),
             err @ ExpressionError::Error { .. } => Terminate::Error(err),
-        })
+        });
+
+        if ctx.is_timed_out() {
+            return Err(Terminate::Timeout);
+        }
+
+        if ctx.is_dropped() {
+            return Err(Terminate::Drop);
+        }
+
+        result
     }
 }
@@ -139,6 +139,8 @@ impl Conditional for Vrl {
                 .to_string();
                 format!("source execution failed: {}", err)
             }
+            vrl::Terminate::Drop => "source execution dropped the event".to_owned(),
+            vrl::Terminate::Timeout => "source execution timed out".to_owned(),
         });
 
         let value = match value_result {
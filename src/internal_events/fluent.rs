@@ -6,13 +6,28 @@ use vector_common::internal_event::{error_stage, error_type};
 
 #[derive(Debug)]
 pub struct FluentMessageReceived {
+    /// Same as `decoded_byte_size`.
+    ///
+    /// Deprecated, kept for the consumers of the `byte_size` field of the trace.
     pub byte_size: u64,
+    /// The on-wire size of the message, before decompression.
+    pub compressed_byte_size: u64,
+    /// The size of the message after decompression.
+    pub decoded_byte_size: u64,
 }
 
 impl InternalEvent for FluentMessageReceived {
     fn emit(self) {
-        trace!(message = "Received fluent message.", byte_size = %self.byte_size);
+        trace!(
+            message = "Received fluent message.",
+            byte_size = %self.byte_size,
+            deprecated = "The `byte_size` field is deprecated, use `decoded_byte_size` instead.",
+            compressed_byte_size = %self.compressed_byte_size,
+            decoded_byte_size = %self.decoded_byte_size,
+        );
         counter!("component_received_events_total", 1);
+        counter!("compressed_bytes_total", self.compressed_byte_size);
+        counter!("decoded_bytes_total", self.decoded_byte_size);
         counter!("events_in_total", 1);
     }
 }
@@ -20,7 +35,19 @@ impl InternalEvent for FluentMessageReceived {
 #[derive(Debug)]
 pub struct FluentMessageDecodeError<'a> {
     pub error: &'a DecodeError,
-    pub base64_encoded_message: String,
+    /// The truncated payload, only set if payloads should be logged.
+    pub base64_encoded_message: Option<String>,
+}
+
+impl<'a> FluentMessageDecodeError<'a> {
+    fn error_type(&self) -> &'static str {
+        match self.error {
+            DecodeError::IO(_) => error_type::IO_FAILED,
+            DecodeError::Decode(_) => error_type::PARSER_FAILED,
+            DecodeError::UnknownCompression(_) => "unknown_compression",
+            DecodeError::UnexpectedValue(_) => "unexpected_value",
+        }
+    }
 }
 
 impl<'a> InternalEvent for FluentMessageDecodeError<'a> {
@@ -28,17 +55,40 @@ impl<'a> InternalEvent for FluentMessageDecodeError<'a> {
         error!(
             message = "Error decoding fluent message.",
             error = ?self.error,
-            base64_encoded_message = %self.base64_encoded_message,
+            base64_encoded_message = self.base64_encoded_message.as_deref(),
             internal_log_rate_limit = true,
-            error_type = error_type::PARSER_FAILED,
+            error_type = self.error_type(),
             stage = error_stage::PROCESSING,
         );
         counter!(
             "component_errors_total", 1,
-            "error_type" => error_type::PARSER_FAILED,
+            "error_type" => self.error_type(),
             "stage" => error_stage::PROCESSING,
         );
         // deprecated
         counter!("decode_errors_total", 1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tracing_test::traced_test;
+
+    use super::*;
+
+    #[traced_test]
+    #[test]
+    fn message_received_marks_byte_size_deprecated() {
+        FluentMessageReceived {
+            byte_size: 10,
+            compressed_byte_size: 4,
+            decoded_byte_size: 10,
+        }
+        .emit();
+
+        assert!(logs_contain("byte_size=10"));
+        assert!(logs_contain(
+            "The `byte_size` field is deprecated, use `decoded_byte_size` instead."
+        ));
+    }
+}
@@ -52,6 +52,20 @@ pub struct FluentConfig {
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: AcknowledgementsConfig,
+
+    /// Whether to include the base64-encoded payload of messages that fail to decode in the error log.
+    #[serde(default)]
+    log_decode_error_payload: bool,
+
+    /// The maximum number of characters of the payload included in the error log.
+    ///
+    /// Longer payloads are truncated.
+    #[serde(default = "default_decode_error_payload_length")]
+    decode_error_payload_length: usize,
+}
+
+const fn default_decode_error_payload_length() -> usize {
+    128
 }
 
 impl GenerateConfig for FluentConfig {
@@ -63,6 +77,8 @@ impl GenerateConfig for FluentConfig {
             receive_buffer_bytes: None,
             acknowledgements: Default::default(),
             connection_limit: Some(2),
+            log_decode_error_payload: false,
+            decode_error_payload_length: default_decode_error_payload_length(),
         })
         .unwrap()
     }
@@ -71,7 +87,11 @@ impl GenerateConfig for FluentConfig {
 #[async_trait::async_trait]
 impl SourceConfig for FluentConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
-        let source = FluentSource {};
+        let source = FluentSource {
+            decode_error_payload_length: self
+                .log_decode_error_payload
+                .then_some(self.decode_error_payload_length),
+        };
         let shutdown_secs = 30;
         let tls_config = self.tls.as_ref().map(|tls| tls.tls_config.clone());
         let tls_client_metadata_key = self
@@ -106,7 +126,9 @@ impl SourceConfig for FluentConfig {
 }
 
 #[derive(Debug, Clone)]
-struct FluentSource;
+struct FluentSource {
+    decode_error_payload_length: Option<usize>,
+}
 
 impl TcpSource for FluentSource {
     type Error = DecodeError;
@@ -115,7 +137,7 @@ impl TcpSource for FluentSource {
     type Acker = FluentAcker;
 
     fn decoder(&self) -> Self::Decoder {
-        FluentDecoder::new()
+        FluentDecoder::new(self.decode_error_payload_length)
     }
 
     fn handle_events(&self, events: &mut [Event], host: SocketAddr) {
@@ -180,11 +202,17 @@ impl From<decode::Error> for DecodeError {
 }
 
 #[derive(Debug)]
-struct FluentDecoder;
+struct FluentDecoder {
+    /// The maximum length of the payload logged on decode errors, if it's
+    /// logged at all.
+    decode_error_payload_length: Option<usize>,
+}
 
 impl FluentDecoder {
-    const fn new() -> Self {
-        FluentDecoder
+    const fn new(decode_error_payload_length: Option<usize>) -> Self {
+        FluentDecoder {
+            decode_error_payload_length,
+        }
     }
 
     fn handle_message(
@@ -254,10 +282,9 @@ impl FluentDecoder {
             FluentMessage::PackedForward(tag, bin) => {
                 let mut buf = BytesMut::from(&bin[..]);
 
+                let mut decoder = FluentEntryStreamDecoder::default();
                 let mut events = smallvec![];
-                while let Some(FluentEntry(timestamp, record)) =
-                    FluentEntryStreamDecoder.decode(&mut buf)?
-                {
+                while let Some(FluentEntry(timestamp, record)) = decoder.decode(&mut buf)? {
                     events.push(Event::from(FluentEvent {
                         tag: tag.clone(),
                         timestamp,
@@ -271,8 +298,10 @@ impl FluentDecoder {
                 Ok(Some((frame, byte_size)))
             }
             FluentMessage::PackedForwardWithOptions(tag, bin, options) => {
+                let mut decoder = FluentEntryStreamDecoder::default();
                 let buf = match options.compressed.as_deref() {
                     Some("gzip") => {
+                        decoder = FluentEntryStreamDecoder::compressed(bin.len() as u64);
                         let mut buf = Vec::new();
                         MultiGzDecoder::new(io::Cursor::new(bin.into_vec()))
                             .read_to_end(&mut buf)
@@ -286,9 +315,7 @@ impl FluentDecoder {
                 let mut buf = BytesMut::from(&buf[..]);
 
                 let mut events = smallvec![];
-                while let Some(FluentEntry(timestamp, record)) =
-                    FluentEntryStreamDecoder.decode(&mut buf)?
-                {
+                while let Some(FluentEntry(timestamp, record)) = decoder.decode(&mut buf)? {
                     events.push(Event::from(FluentEvent {
                         tag: tag.clone(),
                         timestamp,
@@ -339,7 +366,9 @@ impl Decoder for FluentDecoder {
             src.advance(byte_size);
 
             let maybe_item = self.handle_message(res, byte_size).map_err(|error| {
-                let base64_encoded_message = base64::encode(&src);
+                let base64_encoded_message = self
+                    .decode_error_payload_length
+                    .map(|max_length| encode_payload(&src[..], max_length));
                 emit!(FluentMessageDecodeError {
                     error: &error,
                     base64_encoded_message
@@ -353,9 +382,38 @@ impl Decoder for FluentDecoder {
     }
 }
 
+/// Base64-encodes `payload`, truncated to `max_length` characters followed by an
+/// ellipsis.
+fn encode_payload(payload: &[u8], max_length: usize) -> String {
+    // Only encode the bytes needed for `max_length` characters, plus one to
+    // tell whether the payload was truncated.
+    let needed = max_length / 4 * 3 + 4;
+    let mut encoded = base64::encode(&payload[..payload.len().min(needed)]);
+
+    if encoded.len() > max_length {
+        encoded.truncate(max_length);
+        encoded.push_str("...");
+    }
+    encoded
+}
+
 /// Decoder for decoding MessagePackEventStream which are just a stream of Entries
-#[derive(Clone, Debug)]
-struct FluentEntryStreamDecoder;
+#[derive(Clone, Debug, Default)]
+struct FluentEntryStreamDecoder {
+    /// The on-wire size of the compressed stream not yet attributed to an entry.
+    ///
+    /// Entries can't be mapped to a part of the compressed stream, so the whole
+    /// compressed size is reported with the first entry.
+    compressed_byte_size: Option<u64>,
+}
+
+impl FluentEntryStreamDecoder {
+    const fn compressed(compressed_byte_size: u64) -> Self {
+        Self {
+            compressed_byte_size: Some(compressed_byte_size),
+        }
+    }
+}
 
 impl Decoder for FluentEntryStreamDecoder {
     type Item = FluentEntry;
@@ -378,8 +436,16 @@ impl Decoder for FluentEntryStreamDecoder {
             }
 
             let byte_size = des.position();
+            let compressed_byte_size = match self.compressed_byte_size.as_mut() {
+                Some(size) => std::mem::take(size),
+                None => byte_size,
+            };
 
-            emit!(FluentMessageReceived { byte_size });
+            emit!(FluentMessageReceived {
+                byte_size,
+                compressed_byte_size,
+                decoded_byte_size: byte_size,
+            });
 
             (byte_size as usize, res)
         };
@@ -482,7 +548,11 @@ mod tests {
     use crate::{
         config::{SourceConfig, SourceContext},
         event::EventStatus,
-        test_util::{self, next_addr, trace_init, wait_for_tcp},
+        test_util::{
+            self,
+            components::{assert_source_compliance, SOCKET_PUSH_SOURCE_TAGS},
+            next_addr, trace_init, wait_for_tcp,
+        },
         SourceSender,
     };
 
@@ -820,10 +890,18 @@ mod tests {
         assert_event_data_eq!(got.0[2], expected[2]);
     }
 
+    #[test]
+    fn encode_payload_truncates() {
+        assert_eq!(encode_payload(b"foo", 8), "Zm9v");
+        assert_eq!(encode_payload(b"foobar", 8), "Zm9vYmFy");
+        assert_eq!(encode_payload(b"foobarbaz", 8), "Zm9vYmFy...");
+        assert_eq!(encode_payload(&[0; 1024], 6), "AAAAAA...");
+    }
+
     fn decode_all(message: Vec<u8>) -> Result<(SmallVec<[Event; 1]>, usize), DecodeError> {
         let mut buf = BytesMut::from(&message[..]);
 
-        let mut decoder = FluentDecoder::new();
+        let mut decoder = FluentDecoder::new(None);
 
         let (frame, byte_size) = decoder.decode(&mut buf)?.unwrap();
         Ok((frame.into(), byte_size))
@@ -857,6 +935,16 @@ mod tests {
         assert_eq!(output, &b"{}"[..]);
     }
 
+    #[tokio::test]
+    async fn emits_component_metrics() {
+        // `component_received_event_bytes_total` is emitted by the TCP source
+        // for every decoded message, along with the other required metrics.
+        assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
+            check_acknowledgements(EventStatus::Delivered, true).await;
+        })
+        .await;
+    }
+
     async fn check_acknowledgements(
         status: EventStatus,
         with_chunk: bool,
@@ -872,6 +960,8 @@ mod tests {
             receive_buffer_bytes: None,
             acknowledgements: true.into(),
             connection_limit: None,
+            log_decode_error_payload: false,
+            decode_error_payload_length: default_decode_error_payload_length(),
         }
         .build(SourceContext::new_test(sender, None))
         .await
@@ -1121,6 +1211,8 @@ mod integration_tests {
                 receive_buffer_bytes: None,
                 acknowledgements: false.into(),
                 connection_limit: None,
+                log_decode_error_payload: false,
+                decode_error_payload_length: default_decode_error_payload_length(),
             }
             .build(SourceContext::new_test(sender, None))
             .await
@@ -4,6 +4,7 @@ use std::{
     fs::File,
     io::{self, Read},
     path::PathBuf,
+    time::Duration,
 };
 
 use lookup::lookup_v2::ValuePath;
@@ -108,6 +109,14 @@ pub struct RemapConfig {
     /// fields describing why the event was dropped.
     pub reroute_dropped: bool,
 
+    /// The maximum time, in milliseconds, the VRL program may run for each event.
+    ///
+    /// An event whose program runs past the timeout is treated as if the program failed with an
+    /// error: it's subject to `drop_on_error`, and the error can't be handled from within the
+    /// program. The timeout is only checked between expressions, so a single expensive function
+    /// call can still run past it.
+    pub timeout_ms: Option<u64>,
+
     #[configurable(derived)]
     #[serde(default)]
     pub runtime: VrlRuntime,
@@ -251,6 +260,7 @@ impl TransformConfig for RemapConfig {
                 Kind::object(BTreeMap::from([
                     ("reason".into(), Kind::bytes()),
                     ("message".into(), Kind::bytes()),
+                    ("code".into(), Kind::integer()),
                     ("component_id".into(), Kind::bytes()),
                     ("component_type".into(), Kind::bytes()),
                     ("component_kind".into(), Kind::bytes()),
@@ -268,6 +278,7 @@ impl TransformConfig for RemapConfig {
                     .clone()
                     .with_metadata_field("reason", Kind::bytes())
                     .with_metadata_field("message", Kind::bytes())
+                    .with_metadata_field("code", Kind::integer())
                     .with_metadata_field("component_id", Kind::bytes())
                     .with_metadata_field("component_type", Kind::bytes())
                     .with_metadata_field("component_kind", Kind::bytes()),
@@ -302,6 +313,7 @@ where
     component_key: Option<ComponentKey>,
     program: Program,
     timezone: TimeZone,
+    fallible: bool,
     drop_on_error: bool,
     drop_on_abort: bool,
     reroute_dropped: bool,
@@ -326,9 +338,10 @@ pub struct AstRunner {
 
 impl Clone for AstRunner {
     fn clone(&self) -> Self {
-        Self {
-            runtime: Runtime::default(),
-        }
+        let mut runtime = Runtime::default();
+        runtime.set_timeout(self.runtime.timeout());
+
+        Self { runtime }
     }
 }
 
@@ -355,7 +368,8 @@ impl Remap<AstRunner> {
             context.merged_schema_definition.clone(),
         )?;
 
-        let runtime = Runtime::default();
+        let mut runtime = Runtime::default();
+        runtime.set_timeout(config.timeout_ms.map(Duration::from_millis));
         let runner = AstRunner { runtime };
 
         Self::new(config, context, program, runner).map(|remap| (remap, warnings))
@@ -385,10 +399,15 @@ where
             .expect("dropped schema required")
             .clone();
 
+        // Any program can run past its timeout, regardless of whether the compiler considers it
+        // fallible.
+        let fallible = program.info().fallible || config.timeout_ms.is_some();
+
         Ok(Remap {
             component_key: context.key.clone(),
             program,
             timezone: config.timezone,
+            fallible,
             drop_on_error: config.drop_on_error,
             drop_on_abort: config.drop_on_abort,
             reroute_dropped: config.reroute_dropped,
@@ -414,6 +433,7 @@ where
         serde_json::json!({
                 "reason": reason,
                 "message": message,
+                "code": error.code(),
                 "component_id": self.component_key,
                 "component_type": "remap",
                 "component_kind": "transform",
@@ -483,7 +503,7 @@ where
         // the event to the `dropped` output.
         let forward_on_error = !self.drop_on_error || self.reroute_dropped;
         let forward_on_abort = !self.drop_on_abort || self.reroute_dropped;
-        let original_event = if (self.program.info().fallible && forward_on_error)
+        let original_event = if (self.fallible && forward_on_error)
             || (self.program.info().abortable && forward_on_abort)
         {
             Some(event.clone())
@@ -521,6 +541,19 @@ where
 
                         ("error", error, self.drop_on_error)
                     }
+                    Terminate::Timeout => {
+                        let error = ExpressionError::from(Terminate::Timeout.to_string());
+
+                        emit!(RemapMappingError {
+                            error: error.to_string(),
+                            event_dropped: self.drop_on_error,
+                        });
+
+                        ("error", error, self.drop_on_error)
+                    }
+                    // The program discarded the event on purpose, it isn't
+                    // forwarded to any output.
+                    Terminate::Drop => return,
                 };
 
                 if !drop {
@@ -811,6 +844,112 @@ mod tests {
         assert!(event.as_log().get("baz").is_none());
     }
 
+    #[test]
+    fn check_remap_strict_destructure_error() {
+        let event = {
+            let mut event = Event::Log(LogEvent::from("augment me"));
+            event.as_mut_log().insert("bar", "is a string");
+            event
+        };
+
+        let conf = RemapConfig {
+            source: Some(formatdoc! {r#"
+                .foo = "foo"
+                {bar, baz}! = .
+                .baz = baz
+            "#}),
+            file: None,
+            timezone: TimeZone::default(),
+            drop_on_error: false,
+            drop_on_abort: false,
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let event = transform_one(&mut tform, event).unwrap();
+
+        assert_eq!(event.as_log().get("bar"), Some(&Value::from("is a string")));
+        assert!(event.as_log().get("foo").is_none());
+        assert!(event.as_log().get("baz").is_none());
+    }
+
+    #[test]
+    fn check_remap_return_inside_assignment() {
+        let event = {
+            let mut event = Event::Log(LogEvent::from("augment me"));
+            event.as_mut_log().insert("bar", "is a string");
+            event
+        };
+
+        let conf = RemapConfig {
+            source: Some(formatdoc! {r#"
+                .foo = if .bar == "is a string" { return 0 } else { 1 }
+                .baz = 12
+            "#}),
+            drop_on_error: false,
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let event = transform_one(&mut tform, event).unwrap();
+
+        assert_eq!(event.as_log().get("bar"), Some(&Value::from("is a string")));
+        assert!(event.as_log().get("foo").is_none());
+        assert!(event.as_log().get("baz").is_none());
+    }
+
+    #[test]
+    fn check_remap_timeout() {
+        let event = {
+            let mut event = Event::Log(LogEvent::from("augment me"));
+            event.as_mut_log().insert("bar", "is a string");
+            event
+        };
+
+        let conf = RemapConfig {
+            source: Some(formatdoc! {r#"
+                .foo = "foo"
+                .baz = 12
+            "#}),
+            drop_on_error: false,
+            timeout_ms: Some(0),
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let event = transform_one(&mut tform, event).unwrap();
+
+        assert_eq!(event.as_log().get("bar"), Some(&Value::from("is a string")));
+        assert!(event.as_log().get("foo").is_none());
+        assert!(event.as_log().get("baz").is_none());
+    }
+
+    #[test]
+    fn check_remap_timeout_inside_closure() {
+        let event = {
+            let mut event = Event::Log(LogEvent::from("augment me"));
+            event.as_mut_log().insert("bar", "is a string");
+            event
+        };
+
+        let conf = RemapConfig {
+            source: Some(formatdoc! {r#"
+                .foo = filter([1, 2, 3]) -> |_index, value| {
+                    value > 1
+                }
+            "#}),
+            drop_on_error: false,
+            timeout_ms: Some(0),
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let event = transform_one(&mut tform, event).unwrap();
+
+        assert_eq!(event.as_log().get("bar"), Some(&Value::from("is a string")));
+        assert!(event.as_log().get("foo").is_none());
+    }
+
     #[test]
     fn check_remap_error_drop() {
         let event = {
@@ -1052,6 +1191,7 @@ mod tests {
                 "dropped": {
                     "reason": "abort",
                     "message": "aborted",
+                    "code": 0,
                     "component_id": "remapper",
                     "component_type": "remap",
                     "component_kind": "transform",
@@ -1071,6 +1211,7 @@ mod tests {
                 "dropped": {
                     "reason": "error",
                     "message": "function call error for \"string\" at (160:174): expected string, got integer",
+                    "code": 300,
                     "component_id": "remapper",
                     "component_type": "remap",
                     "component_kind": "transform",
@@ -1180,6 +1321,7 @@ mod tests {
                 "dropped": {
                     "reason": "error",
                     "message": "custom message here",
+                    "code": 0,
                     "component_id": "remapper",
                     "component_type": "remap",
                     "component_kind": "transform",
@@ -1200,6 +1342,7 @@ mod tests {
                 "dropped": {
                     "reason": "error",
                     "message": "function call error for \"assert_eq\" at (45:66): assertion failed: 0 == 1",
+                    "code": 0,
                     "component_id": "remapper",
                     "component_type": "remap",
                     "component_kind": "transform",
@@ -1238,6 +1381,7 @@ mod tests {
                 "dropped": {
                     "reason": "abort",
                     "message": "custom message here",
+                    "code": 0,
                     "component_id": "remapper",
                     "component_type": "remap",
                     "component_kind": "transform",
@@ -69,6 +69,21 @@ components: sources: fluent: {
 				unit:    "concurrency"
 			}
 		}
+		decode_error_payload_length: {
+			common:      false
+			description: "The maximum number of characters of the payload included in the error log when `log_decode_error_payload` is enabled. Longer payloads are truncated."
+			required:    false
+			type: uint: {
+				default: 128
+				unit:    null
+			}
+		}
+		log_decode_error_payload: {
+			common:      false
+			description: "Whether to include the base64-encoded payload of messages that fail to decode in the error log."
+			required:    false
+			type: bool: default: false
+		}
 	}
 
 	output: logs: line: {
@@ -195,11 +210,14 @@ components: sources: fluent: {
 	}
 
 	telemetry: metrics: {
-		events_in_total:                 components.sources.internal_metrics.output.metrics.events_in_total
-		decode_errors_total:             components.sources.internal_metrics.output.metrics.decode_errors_total
-		processed_bytes_total:           components.sources.internal_metrics.output.metrics.processed_bytes_total
-		processed_events_total:          components.sources.internal_metrics.output.metrics.processed_events_total
-		component_received_bytes_total:  components.sources.internal_metrics.output.metrics.component_received_bytes_total
-		component_received_events_total: components.sources.internal_metrics.output.metrics.component_received_events_total
+		events_in_total:                      components.sources.internal_metrics.output.metrics.events_in_total
+		compressed_bytes_total:               components.sources.internal_metrics.output.metrics.compressed_bytes_total
+		decoded_bytes_total:                  components.sources.internal_metrics.output.metrics.decoded_bytes_total
+		decode_errors_total:                  components.sources.internal_metrics.output.metrics.decode_errors_total
+		processed_bytes_total:                components.sources.internal_metrics.output.metrics.processed_bytes_total
+		processed_events_total:               components.sources.internal_metrics.output.metrics.processed_events_total
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
 	}
 }
@@ -331,6 +331,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		compressed_bytes_total: {
+			description:       "The total number of bytes received by this component before decompression."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		decoded_bytes_total: {
+			description:       "The total number of bytes received by this component after decompression."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		decode_errors_total: {
 			description:       "The total number of decode errors seen when decoding data in a source component."
 			type:              "counter"
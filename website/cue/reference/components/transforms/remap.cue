@@ -104,6 +104,21 @@ components: transforms: "remap": {
 				"""
 			type: bool: default: false
 		}
+		timeout_ms: {
+			common:   false
+			required: false
+			description: """
+				The maximum time the program may run for each event. An event whose program
+				runs past the timeout is handled as if the program failed with an error,
+				according to `drop_on_error`. The error can't be handled from within the
+				program.
+				"""
+			type: uint: {
+				default: null
+				unit:    "milliseconds"
+				examples: [100]
+			}
+		}
 	}
 
 	input: {
@@ -221,6 +236,10 @@ components: transforms: "remap": {
 				output can be accessed by specifying `foo.dropped` as the input to another
 				component. Events sent to this output will be in their original form,
 				omitting any partial modification that took place before the error or abort.
+
+				The `dropped` metadata added to these events includes the `code` of the runtime
+				error, such as `300` for an unexpected type, so that failures can be routed on
+				their kind. Errors without a code, and aborts, have a `code` of `0`.
 				"""
 		},
 	]
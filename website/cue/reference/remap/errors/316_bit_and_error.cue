@@ -0,0 +1,6 @@
+package metadata
+
+remap: errors: "316": {
+	title:       "Bitwise AND error"
+	description: "These two values can't be combined with a bitwise AND."
+}
@@ -0,0 +1,6 @@
+package metadata
+
+remap: errors: "317": {
+	title:       "Bitwise OR error"
+	description: "These two values can't be combined with a bitwise OR."
+}
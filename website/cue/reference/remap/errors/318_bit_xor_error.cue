@@ -0,0 +1,6 @@
+package metadata
+
+remap: errors: "318": {
+	title:       "Bitwise XOR error"
+	description: "These two values can't be combined with a bitwise XOR."
+}
@@ -0,0 +1,6 @@
+package metadata
+
+remap: errors: "319": {
+	title:       "Shift error"
+	description: "This value can't be shifted by the other value."
+}
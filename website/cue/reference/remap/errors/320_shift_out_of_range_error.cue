@@ -0,0 +1,6 @@
+package metadata
+
+remap: errors: "320": {
+	title:       "Shift out of range error"
+	description: "Integers can only be shifted by 0 to 63 bits."
+}
@@ -18,11 +18,11 @@ remap: errors: "631": {
 		{
 			"title": "\(title)"
 			source: #"""
-				abort to_syslog_level(0)
+				abort to_syslog_level(8)
 				"""#
 			diff: #"""
-				- abort to_syslog_level(0)
-				+ abort to_syslog_level(0) ?? "other"
+				- abort to_syslog_level(8)
+				+ abort to_syslog_level(8) ?? "other"
 				"""#
 		},
 	]
@@ -0,0 +1,34 @@
+package metadata
+
+remap: errors: "642": {
+	title: "Parent path segment rejects this mutation"
+
+	description: """
+		You've assigned a value to a field or index of a value that is known to not be an object or array.
+		"""
+
+	rationale: """
+		A field can only be assigned to an object, and an index can only be assigned to an array. A parent that
+		doesn't exist yet is created by the assignment, so this error is only raised when the type of the parent
+		is known to be something else.
+		"""
+
+	resolution: """
+		Change the parent value to an object or array before assigning to one of its fields or indices.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				.foo = "bar"
+				.foo.bar = "baz"
+				"""#
+			diff: #"""
+				-.foo = "bar"
+				+.foo = {}
+				 .foo.bar = "baz"
+				"""#
+		},
+	]
+}
@@ -0,0 +1,18 @@
+package metadata
+
+remap: errors: "653": {
+	title: "Bitwise operators only apply to integers"
+	description: """
+		You're attempting to apply a bitwise operator to a value that isn't an integer.
+		"""
+	rationale:  """
+		The bitwise operators (`&`, `|`, `^`, `<<`, and `>>`) operate on the individual bits of
+		an integer. It's not clear what it would mean to apply them to, for example, a string or
+		a float.
+		"""
+	resolution: """
+		Make sure that both values are VRL integers. If you're not sure whether a value is an
+		integer, you can use the [`int`](\(urls.vrl_functions)#int) function to check, or the
+		[`to_int`](\(urls.vrl_functions)#to_int) function to convert it.
+		"""
+}
@@ -0,0 +1,36 @@
+package metadata
+
+remap: errors: "670": {
+	title: "Non-literal match pattern"
+
+	description: """
+		You've used an expression other than a literal value as the pattern of a match arm.
+		"""
+
+	rationale: """
+		Match patterns are compared against the matched value at runtime, and must be known at compile-time.
+		"""
+
+	resolution: """
+		Use a literal value or the wildcard pattern (`_`) as the pattern, or use an `if` expression instead.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				match .status {
+					"{{ .expected }}" => true,
+					_ => false,
+				}
+				"""#
+			diff: #"""
+				-	match .status {
+				-		"{{ .expected }}" => true,
+				-		_ => false,
+				-	}
+				+#	.status == .expected
+				"""#
+		},
+	]
+}
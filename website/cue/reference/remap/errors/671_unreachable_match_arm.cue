@@ -0,0 +1,36 @@
+package metadata
+
+remap: errors: "671": {
+	title: "Unreachable match arm"
+
+	description: """
+		You've added a match arm after the wildcard pattern (`_`).
+		"""
+
+	rationale: """
+		The wildcard pattern matches any value, so arms following it are never executed.
+		"""
+
+	resolution: """
+		Move the wildcard arm to the end of the match expression.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				match .level {
+					_ => 7,
+					"warn" => 4,
+				}
+				"""#
+			diff: #"""
+				 	match .level {
+				-		_ => 7,
+				 		"warn" => 4,
+				+		_ => 7,
+				 	}
+				"""#
+		},
+	]
+}
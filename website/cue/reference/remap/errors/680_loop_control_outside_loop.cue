@@ -0,0 +1,33 @@
+package metadata
+
+remap: errors: "680": {
+	title: "Loop control outside of a loop"
+
+	description: """
+		You've used `break` or `continue` outside of the closure of the `for_each` function.
+		"""
+
+	rationale: """
+		Loop control expressions only have meaning within a loop, there's nothing to stop or skip otherwise.
+		"""
+
+	resolution: """
+		Remove the expression, or move it into the closure of a `for_each` function call.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				if .done == true {
+					break
+				}
+				"""#
+			diff: #"""
+				-if .done == true {
+				-	break
+				-}
+				"""#
+		},
+	]
+}
@@ -0,0 +1,40 @@
+package metadata
+
+remap: errors: "681": {
+	title: "Return inside a closure"
+
+	description: """
+		You've used `return` within the closure of a function, such as `for_each`.
+		"""
+
+	rationale: """
+		A closure is called by its function, so it can't stop the program that called the function.
+		"""
+
+	resolution: """
+		Use `break` to stop a `for_each` loop early, or move the `return` expression out of the closure.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				for_each(.values) -> |_index, value| {
+					if value == null {
+						return false
+					}
+				}
+				"""#
+			diff: #"""
+				-for_each(.values) -> |_index, value| {
+				-	if value == null {
+				-		return false
+				-	}
+				-}
+				+if includes(array!(.values), null) {
+				+	return false
+				+}
+				"""#
+		},
+	]
+}
@@ -0,0 +1,18 @@
+package metadata
+
+remap: errors: "690": {
+	title: "Unnecessary try expression"
+	description: """
+		You've used a `try` expression, but its block is infallible, and so the `catch` block is never
+		reached.
+		"""
+	rationale: """
+		Try expressions are useful when you want to specify what happens if an operation fails. If the
+		operations in the `try` block can't fail, the `catch` block is dead code.
+		"""
+	resolution: """
+		If the `try` block is meant to be infallible, replace the `try` expression with its block. If,
+		however, the block is supposed to be fallible, remove the `!` from the function call and anything
+		else that's making it infallible.
+		"""
+}
@@ -0,0 +1,33 @@
+package metadata
+
+remap: errors: "702": {
+	title: "Unused variable"
+	description: """
+		A variable is assigned a value, but the variable is never read. This is a warning, the program still compiles
+		and runs.
+		"""
+
+	rationale: """
+		Assigning to a variable that is never read is likely due to a typo, or a leftover from a previous change.
+		"""
+
+	resolution: """
+		Read the variable, remove the assignment, or prefix the variable name with an underscore (`_`) if the
+		assignment is intentional.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				parsed = parse_json!(.message)
+				.message = "done"
+				"""#
+			diff: #"""
+				-parsed = parse_json!(.message)
+				+_parsed = parse_json!(.message)
+				 .message = "done"
+				"""#
+		},
+	]
+}
@@ -0,0 +1,35 @@
+package metadata
+
+remap: errors: "703": {
+	title: "Unreachable branch"
+	description: """
+		The predicate of an if expression always resolves to the same value, so one of its branches never runs.
+		This is a warning, the program still compiles and runs.
+		"""
+
+	rationale: """
+		A predicate that doesn't depend on the event is likely a leftover from debugging, or a mistake in the
+		condition.
+		"""
+
+	resolution: """
+		Remove the unreachable branch along with the if expression, or change the predicate so that it depends on
+		the event.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				if false {
+					.message = "debug"
+				}
+				"""#
+			diff: #"""
+				-if false {
+				-	.message = "debug"
+				-}
+				"""#
+		},
+	]
+}
@@ -0,0 +1,32 @@
+package metadata
+
+remap: errors: "704": {
+	title: "Unreachable abort"
+	description: """
+		An [abort expression](\(urls.vrl_expressions)#abort) follows an expression that never completes, such as
+		another `abort` or a `return`, so it never runs. This is a warning, the program still compiles and runs.
+		"""
+
+	rationale: """
+		An abort that can't run is likely a leftover from debugging, or a sign that the expression before it
+		doesn't behave as intended.
+		"""
+
+	resolution: """
+		Remove the unreachable abort, or move it before the expression that never completes.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				abort "invalid event"
+				abort "unreachable"
+				"""#
+			diff: #"""
+				 abort "invalid event"
+				-abort "unreachable"
+				"""#
+		},
+	]
+}
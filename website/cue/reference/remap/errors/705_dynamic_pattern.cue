@@ -0,0 +1,31 @@
+package metadata
+
+remap: errors: "705": {
+	title: "Dynamic pattern"
+	description: """
+		A function that compiles a pattern, such as [`parse_grok`](\(urls.vrl_functions)#parse_grok), is called with
+		a pattern that isn't a literal. This is a warning, the program still compiles and runs.
+		"""
+
+	rationale: """
+		A literal pattern is compiled once, when the program is compiled. A pattern that is only known at runtime has
+		to be compiled again each time the function is called, which is expensive when processing many events.
+		"""
+
+	resolution: """
+		Use a literal pattern, or a variable assigned a literal pattern.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				parse_grok!(.message, string!(.pattern))
+				"""#
+			diff: #"""
+				-parse_grok!(.message, string!(.pattern))
+				+parse_grok!(.message, "%{TIMESTAMP_ISO8601:timestamp} %{LOGLEVEL:level} %{GREEDYDATA:message}")
+				"""#
+		},
+	]
+}
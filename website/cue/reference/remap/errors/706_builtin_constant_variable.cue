@@ -0,0 +1,31 @@
+package metadata
+
+remap: errors: "706": {
+	title: "Variable name is a built-in constant"
+	description: """
+		You've used one of the built-in constants `null`, `true`, or `false` as the name of a variable, either as the
+		target of an assignment or as a closure variable.
+		"""
+
+	rationale: """
+		Built-in constants always refer to the same value. Allowing them to be rebound would make it impossible to tell
+		what value they refer to in the rest of the program.
+		"""
+
+	resolution: """
+		Use a different variable name.
+		"""
+
+	examples: [
+		{
+			"title": "\(title)"
+			source: #"""
+				true = .enabled
+				"""#
+			diff: #"""
+				-true = .enabled
+				+enabled = .enabled
+				"""#
+		},
+	]
+}
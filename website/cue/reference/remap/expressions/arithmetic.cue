@@ -26,12 +26,17 @@ remap: expressions: arithmetic: {
 					The `operator` defines the operation performed on the left-hand- and right-hand-side operands.
 					"""
 				enum: {
-					"+":  "Sum. Operates on `int`, `float`, and `string` types."
-					"-":  "Difference. Operates on `int` and `float` types."
+					"+":  "Sum. Operates on `int`, `float`, and `string` types, and shifts a `timestamp` by an `int` or `float` number of seconds."
+					"-":  "Difference. Operates on `int` and `float` types, and shifts a `timestamp` back by an `int` or `float` number of seconds. The difference of two `timestamp` types is a `float` number of seconds."
 					"*":  "Multiplication. Operates on `int` and `float` types."
 					"/":  "Float division. Operates on `int` and `float` types. _Always_ produces a `float`."
 					"//": "Integer division. Operates on `int` and `float` types. _Always_ produces a `int`."
 					"%":  "Remainder. Operates on `int` and `float` types. _Always_ produces an `int`."
+					"&":  "Bitwise AND. Operates on `int` types."
+					"|":  "Bitwise OR. Operates on `int` types. Between two objects, `|` merges them instead."
+					"^":  "Bitwise XOR. Operates on `int` types."
+					"<<": "Left shift. Operates on `int` types, the right-hand side must be between `0` and `63`."
+					">>": "Arithmetic right shift. Operates on `int` types, the right-hand side must be between `0` and `63`."
 				}
 			}
 		}
@@ -87,6 +92,20 @@ remap: expressions: arithmetic: {
 				"""#
 			return: 1.0
 		},
+		{
+			title: "Sum (timestamp)"
+			source: #"""
+				t'2021-02-11T10:32:50Z' + 5m
+				"""#
+			return: "2021-02-11T10:37:50Z"
+		},
+		{
+			title: "Difference (timestamp)"
+			source: #"""
+				t'2021-02-11T10:32:50Z' - t'2021-02-11T10:30:00Z'
+				"""#
+			return: 170.0
+		},
 		{
 			title: "Multiplication (int)"
 			source: #"""
@@ -129,6 +148,41 @@ remap: expressions: arithmetic: {
 				"""#
 			return: 2.0
 		},
+		{
+			title: "Bitwise AND"
+			source: #"""
+				6 & 3
+				"""#
+			return: 2
+		},
+		{
+			title: "Bitwise OR"
+			source: #"""
+				6 | 3
+				"""#
+			return: 7
+		},
+		{
+			title: "Bitwise XOR"
+			source: #"""
+				6 ^ 3
+				"""#
+			return: 5
+		},
+		{
+			title: "Left shift"
+			source: #"""
+				5 << 3
+				"""#
+			return: 40
+		},
+		{
+			title: "Right shift"
+			source: #"""
+				40 >> 3
+				"""#
+			return: 5
+		},
 		{
 			title: "Remainder"
 			source: #"""
@@ -13,7 +13,7 @@ remap: expressions: assignment: {
 
 	grammar: {
 		source: """
-			target ~ ("," ~ error)? ~ operator ~ expression
+			(target ~ ("," ~ error)? | pattern ~ "!"?) ~ operator ~ expression
 			"""
 		definitions: {
 			target: {
@@ -28,6 +28,18 @@ remap: expressions: assignment: {
 					fallible. This is commonly used when invoking fallible functions.
 					"""
 			}
+			pattern: {
+				description: """
+					The `pattern` destructures the right-hand side into variables. An object pattern, such as
+					`{a, b}`, assigns each field to the variable of the same name. An array pattern, such as
+					`[a, _, c]`, assigns each element to the variable at the same position, and skips elements
+					assigned to `_`.
+
+					Fields or elements that don't exist are assigned `null`. With a trailing `!`, such as
+					`{a, b}! = ...`, they result in a runtime error instead. Only the `=` operator can be used
+					with a pattern.
+					"""
+			}
 			operator: {
 				description: """
 					The `operator` delimits the `target` and `expression` and defines assignment conditions.
@@ -123,6 +135,22 @@ remap: expressions: assignment: {
 				"""#
 			return: {"level": "info", "message": "Hello, World!"}
 		},
+		{
+			title: "Object destructuring assignment"
+			source: #"""
+				{level, message} = {"level": "info", "message": "Hello, World!"}
+				message
+				"""#
+			return: "Hello, World!"
+		},
+		{
+			title: "Array destructuring assignment"
+			source: #"""
+				[first, _, third] = ["a", "b", "c"]
+				[first, third]
+				"""#
+			return: ["a", "c"]
+		},
 		{
 			title: "Fallible assignment (success)"
 			source: #"""
@@ -26,8 +26,8 @@ remap: expressions: coalesce: {
 					"""
 				enum: {
 					"??": """
-						The `??` operator performs error coalescing, short-circutiing on the first expression that
-						doesn't error and returning its result.
+						The `??` operator performs error and null coalescing, short-circutiing on the first expression
+						that doesn't error or resolve to `null` and returning its result.
 						"""
 				}
 			}
@@ -42,5 +42,13 @@ remap: expressions: coalesce: {
 				"""#
 			return: "malformed"
 		},
+		{
+			title: "Null coalescing"
+			input: log: hostname: null
+			source: #"""
+				.hostname ?? "unknown"
+				"""#
+			return: "unknown"
+		},
 	]
 }
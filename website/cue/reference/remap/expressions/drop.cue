@@ -0,0 +1,38 @@
+package metadata
+
+remap: expressions: drop: {
+	title: "Drop"
+	description: """
+		A _drop_ expression stops the program and discards the event. None of the expressions after it are executed,
+		including the expressions it's nested in and, when used within the closure of a function such as `for_each`,
+		the remaining iterations of that function. Unlike `abort`, dropping an event isn't an error, the event isn't
+		forwarded to any output, including the `dropped` output of the `remap` transform. It also can't be handled by
+		the program, with `??`, `try`/`catch` or an error assignment.
+
+		`drop` is a reserved keyword, so it can't be used as the name of a variable.
+		"""
+	return: """
+		Does not return a value, the event is discarded instead.
+		"""
+
+	grammar: {
+		source: """
+			"drop"
+			"""
+		definitions: {}
+	}
+
+	examples: [
+		{
+			title: "Drop health checks"
+			input: log: path: "/health"
+			source: #"""
+				if .path == "/health" {
+					drop
+				}
+				.processed = true
+				"""#
+			raises: runtime: "event dropped"
+		},
+	]
+}
@@ -14,6 +14,7 @@ remap: expressions: if: {
 	grammar: {
 		source: """
 			"if" ~ predicate ~ block ~ ("else if" ~ predicate ~ block)* ~ ("else" ~ block)?
+			| predicate ~ "?" ~ expression ~ ":" ~ expression
 			"""
 		definitions: {
 			predicate: {
@@ -22,6 +23,18 @@ remap: expressions: if: {
 					compile-time error is raised.
 					The predicate can contain multiple expressions. Multiple expression predicates must be wrapped in
 					parentheses. The expressions need to be separated by either a semicolon (`;`) or a new line.
+
+					If the predicate is a single `exists(path)`, `is_string(path)`, or `path != null` check on a path
+					or variable, the type of that path is narrowed within the "if" branch. For example, inside
+					`if is_string(.message) { ... }`, `.message` is known to be a string, so string functions such as
+					`upcase(.message)` don't need their errors handled.
+					"""
+			}
+			expression: {
+				description: """
+					The ternary form (`predicate ? expression : expression`) is shorthand for an if/else expression
+					with a single expression in each branch. Since both branches are always present, it never
+					resolves to null on its own.
 					"""
 			}
 		}
@@ -39,8 +52,9 @@ remap: expressions: if: {
 		},
 		{
 			title: "False if expression"
+			input: log: status: "ok"
 			source: #"""
-				if false {
+				if .status == "error" {
 					# not evaluated
 					null
 				}
@@ -49,8 +63,9 @@ remap: expressions: if: {
 		},
 		{
 			title: "If/else expression"
+			input: log: status: "ok"
 			source: #"""
-				if false {
+				if .status == "error" {
 					# not evaluated
 					null
 				} else {
@@ -61,11 +76,12 @@ remap: expressions: if: {
 		},
 		{
 			title: "If/else if/else expression"
+			input: log: status: "ok"
 			source: #"""
-				if false {
+				if .status == "error" {
 					# not evaluated
 					null
-				} else if false {
+				} else if .status == "warning" {
 					# not evaluated
 					null
 				} else {
@@ -90,6 +106,13 @@ remap: expressions: if: {
 				"""#
 			return: "Hello, World!"
 		},
-
+		{
+			title: "Ternary expression"
+			input: log: status: "ok"
+			source: #"""
+				.status == "error" ? "failed" : "succeeded"
+				"""#
+			return: "succeeded"
+		},
 	]
 }
@@ -26,6 +26,19 @@ remap: expressions: index: {
 							Indexes are zero-based where `0` represents the first array element.
 							"""
 					}
+					negative: {
+						title: "Negative indices"
+						description: """
+							Negative indexes count from the end of the array, where `-1` represents the last array
+							element, `-2` the second to last element, and so on.
+							"""
+					}
+					out_of_range: {
+						title: "Out of range indices"
+						description: """
+							An index outside of the array, whether positive or negative, returns `null`.
+							"""
+					}
 				}
 			}
 		}
@@ -40,5 +53,13 @@ remap: expressions: index: {
 				"""#
 			return: "first"
 		},
+		{
+			title: "Array index expression (negative)"
+			input: log: array: ["first", "second"]
+			source: #"""
+				.array[-1]
+				"""#
+			return: "second"
+		},
 	]
 }
@@ -0,0 +1,51 @@
+package metadata
+
+remap: expressions: loop_control: {
+	title: "Loop control"
+	description: """
+		The `break` and `continue` expressions control the loop created by the closure of the `for_each`
+		function. `break` stops the loop, and `continue` skips to its next iteration. Both can only be used
+		within such a closure.
+		"""
+	return: """
+		Does not return a value, the rest of the current iteration isn't executed.
+		"""
+
+	grammar: {
+		source: """
+			"break" | "continue"
+			"""
+		definitions: {}
+	}
+
+	examples: [
+		{
+			title: "Stop iterating"
+			source: #"""
+				sum = 0
+				for_each([1, 2, 3, 4]) -> |_index, value| {
+					if value > 2 {
+						break
+					}
+					sum = sum + value
+				}
+				sum
+				"""#
+			return: 3
+		},
+		{
+			title: "Skip an iteration"
+			source: #"""
+				sum = 0
+				for_each({ "a": 1, "b": 2, "c": 3 }) -> |key, value| {
+					if key == "b" {
+						continue
+					}
+					sum = sum + value
+				}
+				sum
+				"""#
+			return: 4
+		},
+	]
+}
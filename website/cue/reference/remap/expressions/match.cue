@@ -0,0 +1,61 @@
+package metadata
+
+remap: expressions: match: {
+	title: "Match"
+	description: """
+		A _match_ expression compares a value against a list of literal patterns and executes the expression of the
+		first arm whose pattern is equal to the value. The wildcard pattern (`_`) matches any value and must be the
+		last arm.
+		"""
+	return: """
+		The result of the expression in the matched arm, or null if no arm matches.
+		"""
+
+	grammar: {
+		source: """
+			"match" ~ value ~ "{" ~ (pattern ~ "=>" ~ expression ~ ",")+ ~ "}"
+			"""
+		definitions: {
+			value: {
+				description: """
+					The `value` can be any expression.
+					"""
+			}
+			pattern: {
+				description: """
+					The `pattern` _must_ be a literal value or the wildcard pattern (`_`). Templated strings aren't
+					allowed as patterns.
+					"""
+			}
+			expression: {
+				description: """
+					The `expression` can be any expression, including a block.
+					"""
+			}
+		}
+	}
+
+	examples: [
+		{
+			title: "Match expression"
+			source: #"""
+				match "warn" {
+					"info" => 6,
+					"warn" => 4,
+					_ => 7,
+				}
+				"""#
+			return: 4
+		},
+		{
+			title: "Match expression without a matching arm"
+			source: #"""
+				match 500 {
+					200 => "ok",
+					404 => "not found",
+				}
+				"""#
+			return: null
+		},
+	]
+}
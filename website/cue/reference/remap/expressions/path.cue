@@ -36,6 +36,15 @@ remap: expressions: path: {
 							```coffee
 							.array[0]
 							```
+
+							Negative indices count from the end of the array, with `-1` denoting the last
+							element:
+
+							```coffee
+							.array[-1]
+							```
+
+							Indices outside of the array resolve to `null`, both positive and negative ones.
 							"""
 					}
 					coalescing: {
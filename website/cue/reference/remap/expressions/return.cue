@@ -0,0 +1,39 @@
+package metadata
+
+remap: expressions: return: {
+	title: "Return"
+	description: """
+		A _return_ expression stops the program, resolving it to the given value. None of the expressions after
+		it are executed. It can't be used within the closure of a function, such as `for_each`.
+		"""
+	return: """
+		Does not return a value, the program resolves to the value of the expression instead.
+		"""
+
+	grammar: {
+		source: """
+			"return" ~ expression
+			"""
+		definitions: {
+			expression: {
+				description: """
+					The `expression` to resolve the program to.
+					"""
+			}
+		}
+	}
+
+	examples: [
+		{
+			title: "Return early"
+			input: log: status: 404
+			source: #"""
+				if .status == 404 {
+					return "not found"
+				}
+				"found"
+				"""#
+			return: "not found"
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: expressions: try: {
+	title: "Try"
+	description: """
+		A _try_ expression resolves its `block`, and resolves the `fallback` block instead if any expression in
+		`block` fails. Changes made by `block` before it failed aren't undone. An `abort` isn't handled by the
+		fallback.
+		"""
+	return: """
+		The result of `block`, or the result of `fallback` if `block` fails. The expression can only fail if
+		`fallback` can fail.
+		"""
+
+	grammar: {
+		source: """
+			"try" ~ block ~ "catch" ~ fallback
+			"""
+		definitions: {
+			block: {
+				description: """
+					The `block` is a block expression that can fail. Errors within it don't need to be handled.
+					"""
+			}
+			fallback: {
+				description: """
+					The `fallback` is a block expression resolved if `block` fails.
+					"""
+			}
+		}
+	}
+
+	examples: [
+		{
+			title: "Try expression"
+			source: #"""
+				try {
+					parse_json("{")
+				} catch {
+					"invalid"
+				}
+				"""#
+			return: "invalid"
+		},
+	]
+}
@@ -5,7 +5,8 @@ remap: functions: assert_eq: {
 
 	description: """
 		Asserts that two expressions, `left` and `right`, have the same value. The program is
-		aborted with the `message` if they are unequal.
+		aborted with the `message` if they are unequal. The error also lists both values on their own
+		line, to make the difference between them easier to spot.
 		"""
 
 	notices: [
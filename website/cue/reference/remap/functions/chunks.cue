@@ -3,25 +3,25 @@ package metadata
 remap: functions: chunks: {
 	category: "Array"
 	description: """
-		Chunks `value` into slices of length `chunk_size` bytes
+		Chunks `value` into slices of length `chunk_size`. Strings are chunked by bytes, arrays by elements.
 		"""
 
 	arguments: [
 		{
 			name:        "value"
-			description: "The array of bytes to split."
+			description: "The string or array to split."
 			required:    true
 			type: ["array", "string"]
 		},
 		{
 			name:        "chunk_size"
-			description: "The desired length of each chunk in bytes. This may be constrained by the host platform architecture."
+			description: "The desired length of each chunk, in bytes for strings and elements for arrays. This may be constrained by the host platform architecture."
 			required:    true
 			type: ["integer"]
 		},
 	]
 	internal_failure_reasons: [
-		"`chunk_size` must be at least 1 byte",
+		"`chunk_size` must be at least 1",
 		"`chunk_size` is too large",
 	]
 	return: {
@@ -46,5 +46,12 @@ remap: functions: chunks: {
 				"""#
 			return: ["ab�", "�好"]
 		},
+		{
+			title: "Split an array into chunks"
+			source: #"""
+				chunks([1, 2, 3, 4, 5], 2)
+				"""#
+			return: [[1, 2], [3, 4], [5]]
+		},
 	]
 }
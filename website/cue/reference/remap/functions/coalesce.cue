@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: coalesce: {
+	category: "Type"
+	description: """
+		Returns the first value in `values` that isn't `null`, or `default` if there is none. Values after
+		the returned one aren't evaluated.
+		"""
+
+	arguments: [
+		{
+			name:        "values"
+			description: "The candidate values, in order of preference. This must be an array literal."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "default"
+			description: "The value to return if all candidates are `null`."
+			required:    false
+			default:     null
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["any"]
+		rules: [
+			"The return type is the union of the non-null types of all candidates and the type of `default`.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Take the first existing field"
+			input: log: hostname: "localhost"
+			source: #"""
+				coalesce([.host, .hostname], "unknown")
+				"""#
+			return: "localhost"
+		},
+		{
+			title: "Fall back to the default"
+			source: #"""
+				coalesce([null, null], "unknown")
+				"""#
+			return: "unknown"
+		},
+	]
+}
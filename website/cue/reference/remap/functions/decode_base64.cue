@@ -24,9 +24,17 @@ remap: functions: decode_base64: {
 				url_safe: "Modified Base64 for [URL variants](\(urls.base64_url_safe))."
 			}
 		},
+		{
+			name:        "strict"
+			description: "Whether to require the data to be padded. When `false`, unpadded data is accepted."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
 	]
 	internal_failure_reasons: [
 		"`value` isn't a valid encoded Base64 string.",
+		"`strict` is `true` and `value` isn't padded.",
 	]
 	return: types: ["string"]
 
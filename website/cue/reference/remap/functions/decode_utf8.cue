@@ -0,0 +1,49 @@
+package metadata
+
+remap: functions: decode_utf8: {
+	category:    "Codec"
+	description: """
+		Checks that `value` is a valid UTF-8 string, so that it can safely be passed to string functions, and
+		returns it unchanged.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The bytes to decode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name: "lossy"
+			description: """
+				Whether to replace invalid UTF-8 sequences with the replacement character (`�`) instead of
+				failing. The function is infallible if this is set to the literal `true`.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains invalid UTF-8 sequences and `lossy` is `false`.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode a valid UTF-8 string"
+			source: """
+				decode_utf8!("foo")
+				"""
+			return: "foo"
+		},
+		{
+			title: "Replace invalid UTF-8 sequences"
+			source: """
+				decode_utf8(decode_base64!("Zm//bw=="), lossy: true)
+				"""
+			return: "fo�o"
+		},
+	]
+}
@@ -10,6 +10,9 @@ remap: functions: encode_key_value: {
 		"""
 			If `fields_ordering` is specified then the function is fallible else it is infallible.
 			""",
+		"""
+			Keys and values containing whitespace, quotes, or either delimiter are quoted.
+			""",
 	]
 
 	arguments: [
@@ -21,7 +24,7 @@ remap: functions: encode_key_value: {
 		},
 		{
 			name:        "fields_ordering"
-			description: "The ordering of fields to preserve. Any fields not in this list will appear unordered, after any ordered fields."
+			description: "The ordering of fields to preserve. Any fields not in this list are listed in key order, after any ordered fields."
 			required:    false
 			type: ["array"]
 		},
@@ -16,7 +16,7 @@ remap: functions: encode_logfmt: {
 		},
 		{
 			name:        "fields_ordering"
-			description: "The ordering of fields to preserve. Any fields not in this list will appear unordered, after any ordered fields."
+			description: "The ordering of fields to preserve. Any fields not in this list are listed in key order, after any ordered fields."
 			required:    false
 			type: ["array"]
 		},
@@ -0,0 +1,30 @@
+package metadata
+
+remap: functions: entries: {
+	category: "Enumerate"
+	description: """
+		Returns the entries of the `value` object as an array of `[key, value]` pairs, ordered by key. This is the
+		inverse of [`object_from_entries`](#object_from_entries).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to get the entries of."
+			required:    true
+			type: ["object"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Get the entries of an object"
+			source: #"""
+				entries({"a": 1, "b": true})
+				"""#
+			return: [["a", 1], ["b", true]]
+		},
+	]
+}
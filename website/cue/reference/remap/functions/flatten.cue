@@ -20,8 +20,29 @@ remap: functions: flatten: {
 			default:     "."
 			type: ["string"]
 		},
+		{
+			name: "max_depth"
+			description: """
+				The maximum number of nested levels to flatten. Values nested deeper than this are kept
+				as they are. By default all levels are flattened.
+				"""
+			required: false
+			type: ["integer"]
+		},
+		{
+			name: "index_arrays"
+			description: """
+				Whether arrays nested in an object are flattened into keys containing the index of each
+				element, rather than kept as array values. Only applies when flattening an object.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`max_depth` is not greater than `0`.",
 	]
-	internal_failure_reasons: []
 	return: {
 		types: ["array", "object"]
 		rules: [
@@ -56,5 +77,36 @@ remap: functions: flatten: {
 				"parent2.child3": 3
 			}
 		},
+		{
+			title: "Flatten object up to a maximum depth"
+			source: #"""
+				flatten({
+					"parent1": {
+						"child1": {
+							"grandchild1": 1
+						}
+					}
+				}, max_depth: 1)
+				"""#
+			return: {
+				"parent1.child1": {
+					"grandchild1": 1
+				}
+			}
+		},
+		{
+			title: "Flatten object with arrays"
+			source: #"""
+				flatten({
+					"parent1": {
+						"child1": [1, { "grandchild1": 2 }]
+					}
+				}, index_arrays: true)
+				"""#
+			return: {
+				"parent1.child1.0":             1
+				"parent1.child1.1.grandchild1": 2
+			}
+		},
 	]
 }
@@ -3,7 +3,8 @@ package metadata
 remap: functions: format_number: {
 	category: "Number"
 	description: #"""
-		Formats the `value` into a string representation of the number.
+		Formats the `value` into a string representation of the number. The number is never written in scientific
+		notation, however large or small it is.
 		"""#
 
 	arguments: [
@@ -45,5 +46,12 @@ remap: functions: format_number: {
 				"""#
 			return: "1,234,567.890"
 		},
+		{
+			title: "Format a negative number"
+			source: #"""
+				format_number(-1234.5, 2, decimal_separator: ".", grouping_separator: ",")
+				"""#
+			return: "-1,234.50"
+		},
 	]
 }
@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: group_by: {
+	category: "Enumerate"
+	description: """
+		Groups the elements of the `value` array by the string found at `path` in each element. Returns an object
+		with a key for each distinct string, mapped to an array of the elements sharing it, in their original order.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of elements to group."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "path"
+			description: "An array of path segments to look up the grouping key in each element."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "missing"
+			description: "What to do with elements that don't have a value at `path`, or where it is `null`."
+			required:    false
+			default:     "skip"
+			enum: {
+				skip: "Leave the element out of the result."
+				null: "Group the element under the `\"null\"` key."
+			}
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		#"path segment must be either "string" or "integer""#,
+		"The value at `path` is neither a string nor missing.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Group by field"
+			source: #"""
+				group_by!([{"level": "info"}, {"level": "error"}, {"level": "info"}], ["level"])
+				"""#
+			return: {
+				error: [{level: "error"}]
+				info:  [{level: "info"}, {level: "info"}]
+			}
+		},
+		{
+			title: "Group missing keys under null"
+			source: #"""
+				group_by!([{"level": "info"}, {"message": "foo"}], ["level"], missing: "null")
+				"""#
+			return: {
+				info:   [{level: "info"}]
+				"null": [{message: "foo"}]
+			}
+		},
+	]
+}
@@ -21,7 +21,7 @@ remap: functions: ip_cidr_contains: {
 		},
 	]
 	internal_failure_reasons: [
-		"`cidr` isn't a valid CIDR",
+		"`cidr` isn't a valid CIDR. An invalid literal `cidr` is rejected when the program is compiled instead.",
 		"`ip` isn't a valid IP address",
 	]
 	return: types: ["boolean"]
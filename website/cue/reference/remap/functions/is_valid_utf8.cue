@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: is_valid_utf8: {
+	category: "Type"
+	description: """
+		Check if `value` is a valid UTF-8 string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: #"The value to check"#
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			#"Returns `true` if `value` only contains valid UTF-8 sequences."#,
+			#"Returns `false` if `value` contains binary data that isn't valid UTF-8."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Valid UTF-8 string"
+			source: """
+				is_valid_utf8("föö")
+				"""
+			return: true
+		},
+		{
+			title: "Invalid UTF-8 string"
+			source: """
+				is_valid_utf8(decode_base64!("/w=="))
+				"""
+			return: false
+		},
+	]
+}
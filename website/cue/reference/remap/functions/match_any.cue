@@ -7,6 +7,10 @@ remap: functions: match_any: {
 		patterns are checked in a single pass over the target string, giving this
 		function a potentially significant performance advantage over multiple calls
 		to `match`.
+
+		Patterns that aren't known when the program is compiled, such as an array
+		built at runtime, are checked one at a time instead, stopping at the first
+		match.
 		"""
 
 	arguments: [
@@ -23,7 +27,9 @@ remap: functions: match_any: {
 			type: ["array"]
 		},
 	]
-	internal_failure_reasons: []
+	internal_failure_reasons: [
+		"`patterns` isn't known at compile time and contains an element that isn't a regular expression.",
+	]
 	return: types: ["boolean"]
 
 	examples: [
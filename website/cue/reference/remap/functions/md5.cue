@@ -13,6 +13,17 @@ remap: functions: md5: {
 			required:    true
 			type: ["string"]
 		},
+		{
+			name:        "encoding"
+			description: "The encoding of the returned hash."
+			enum: {
+				hex:    "Lowercase hexadecimal encoding"
+				base64: "Standard, padded base64 encoding"
+			}
+			required: false
+			default:  "hex"
+			type: ["string"]
+		},
 	]
 	internal_failure_reasons: []
 	return: types: ["string"]
@@ -25,5 +36,12 @@ remap: functions: md5: {
 				"""#
 			return: "acbd18db4cc2f85cedef654fccc4a4d8"
 		},
+		{
+			title: "Create base64 encoded md5 hash"
+			source: #"""
+				md5("foobar", encoding: "base64")
+				"""#
+			return: "OFj2IjCsPJFfMAxmQxLGPw=="
+		},
 	]
 }
@@ -36,6 +36,10 @@ remap: functions: merge: {
 				Objects are merged recursively if `deep` is specified, a key exists in both objects, and both of those
 				fields are also objects.
 				"""#,
+			#"""
+				A field that is an object in only one of the objects, such as an array in `to` and an object in `from`,
+				isn't merged recursively, the field from the `from` object is chosen instead.
+				"""#,
 		]
 	}
 
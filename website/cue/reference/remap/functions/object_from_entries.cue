@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: object_from_entries: {
+	category: "Object"
+	description: """
+		Builds an object from the `value` array of `[key, value]` pairs. When a key appears more than once, the last
+		pair with that key wins. This is the inverse of [`entries`](#entries).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of pairs to build the object from."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `value` isn't an array of exactly two elements.",
+		"The first element of a pair isn't a string.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Build an object from entries"
+			source: #"""
+				object_from_entries!([["a", 1], ["b", true]])
+				"""#
+			return: {"a": 1, "b": true}
+		},
+	]
+}
@@ -32,7 +32,8 @@ remap: functions: parse_apache_log: {
 		{
 			name:        "format"
 			description: "The format to use for parsing the log."
-			required:    true
+			required:    false
+			default:     "common"
 			enum: {
 				"common":   "Common format"
 				"combined": "Apache combined format"
@@ -9,6 +9,10 @@ remap: functions: parse_cef: {
 		"""
 			All values are returned as strings. We recommend manually coercing values to desired types as you see fit.
 			""",
+		"""
+			Escaped characters are unescaped: `\\|` and `\\\\` in header fields, and `\\=`, `\\|`, `\\\\`, `\\n`, and `\\r` in
+			extension values. Both `\\n` and `\\r` are returned as a newline.
+			""",
 	]
 
 	arguments: [
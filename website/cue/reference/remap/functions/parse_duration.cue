@@ -4,6 +4,9 @@ remap: functions: parse_duration: {
 	category: "Parse"
 	description: """
 		Parses the `value` into a human-readable duration format specified by `unit`.
+
+		The `value` can be a compound duration, such as `1h30m`, which is the sum of its components. Each component
+		can be fractional, and components can be separated by a space.
 		"""
 
 	arguments: [
@@ -45,5 +48,12 @@ remap: functions: parse_duration: {
 				"""#
 			return: 1.005
 		},
+		{
+			title: "Parse compound duration"
+			source: #"""
+				parse_duration!("1h30m", unit: "s")
+				"""#
+			return: 5400.0
+		},
 	]
 }
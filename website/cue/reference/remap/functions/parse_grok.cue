@@ -11,6 +11,10 @@ remap: functions: parse_grok: {
 			We recommend using community-maintained Grok patterns when possible, as they're more likely to be properly
 			vetted and improved over time than bespoke patterns.
 			""",
+		"""
+			A literal `pattern` is compiled once, when the program is compiled. A pattern that is only known at runtime
+			is compiled each time the function is called, which is considerably slower, and results in a warning.
+			""",
 	]
 
 	arguments: [
@@ -29,6 +33,7 @@ remap: functions: parse_grok: {
 	]
 	internal_failure_reasons: [
 		"`value` fails to parse using the provided `pattern`",
+		"`pattern` is not a literal, and fails to compile",
 	]
 	return: types: ["object"]
 
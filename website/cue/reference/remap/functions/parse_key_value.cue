@@ -7,6 +7,8 @@ remap: functions: parse_key_value: {
 
 		* Keys and values can be wrapped with `"`.
 		* `"` characters can be escaped using `\\`.
+		* Delimiters in keys and values that aren't wrapped with `"` can be escaped using `\\` if
+		  `escape_delimiters` is `true`.
 		"""
 	notices: [
 		"""
@@ -53,6 +55,13 @@ remap: functions: parse_key_value: {
 			type: ["boolean"]
 			default: true
 		},
+		{
+			name:        "escape_delimiters"
+			description: "Whether a delimiter preceded by `\\` in a key or value that isn't wrapped with `\"` is part of that key or value. The `\\` is kept in the result."
+			required:    false
+			type: ["boolean"]
+			default: false
+		},
 	]
 	internal_failure_reasons: [
 		"`value` isn't a properly formatted key/value string",
@@ -33,7 +33,8 @@ remap: functions: parse_nginx_log: {
 		{
 			name:        "format"
 			description: "The format to use for parsing the log."
-			required:    true
+			required:    false
+			default:     "combined"
 			enum: {
 				"combined": "Nginx combined format"
 				"error":    "Default Nginx error format"
@@ -46,6 +46,13 @@ remap: functions: parse_xml: {
 			default:     false
 			type: ["boolean"]
 		},
+		{
+			name:        "always_use_array"
+			description: "Always return child elements as an array, even if the element isn't repeated."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
 		{
 			name:        "parse_bool"
 			description: "Parse \"true\" and \"false\" as boolean."
@@ -27,7 +27,7 @@ remap: functions: redact: {
 				For arrays and objects it will recurse into any nested arrays or objects. Any non-string elements will
 				be skipped.
 
-				Any redacted text will be replaced with `[REDACTED]`.
+				Any redacted text will be replaced as specified by `redactor`.
 				"""#
 			required: true
 			type: ["string", "object", "array"]
@@ -57,6 +57,25 @@ remap: functions: redact: {
 			required: true
 			type: ["array"]
 		},
+		{
+			name: "redactor"
+			description: #"""
+				Specifies what to replace the redacted text with.
+
+				The redactor can be specified as a redactor name or as an object with a `type` key that corresponds to
+				a redactor name and additional keys for customizing that redactor.
+
+				Redactors are:
+
+				- `full`: Replace the redacted text with `[REDACTED]`. This is the default.
+				- `text`: Replace the redacted text with the string in the, required, `replacement` key.
+
+				This parameter must be a static expression.
+				"""#
+			required: false
+			default:  "full"
+			type: ["string", "object"]
+		},
 	]
 	internal_failure_reasons: []
 	return: types: ["string", "object", "array"]
@@ -79,5 +98,12 @@ remap: functions: redact: {
 				ssn:  "[REDACTED]"
 			}
 		},
+		{
+			title: "Replace text with a custom string"
+			source: #"""
+				redact("my id is 123456", filters: [r'\d+'], redactor: {"type": "text", "replacement": "***"})
+				"""#
+			return: "my id is ***"
+		},
 	]
 }
@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: sample: {
+	category: "Random"
+	description: """
+		Returns `true` for a `rate` fraction of calls, and `false` otherwise. This can be used to keep a
+		random sample of events, for example with `if !sample(0.1) { abort }`.
+
+		The random numbers used for this aren't cryptographically secure.
+		"""
+
+	arguments: [
+		{
+			name:        "rate"
+			description: "The fraction of calls that return `true`. Must be between `0.0` and `1.0`."
+			required:    true
+			type: ["float"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Always sample"
+			source: #"""
+				sample(1.0)
+				"""#
+			return: true
+		},
+		{
+			title: "Never sample"
+			source: #"""
+				sample(0.0)
+				"""#
+			return: false
+		},
+	]
+}
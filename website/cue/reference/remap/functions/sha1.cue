@@ -13,6 +13,17 @@ remap: functions: sha1: {
 			required:    true
 			type: ["string"]
 		},
+		{
+			name:        "encoding"
+			description: "The encoding of the returned hash."
+			enum: {
+				hex:    "Lowercase hexadecimal encoding"
+				base64: "Standard, padded base64 encoding"
+			}
+			required: false
+			default:  "hex"
+			type: ["string"]
+		},
 	]
 	internal_failure_reasons: []
 	return: types: ["string"]
@@ -25,5 +36,12 @@ remap: functions: sha1: {
 				"""#
 			return: "0beec7b5ea3f0fdbc95d0dd47f3c5bc275da8a33"
 		},
+		{
+			title: "Create base64 encoded sha1 hash"
+			source: #"""
+				sha1("foobar", encoding: "base64")
+				"""#
+			return: "iEPX+SQWIR3p67lj/0zigSWTKHg="
+		},
 	]
 }
@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: sha256: {
+	category:    "Cryptography"
+	description: """
+		Calculates a SHA-256 hash of the `value`, one of the [SHA-2](\(urls.sha2)) variants.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to calculate the hash for."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "encoding"
+			description: "The encoding of the returned hash."
+			enum: {
+				hex:    "Lowercase hexadecimal encoding"
+				base64: "Standard, padded base64 encoding"
+			}
+			required: false
+			default:  "hex"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Calculate sha256 hash"
+			source: #"""
+				sha256("foo")
+				"""#
+			return: "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+		},
+		{
+			title: "Create base64 encoded sha256 hash"
+			source: #"""
+				sha256("foobar", encoding: "base64")
+				"""#
+			return: "w6uP8Tcg6K2QR905Rms8iXTlksL6OD1KOWBxTK7wxPI="
+		},
+	]
+}
@@ -6,8 +6,11 @@ remap: functions: slice: {
 		Returns a slice of the `value` between the `start` and `end` positions.
 
 		If the `start` and `end` parameters are negative, they refer to positions counting from the right of the
-		string or array. If `end` refers to a position that is greater than the length of the string or array
-		a slice up to the end of the string or array is returned.
+		string or array. Positions outside of the string or array are clamped to its bounds, and an `end` before
+		`start` returns an empty slice.
+
+		Strings are sliced by grapheme cluster, so a character made up of multiple code points is never split.
+		Strings that aren't valid UTF-8 are sliced by byte.
 		"""
 
 	arguments: [
@@ -32,7 +35,7 @@ remap: functions: slice: {
 		},
 	]
 	internal_failure_reasons: []
-	return: types: ["string"]
+	return: types: ["array", "string"]
 
 	examples: [
 		{
@@ -49,5 +52,19 @@ remap: functions: slice: {
 				"""#
 			return: "califragilistic"
 		},
+		{
+			title: "Slice an array"
+			source: #"""
+				slice!([0, 1, 2, 3], start: 1, end: -1)
+				"""#
+			return: [1, 2]
+		},
+		{
+			title: "Slice past the end"
+			source: #"""
+				slice!("foobar", start: 3, end: 10)
+				"""#
+			return: "bar"
+		},
 	]
 }
@@ -0,0 +1,59 @@
+package metadata
+
+remap: functions: to_case: {
+	category:    "String"
+	description: """
+		Converts the `value` to the given naming convention.
+
+		Words are separated by any character that isn't a letter or digit, and by a change from a lowercase
+		letter or digit to an uppercase letter. A run of uppercase letters is kept together as an acronym, so
+		`HTTPServer` is split into `HTTP` and `Server`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to convert."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "case"
+			description: "The naming convention to convert to."
+			enum: {
+				snake:  "Lowercase words separated by underscores, such as `user_id`"
+				camel:  "Capitalized words without separators, starting with a lowercase word, such as `userId`"
+				kebab:  "Lowercase words separated by hyphens, such as `user-id`"
+				pascal: "Capitalized words without separators, such as `UserId`"
+			}
+			required: true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Convert to snake case"
+			source: #"""
+				to_case("userID", "snake")
+				"""#
+			return: "user_id"
+		},
+		{
+			title: "Convert an acronym to kebab case"
+			source: #"""
+				to_case("HTTPServerError", "kebab")
+				"""#
+			return: "http-server-error"
+		},
+		{
+			title: "Convert to camel case"
+			source: #"""
+				to_case("http_status_code", "camel")
+				"""#
+			return: "httpStatusCode"
+		},
+	]
+}
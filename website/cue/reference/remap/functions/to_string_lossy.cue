@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: to_string_lossy: {
+	category: "Coerce"
+	description: """
+		Converts any `value` into a string, without failing.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to convert to a string."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["string"]
+		rules: [
+			#"If `value` is an integer, float, boolean, timestamp, or null, returns the same string as `to_string`."#,
+			#"If `value` is a regex, returns its pattern."#,
+			#"If `value` is an array or object, returns its JSON representation."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Convert an integer to a string"
+			source: #"""
+				to_string_lossy(52)
+				"""#
+			return: "52"
+		},
+		{
+			title: "Convert an object to a string"
+			source: #"""
+				to_string_lossy({ "field": "value", "another": [1, 2, 3] })
+				"""#
+			return: #"{"another":[1,2,3],"field":"value"}"#
+		},
+	]
+}
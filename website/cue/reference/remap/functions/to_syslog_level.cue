@@ -18,13 +18,18 @@ remap: functions: to_syslog_level: {
 	internal_failure_reasons: [
 		"`value` isn't a valid Syslog [severity level](\(urls.syslog_levels)).",
 	]
-	return: types: ["string"]
+	return: {
+		types: ["string"]
+		rules: [
+			"The function is infallible if `value` is a literal valid severity level.",
+		]
+	}
 
 	examples: [
 		{
 			title: "Coerce to a Syslog level"
 			source: """
-				to_syslog_level!(5)
+				to_syslog_level(5)
 				"""
 			return: "notice"
 		},
@@ -22,6 +22,9 @@ remap: functions: to_syslog_severity: {
 		types: ["integer"]
 		rules: [
 			"The now-deprecated keywords `panic`, `error`, and `warn` are converted to `0`, `3`, and `4` respectively.",
+			"The full names `emergency`, `critical`, and `informational` are converted to `0`, `2`, and `6` respectively.",
+			"Keywords are matched case-insensitively.",
+			"The function is infallible if `value` is a literal valid keyword.",
 		]
 	}
 
@@ -29,9 +32,16 @@ remap: functions: to_syslog_severity: {
 		{
 			title: "Coerce to Syslog severity"
 			source: """
-				to_syslog_severity!("alert")
+				to_syslog_severity("alert")
 				"""
 			return: 1
 		},
+		{
+			title: "Coerce to Syslog severity, ignoring case"
+			source: """
+				to_syslog_severity("WARN")
+				"""
+			return: 4
+		},
 	]
 }
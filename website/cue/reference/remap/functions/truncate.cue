@@ -3,7 +3,8 @@ package metadata
 remap: functions: truncate: {
 	category: "String"
 	description: """
-		Truncates the `value` string up to the `limit` number of characters.
+		Truncates the `value` string up to the `limit` number of characters. Characters are counted as grapheme
+		clusters, so a character made up of multiple code points is never split.
 		"""
 
 	arguments: [
@@ -22,11 +23,12 @@ remap: functions: truncate: {
 		{
 			name: "ellipsis"
 			description: """
-				An ellipsis (`...`) is appended if this is set to `true` _and_ the `value` string ends up being
-				truncated because it's exceeded the `limit`.
+				The ellipsis to append if the `value` string ends up being truncated because it's exceeded the
+				`limit`. If set to `true`, `...` is appended.
 				"""
-			required: true
-			type: ["boolean"]
+			required: false
+			default:  false
+			type: ["boolean", "string"]
 		},
 	]
 	internal_failure_reasons: []
@@ -35,6 +37,7 @@ remap: functions: truncate: {
 		rules: [
 			"The string is returned unchanged its length is less than `limit`.",
 			"If `ellipsis` is `true`, then an ellipsis (`...`) is appended to the string (beyond the specified `limit`).",
+			"If `ellipsis` is a string, then that string is appended to the truncated string instead.",
 		]
 	}
 
@@ -46,5 +49,12 @@ remap: functions: truncate: {
 				"""#
 			return: "A rather lo..."
 		},
+		{
+			title: "Truncate a string with a custom ellipsis"
+			source: #"""
+				truncate("A rather long sentence.", limit: 11, ellipsis: "…")
+				"""#
+			return: "A rather lo…"
+		},
 	]
 }
@@ -5,7 +5,8 @@ remap: functions: unique: {
 	description: #"""
 		Returns unique values for an array.
 
-		The first occurrence of each element is kept.
+		The first occurrence of each element is kept. Elements are compared by value, including
+		nested arrays and objects.
 		"""#
 
 	arguments: [
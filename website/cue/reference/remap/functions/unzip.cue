@@ -0,0 +1,32 @@
+package metadata
+
+remap: functions: unzip: {
+	category: "Array"
+	description: """
+		Splits the `value` array of pairs into two arrays, the first holding the first element of each pair, the
+		second holding the second element of each pair. This is the inverse of [`zip`](#zip).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of pairs to split."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `value` isn't an array of exactly two elements.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Unzip an array of pairs"
+			source: #"""
+				unzip!([[1, "a"], [2, "b"]])
+				"""#
+			return: [[1, 2], ["a", "b"]]
+		},
+	]
+}
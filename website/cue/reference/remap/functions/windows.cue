@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: windows: {
+	category: "Array"
+	description: """
+		Returns all overlapping windows of length `window_size` of the `value` array, in order. If the array is shorter
+		than `window_size`, no windows are returned.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to split into windows."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "window_size"
+			description: "The number of elements in each window."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`window_size` must be at least 1",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"`windows` is considered fallible if the supplied `window_size` is an expression, and infallible if it's a literal integer.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Split an array into overlapping windows"
+			source: #"""
+				windows([1, 2, 3, 4], 3)
+				"""#
+			return: [[1, 2, 3], [2, 3, 4]]
+		},
+	]
+}
@@ -0,0 +1,58 @@
+package metadata
+
+remap: functions: zip: {
+	category: "Array"
+	description: """
+		Combines the `value` and `other` arrays into an array of pairs, where each pair holds the elements at the same
+		index of both arrays. If the arrays have different lengths, the result is as long as the shorter one, unless
+		`strict` is set.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array holding the first element of each pair."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "other"
+			description: "The array holding the second element of each pair."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "strict"
+			description: "Whether to raise an error, instead of truncating, if the arrays have different lengths."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`strict` is set and the arrays have different lengths",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"`zip` is considered infallible unless `strict` is set, or is an expression.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Zip two arrays"
+			source: #"""
+				zip([1, 2], ["a", "b"])
+				"""#
+			return: [[1, "a"], [2, "b"]]
+		},
+		{
+			title: "Zip arrays of different lengths"
+			source: #"""
+				zip([1, 2, 3], ["a", "b"])
+				"""#
+			return: [[1, "a"], [2, "b"]]
+		},
+	]
+}
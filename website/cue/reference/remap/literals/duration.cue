@@ -0,0 +1,35 @@
+package metadata
+
+remap: literals: duration: {
+	title:       "Duration"
+	description: """
+		A _duration_ literal defines a span of time as an integer amount followed by a unit, such as `30s` or `5m`.
+
+		Durations are represented as a `float` number of seconds, so they can be added to and subtracted from
+		timestamps: `t'2021-02-11T10:32:50Z' - 5m` returns the timestamp five minutes earlier.
+		"""
+
+	characteristics: {
+		units: {
+			title: "Units"
+			description: """
+				The supported units are `ns` (nanoseconds), `us` (microseconds), `ms` (milliseconds), `s` (seconds),
+				`m` (minutes), `h` (hours), and `d` (days). Units can't be combined, use `90m` instead of `1h30m`.
+				"""
+		}
+
+		underscores: {
+			title: "Underscores"
+			description: """
+				Durations can use underscore (`_`) characters instead of `,` to make them human readable. For
+				example, `1_000ms`.
+				"""
+		}
+	}
+
+	examples: [
+		"30s",
+		"5m",
+		"1_000ms",
+	]
+}
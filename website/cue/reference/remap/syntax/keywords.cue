@@ -9,6 +9,7 @@ remap: syntax: keywords: {
 		* `abort`
 		* `as`
 		* `break`
+		* `catch`
 		* `continue`
 		* `else`
 		* `false`
@@ -25,6 +26,7 @@ remap: syntax: keywords: {
 		* `then`
 		* `this`
 		* `true`
+		* `try`
 		* `type`
 		* `until`
 		* `use`